@@ -71,6 +71,14 @@ impl CardCache {
         })
     }
 
+    /// Every cached card, including expired entries, once per name
+    pub fn cards(&self) -> Vec<Card> {
+        let mut cards: Vec<Card> = self.data.entries.values().map(|e| e.card.clone()).collect();
+        cards.sort_by(|a, b| a.name.cmp(&b.name));
+        cards.dedup_by(|a, b| a.name == b.name);
+        cards
+    }
+
     pub fn set(&self, key: &str, card: &Card) {
         let normalized_key = key.to_lowercase();
         let entry = CacheEntry {
@@ -0,0 +1,59 @@
+//! Card images for self-contained exports
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::header::CONTENT_TYPE;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::types::Card;
+
+const APP_USER_AGENT: &str = "mtg-cli/0.1.0";
+const IMAGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Scryfall URL of a card's image, preferring the small size so embedded
+/// images keep reports light
+pub fn image_url(card: &Card) -> Option<&str> {
+    card.image_uris
+        .as_ref()
+        .and_then(|uris| uris.small.as_deref().or(uris.normal.as_deref()))
+}
+
+/// Download each card's image and encode it as a `data:` URI, keyed by card
+/// name. Cards without an image, or whose image can't be fetched, are left out.
+pub async fn fetch_image_data_uris(cards: &[&Card]) -> HashMap<String, String> {
+    let mut images = HashMap::new();
+    let Ok(client) = reqwest::Client::builder()
+        .user_agent(APP_USER_AGENT)
+        .timeout(IMAGE_TIMEOUT)
+        .build()
+    else {
+        return images;
+    };
+
+    for card in cards {
+        if images.contains_key(&card.name) {
+            continue;
+        }
+        let Some(url) = image_url(card) else {
+            continue;
+        };
+        if let Some(data_uri) = fetch_data_uri(&client, url).await {
+            images.insert(card.name.clone(), data_uri);
+        }
+    }
+
+    images
+}
+
+async fn fetch_data_uri(client: &reqwest::Client, url: &str) -> Option<String> {
+    let response = client.get(url).send().await.ok()?.error_for_status().ok()?;
+    let mime = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("image/jpeg")
+        .to_string();
+    let bytes = response.bytes().await.ok()?;
+    Some(format!("data:{mime};base64,{}", STANDARD.encode(&bytes)))
+}
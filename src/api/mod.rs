@@ -1,6 +1,7 @@
 pub mod cache;
 pub mod factory;
 pub mod fallback;
+pub mod images;
 pub mod mtgio;
 pub mod scryfall;
 pub mod traits;
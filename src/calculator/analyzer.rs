@@ -35,7 +35,7 @@ pub fn analyze_pip_intensity(deck: &Deck) -> Vec<PipAnalysis> {
     }
 
     // Sort by intensity descending
-    analyses.sort_by(|a, b| b.intensity.cmp(&a.intensity));
+    analyses.sort_by_key(|a| std::cmp::Reverse(a.intensity));
     analyses
 }
 
@@ -44,13 +44,8 @@ impl ManaCalculator for CmcWeightedCalculator {
         // Copy dual lands from deck
         mana_base.duals = deck.dual_lands.clone();
 
-        // Calculate effective color sources from dual lands
-        let mut dual_sources: HashMap<Color, f64> = HashMap::new();
-        for dual in &deck.dual_lands {
-            for color in &dual.colors {
-                *dual_sources.entry(*color).or_insert(0.0) += dual.count as f64;
-            }
-        }
+        // Calculate effective color sources from dual and tri-lands
+        let dual_sources = deck.dual_sources();
 
         // Calculate baseline basics (as if no duals existed)
         let basic_slots = deck.basic_land_slots() as f64;
@@ -25,13 +25,8 @@ impl ManaCalculator for SimpleCalculator {
         // Copy dual lands from deck
         mana_base.duals = deck.dual_lands.clone();
 
-        // Calculate effective color sources from dual lands
-        let mut dual_sources: HashMap<Color, f64> = HashMap::new();
-        for dual in &deck.dual_lands {
-            for color in &dual.colors {
-                *dual_sources.entry(*color).or_insert(0.0) += dual.count as f64;
-            }
-        }
+        // Calculate effective color sources from dual and tri-lands
+        let dual_sources = deck.dual_sources();
 
         // Calculate baseline basics (as if no duals existed)
         let basic_slots = deck.basic_land_slots() as f64;
@@ -309,4 +304,55 @@ mod tests {
             "Non-dual colors should have more basics"
         );
     }
+
+    #[test]
+    fn test_tri_lands_count_once_per_deck_color() {
+        // 24 lands, Grixis even split, 3 Grixis tri-lands and 2 Jund tri-lands.
+        // Jund lands only help Black and Red; Green isn't in the deck.
+        let mut symbols = HashMap::new();
+        symbols.insert(Color::Blue, 10);
+        symbols.insert(Color::Black, 10);
+        symbols.insert(Color::Red, 10);
+
+        let grixis = DualLand::new(
+            "Grixis lands".to_string(),
+            vec![Color::Red, Color::Blue, Color::Black],
+            3,
+        );
+        let jund = DualLand::new(
+            "Jund lands".to_string(),
+            vec![Color::Black, Color::Red, Color::Green],
+            2,
+        );
+        assert_eq!(grixis.colors, vec![Color::Blue, Color::Black, Color::Red]);
+        assert_eq!(grixis.combination_name(), Some("Grixis"));
+
+        let deck = make_deck(
+            vec![Color::Blue, Color::Black, Color::Red],
+            symbols,
+            vec![grixis, jund],
+            24,
+        );
+
+        let sources = deck.dual_sources();
+        assert_eq!(sources.get(&Color::Blue), Some(&3.0));
+        assert_eq!(sources.get(&Color::Black), Some(&5.0));
+        assert_eq!(sources.get(&Color::Red), Some(&5.0));
+        assert_eq!(sources.get(&Color::Green), None);
+
+        for result in [
+            SimpleCalculator.calculate(&deck),
+            crate::calculator::CmcWeightedCalculator.calculate(&deck),
+        ] {
+            let total: u32 = result.basics.values().sum();
+            assert_eq!(total, 19); // 24 - 5 tri-lands
+
+            // Blue gets the least help from the tri-lands, so the most basics
+            let blue = result.basics.get(&Color::Blue).copied().unwrap_or(0);
+            let black = result.basics.get(&Color::Black).copied().unwrap_or(0);
+            let red = result.basics.get(&Color::Red).copied().unwrap_or(0);
+            assert!(blue > black && blue > red);
+            assert_eq!(result.basics.get(&Color::Green), None);
+        }
+    }
 }
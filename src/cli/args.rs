@@ -8,6 +8,10 @@ use clap::{Parser, Subcommand, ValueEnum};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    /// Custom theme rules file (TOML or JSON); defaults to rules.toml in the config directory
+    #[arg(long, global = true)]
+    pub rules: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -79,6 +83,10 @@ pub enum Commands {
         #[arg(long)]
         json: Option<String>,
 
+        /// Export the synergy graph (.dot/.gv, .graphml or .mmd/.mermaid)
+        #[arg(long)]
+        graph: Option<String>,
+
         /// Show verbose card-by-card analysis
         #[arg(short, long)]
         verbose: bool,
@@ -94,6 +102,10 @@ pub enum Commands {
         /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
         #[arg(long)]
         excludes_lands: bool,
+
+        /// Re-run the analysis whenever the decklist file changes
+        #[arg(short, long)]
+        watch: bool,
     },
 
     /// Analyze deck mana curve distribution
@@ -106,6 +118,10 @@ pub enum Commands {
         #[arg(long)]
         by_type: bool,
 
+        /// Bucket cards by effective cost (cost reduction and alternative costs)
+        #[arg(long)]
+        effective: bool,
+
         /// Target number of lands (auto-detected if not specified)
         #[arg(short, long)]
         lands: Option<u32>,
@@ -114,6 +130,163 @@ pub enum Commands {
         #[arg(short, long, value_enum, default_value = "simple")]
         algorithm: AlgorithmArg,
 
+        /// Ideal curve to compare against (auto-detected if not specified)
+        #[arg(long, value_enum)]
+        archetype: Option<ArchetypeArg>,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// Export curve, mana base and synergy analysis to a standalone HTML file
+        #[arg(long)]
+        html: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+
+        /// Re-run the analysis whenever the decklist file changes
+        #[arg(short, long)]
+        watch: bool,
+    },
+
+    /// Run curve, mana base, synergy, legality and price analysis in one pass
+    Analyze {
+        /// Path to decklist file or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Format to check legality against (e.g. commander, modern); detected if omitted
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Target number of lands (auto-detected from deck if not specified)
+        #[arg(short, long)]
+        lands: Option<u32>,
+
+        /// Algorithm for mana base calculation
+        #[arg(short, long, value_enum, default_value = "simple")]
+        algorithm: AlgorithmArg,
+
+        /// Skip the mana curve (and the mana base, which depends on it)
+        #[arg(long)]
+        no_curve: bool,
+
+        /// Skip the mana base recommendation
+        #[arg(long)]
+        no_mana: bool,
+
+        /// Skip synergy analysis
+        #[arg(long)]
+        no_synergy: bool,
+
+        /// Skip the format legality check
+        #[arg(long)]
+        no_legality: bool,
+
+        /// Skip deck pricing
+        #[arg(long)]
+        no_price: bool,
+
+        /// Add LLM-enhanced synergy insights
+        #[arg(long, default_value = "false")]
+        llm: bool,
+
+        /// LLM provider (anthropic, openai, ollama)
+        #[arg(long, value_enum)]
+        provider: Option<LlmProviderArg>,
+
+        /// Show verbose card-by-card synergy analysis
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// Export results to a standalone HTML file
+        #[arg(long)]
+        html: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+    },
+
+    /// Break down card types and functional roles against format targets
+    Composition {
+        /// Path to decklist file or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Format template to compare against (auto-detected if not specified)
+        #[arg(short, long, value_enum)]
+        format: Option<FormatArg>,
+
+        /// List the cards filling each role
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+    },
+
+    /// Summarize the sideboard by role and test maybeboard swaps
+    Sections {
+        /// Path to decklist file or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Format for sideboard rules and curve template (auto-detected if not specified)
+        #[arg(short, long, value_enum)]
+        format: Option<FormatArg>,
+
+        /// List the cards filling each sideboard role
+        #[arg(short, long)]
+        verbose: bool,
+
         /// Export results to markdown file
         #[arg(short, long)]
         export: Option<String>,
@@ -134,6 +307,307 @@ pub enum Commands {
         #[arg(long)]
         excludes_lands: bool,
     },
+
+    /// Rate a Limited deck or suggest builds from a sealed pool
+    Limited {
+        /// Path to decklist or sealed pool file, or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Treat the decklist as a sealed pool and suggest two-color builds
+        #[arg(long)]
+        pool: bool,
+
+        /// Also suggest pool builds that splash a third color
+        #[arg(long)]
+        splash: bool,
+
+        /// Path to a 17lands-style card ratings CSV
+        #[arg(short, long)]
+        ratings: Option<String>,
+
+        /// Set code whose ratings to load from the ratings directory (auto-detected if not specified)
+        #[arg(short, long)]
+        set: Option<String>,
+
+        /// Number of pool builds to show
+        #[arg(short, long, default_value = "3")]
+        builds: usize,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+    },
+
+    /// Suggest cards to cut and cards to add
+    Recommend {
+        /// Path to decklist file or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Number of cuts to suggest
+        #[arg(long, default_value = "5")]
+        cuts: usize,
+
+        /// Number of additions to suggest
+        #[arg(long, default_value = "5")]
+        adds: usize,
+
+        /// Scryfall bulk data file to draw additions from, on top of the card cache
+        #[arg(long)]
+        pool: Option<String>,
+
+        /// Format template for role targets (auto-detected if not specified)
+        #[arg(short, long, value_enum)]
+        format: Option<FormatArg>,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+    },
+
+    /// Edit a deck interactively with live curve and synergy analysis
+    Repl {
+        /// Path to decklist file or Moxfield URL (starts an empty deck if omitted)
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+    },
+
+    /// Open a full-screen dashboard that refreshes when the decklist changes
+    Dashboard {
+        /// Path to decklist file or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+    },
+
+    /// Inspect and test custom theme rules
+    Rules {
+        #[command(subcommand)]
+        action: RulesAction,
+    },
+
+    /// Manage the local combo database used by synergy analysis
+    Combos {
+        #[command(subcommand)]
+        action: ComboAction,
+    },
+
+    /// Analyze a cube list and compare cube versions
+    Cube {
+        #[command(subcommand)]
+        action: CubeAction,
+    },
+
+    /// Pick dual, tri and fetch lands for a deck's mana base
+    Lands {
+        /// Path to decklist file or Moxfield URL; colors, land count and format come from the deck
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Deck colors (e.g., UBR), when not reading a decklist
+        #[arg(short, long)]
+        colors: Option<String>,
+
+        /// Deck format preset (auto-detected from the deck if not specified)
+        #[arg(short, long, value_enum)]
+        format: Option<FormatArg>,
+
+        /// Target number of lands (auto-detected if not specified)
+        #[arg(short, long)]
+        lands: Option<u32>,
+
+        /// Scryfall format the lands must be legal in (e.g. pioneer, pauper)
+        #[arg(long)]
+        legality: Option<String>,
+
+        /// Most to spend, in USD, on lands not in the collection
+        #[arg(short, long)]
+        budget: Option<f64>,
+
+        /// Decklist-style file of owned cards; owned copies are free
+        #[arg(long)]
+        collection: Option<String>,
+
+        /// Scryfall bulk data file to draw lands from, on top of the card cache
+        #[arg(long)]
+        pool: Option<String>,
+
+        /// Algorithm for splitting the remaining slots into basics
+        #[arg(short, long, value_enum, default_value = "simple")]
+        algorithm: AlgorithmArg,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+
+        /// Indicates the decklist excludes basic lands (common when exporting from Moxfield)
+        #[arg(long)]
+        excludes_lands: bool,
+    },
+}
+
+impl Commands {
+    /// Whether the command runs theme detection, and so needs custom theme rules
+    pub fn uses_theme_rules(&self) -> bool {
+        !matches!(
+            self,
+            Commands::Mana { .. }
+                | Commands::Card { .. }
+                | Commands::Combos { .. }
+                | Commands::Lands { .. }
+        )
+    }
+}
+
+#[derive(Subcommand)]
+pub enum RulesAction {
+    /// Show which theme rules fire for a card
+    Test {
+        /// Card name to test
+        name: String,
+
+        /// API provider to use
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+    },
+
+    /// List the custom theme rules that are loaded
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum ComboAction {
+    /// Import combos from a Commander Spellbook JSON export
+    Import {
+        /// Path to the exported JSON file (e.g. variants.json)
+        path: String,
+    },
+
+    /// Show where the combo database lives and how many combos it holds
+    Info,
+}
+
+#[derive(Subcommand)]
+pub enum CubeAction {
+    /// Report color balance, curves, archetype support, duplicates and legality
+    Analyze {
+        /// Path to cube list file or Moxfield URL
+        #[arg(short, long)]
+        input: String,
+
+        /// Scryfall format to check every card against (e.g. vintage, pauper)
+        #[arg(short, long)]
+        legality: Option<String>,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+    },
+
+    /// Show cards added and removed between two versions of a cube
+    Diff {
+        /// Path to the older cube list file or Moxfield URL
+        old: String,
+
+        /// Path to the newer cube list file or Moxfield URL
+        new: String,
+
+        /// Export results to markdown file
+        #[arg(short, long)]
+        export: Option<String>,
+
+        /// Export results to JSON file
+        #[arg(long)]
+        json: Option<String>,
+
+        /// API provider to use for card data
+        #[arg(long, value_enum, default_value = "scryfall")]
+        api: ApiProviderArg,
+
+        /// Disable fallback to secondary API on failure
+        #[arg(long)]
+        no_fallback: bool,
+    },
 }
 
 #[derive(Clone, Copy, ValueEnum)]
@@ -175,6 +649,28 @@ impl AlgorithmArg {
     }
 }
 
+/// Ideal curve template selection
+#[derive(Clone, Copy, ValueEnum, Debug)]
+pub enum ArchetypeArg {
+    Aggro,
+    Midrange,
+    Control,
+    Commander,
+    Limited,
+}
+
+impl ArchetypeArg {
+    pub fn to_archetype(self) -> crate::curve::CurveArchetype {
+        match self {
+            ArchetypeArg::Aggro => crate::curve::CurveArchetype::Aggro,
+            ArchetypeArg::Midrange => crate::curve::CurveArchetype::Midrange,
+            ArchetypeArg::Control => crate::curve::CurveArchetype::Control,
+            ArchetypeArg::Commander => crate::curve::CurveArchetype::Commander,
+            ArchetypeArg::Limited => crate::curve::CurveArchetype::Limited,
+        }
+    }
+}
+
 #[derive(Clone, Copy, ValueEnum, Debug)]
 pub enum LlmProviderArg {
     Anthropic,
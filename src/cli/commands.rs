@@ -1,22 +1,56 @@
-use crate::api::{create_client, ApiProvider};
+use crate::api::images::{fetch_image_data_uris, image_url};
+use crate::api::traits::CardApi;
+use crate::api::{create_client, ApiProvider, Card};
 use crate::calculator::{get_calculator, get_intensity_recommendations};
-use crate::cli::{AlgorithmArg, ApiProviderArg, FormatArg, LlmProviderArg};
+use crate::cli::{
+    AlgorithmArg, ApiProviderArg, ArchetypeArg, ComboAction, CubeAction, FormatArg, LlmProviderArg,
+    RulesAction,
+};
+use crate::combo::ComboDatabase;
+use crate::composition::CompositionAnalyzer;
+use crate::cube::{diff_cubes, CubeAnalyzer};
 use crate::curve::{
     calculate_mana_base, detect_format_from_deck, determine_land_count, CurveAnalyzer,
-    LandCountSource,
+    CurveArchetype, CurveFit, LandCountSource,
+};
+use crate::deck::{color_label, sorted_colors, Algorithm, Color, Deck, Format, ManaBase};
+use crate::export::{
+    CompositionReportExporter, CubeReportExporter, CurveReportExporter, DeckReportExporter,
+    GraphFormat, HtmlReportExporter, JsonExporter, LandSuiteReportExporter, LimitedReportExporter,
+    MarkdownExporter, RecommendationReportExporter, SectionReportExporter, SynergyGraphExporter,
+    SynergyReportExporter,
 };
-use crate::deck::{guild_name, Algorithm, Color, Deck, ManaBase};
-use crate::export::{CurveReportExporter, JsonExporter, MarkdownExporter, SynergyReportExporter};
-use crate::input::{DeckList, DeckListParser, MoxfieldClient, TextDecklistParser};
+use crate::input::{DeckList, DeckListParser, FileWatcher, MoxfieldClient, TextDecklistParser};
+use crate::lands::ManaBaseOptimizer;
+use crate::limited::{detect_set, CardRatings, LimitedAnalyzer};
+use crate::recommend::{load_card_pool, RecommendationEngine};
+use crate::report::legality::{legality_format, parse_legality_format, LEGALITY_FORMATS};
+use crate::report::{build_report, strip_ansi, ReportOptions};
+use crate::sections::SectionAnalyzer;
 use crate::synergy::get_detector;
+use crate::synergy::oracle::NormalizedOracle;
+use crate::synergy::rules::{custom_rules, custom_rules_path, default_rules_paths};
+use crate::synergy::themes::{classify_oracle_role, detect_oracle_themes, match_rule, THEME_RULES};
 use colored::Colorize;
+use std::collections::HashMap;
+use std::sync::Arc;
 
+use super::composition_display::display_composition;
+use super::cube_display::{display_cube, display_cube_diff};
 use super::curve_display::display_curve_analysis;
 use super::interactive::{run_interactive_mana_flow, InteractiveConfig};
+use super::lands_display::display_land_suite;
+use super::limited_display::display_limited;
+use super::recommend_display::display_recommendations;
+use super::report_display::{display_legality, display_price};
+use super::sections_display::display_sections;
 use super::synergy_display::{
     display_error, display_llm_insights, display_progress, display_synergy_matrix, display_warning,
 };
 
+/// How often `--watch` checks the decklist file for changes
+const WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
 /// Shared helper to parse and hydrate a decklist from file or Moxfield URL
 pub async fn parse_and_hydrate_deck(
     input: &str,
@@ -86,6 +120,47 @@ pub async fn parse_and_hydrate_deck(
     Ok(deck_list)
 }
 
+/// Re-parse a decklist file, reusing card data from the previous parse and
+/// fetching only card names that were not in it
+pub async fn reload_deck(
+    path: &str,
+    previous: &DeckList,
+    client: &dyn CardApi,
+) -> Result<(DeckList, usize), String> {
+    let mut deck_list = TextDecklistParser::new()
+        .parse(path)
+        .await
+        .map_err(|e| format!("Failed to parse decklist: {e}"))?;
+    deck_list.excludes_lands = previous.excludes_lands;
+
+    let missing = deck_list.reuse_cards_from(previous);
+    if missing.is_empty() {
+        return Ok((deck_list, 0));
+    }
+
+    let cards = client
+        .batch_fetch_cards(missing.clone())
+        .await
+        .map_err(|e| format!("Failed to fetch card data: {}", e.message))?;
+    for entry in deck_list.entries.iter_mut().filter(|e| e.card.is_none()) {
+        entry.card = cards.get(&entry.card_name).cloned();
+    }
+
+    Ok((deck_list, missing.len()))
+}
+
+/// Load the imported combo database, warning and detecting no combos if it
+/// can't be read
+pub fn load_combo_database() -> Arc<ComboDatabase> {
+    let db = ComboDatabase::load_default().unwrap_or_else(|e| {
+        display_warning(&format!(
+            "{e}; re-import it with `scry combos import` to detect combos"
+        ));
+        ComboDatabase::default()
+    });
+    Arc::new(db)
+}
+
 pub async fn handle_mana_command(
     format: Option<FormatArg>,
     algorithm: AlgorithmArg,
@@ -285,6 +360,9 @@ pub fn run_calculation(deck: &Deck, algorithm: Algorithm, export: Option<String>
     println!("{}", "=== MANA BASE RECOMMENDATION ===".bold().green());
     println!();
 
+    println!("{}: {}", "Colors".yellow(), color_label(&deck.colors));
+    println!();
+
     // Basic lands
     let total_basics: u32 = mana_base.basics.values().sum();
     println!(
@@ -323,13 +401,10 @@ pub fn run_calculation(deck: &Deck, algorithm: Algorithm, export: Option<String>
         println!("{}", format!("Dual Lands ({total_duals} total):").yellow());
 
         for dual in &mana_base.duals {
-            let name = if dual.colors.len() == 2 {
-                guild_name(&dual.colors)
-                    .map(|g| g.to_string())
-                    .unwrap_or_else(|| dual.name.clone())
-            } else {
-                dual.name.clone()
-            };
+            let name = dual
+                .combination_name()
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| dual.name.clone());
 
             let colors_str: Vec<_> = dual.colors.iter().map(|c| c.symbol()).collect();
             println!("  • {} ({}): {}", name, colors_str.join("/"), dual.count);
@@ -460,17 +535,31 @@ pub async fn handle_synergy_command(
     llm_provider_arg: Option<LlmProviderArg>,
     export: Option<String>,
     json: Option<String>,
+    graph: Option<String>,
     verbose: bool,
     api: ApiProviderArg,
     no_fallback: bool,
     excludes_lands: bool,
+    watch: bool,
 ) {
+    if watch && !std::path::Path::new(&input).is_file() {
+        display_error("--watch requires a decklist file");
+        return;
+    }
+    if let Some(Err(e)) = graph.as_deref().map(GraphFormat::from_path) {
+        display_error(&e);
+        return;
+    }
+
     println!();
     display_progress("Analyzing deck synergies...");
     println!();
 
+    // Start watching before the first parse so saves made during it aren't missed
+    let watcher = watch.then(|| FileWatcher::new(&input));
+
     // Parse and hydrate the deck using shared helper
-    let deck_list = match parse_and_hydrate_deck(
+    let mut deck_list = match parse_and_hydrate_deck(
         &input,
         api.to_provider(),
         no_fallback,
@@ -485,10 +574,54 @@ pub async fn handle_synergy_command(
         }
     };
 
+    let export = export.as_deref();
+    let json = json.as_deref();
+    let graph = graph.as_deref();
+    run_synergy_analysis(
+        &deck_list,
+        llm,
+        llm_provider_arg,
+        export,
+        json,
+        graph,
+        verbose,
+    )
+    .await;
+
+    let Some(mut watcher) = watcher else {
+        return;
+    };
+
+    let client = create_client(api.to_provider(), !no_fallback);
+    loop {
+        deck_list = wait_for_deck_change(&input, &mut watcher, &deck_list, client.as_ref()).await;
+        run_synergy_analysis(
+            &deck_list,
+            llm,
+            llm_provider_arg,
+            export,
+            json,
+            graph,
+            verbose,
+        )
+        .await;
+    }
+}
+
+/// Analyze, display and export synergies for a hydrated deck
+async fn run_synergy_analysis(
+    deck_list: &DeckList,
+    llm: bool,
+    llm_provider_arg: Option<LlmProviderArg>,
+    export: Option<&str>,
+    json: Option<&str>,
+    graph: Option<&str>,
+    verbose: bool,
+) {
     // Run synergy analysis
     display_progress("Running synergy analysis...");
-    let detector = get_detector();
-    let matrix = detector.analyze(&deck_list);
+    let detector = get_detector(load_combo_database());
+    let matrix = detector.analyze(deck_list);
 
     // 4. Display results
     display_synergy_matrix(&matrix, verbose);
@@ -508,7 +641,7 @@ pub async fn handle_synergy_command(
         let report = SynergyReportExporter::generate(&matrix);
 
         match crate::llm::create_llm_client(llm_provider) {
-            Ok(client) => match client.analyze_synergies(&deck_list, &matrix, &report).await {
+            Ok(client) => match client.analyze_synergies(deck_list, &matrix, &report).await {
                 Ok(result) => {
                     display_llm_insights(&result);
                 }
@@ -528,38 +661,57 @@ pub async fn handle_synergy_command(
 
     // 6. Export if requested
     if let Some(path) = export {
-        match SynergyReportExporter::export(&matrix, &path) {
+        match SynergyReportExporter::export(&matrix, path) {
             Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
             Err(e) => display_error(&format!("Failed to export: {e}")),
         }
     }
 
     if let Some(path) = json {
-        match JsonExporter::export(&matrix, &path) {
+        match JsonExporter::export(&matrix, path) {
             Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
             Err(e) => display_error(&format!("Failed to export JSON: {e}")),
         }
     }
+
+    if let Some(path) = graph {
+        match SynergyGraphExporter::export(&matrix, path) {
+            Ok(_) => println!("{}", format!("Synergy graph saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export graph: {e}")),
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_curve_command(
     input: String,
     by_type: bool,
+    effective: bool,
     lands: Option<u32>,
     algorithm: AlgorithmArg,
+    archetype: Option<ArchetypeArg>,
     export: Option<String>,
     json: Option<String>,
+    html: Option<String>,
     api: ApiProviderArg,
     no_fallback: bool,
     excludes_lands: bool,
+    watch: bool,
 ) {
+    if watch && !std::path::Path::new(&input).is_file() {
+        display_error("--watch requires a decklist file");
+        return;
+    }
+
     println!();
     display_progress("Analyzing mana curve...");
     println!();
 
+    // Start watching before the first parse so saves made during it aren't missed
+    let watcher = watch.then(|| FileWatcher::new(&input));
+
     // Parse and hydrate the deck using shared helper
-    let deck_list = match parse_and_hydrate_deck(
+    let mut deck_list = match parse_and_hydrate_deck(
         &input,
         api.to_provider(),
         no_fallback,
@@ -574,24 +726,73 @@ pub async fn handle_curve_command(
         }
     };
 
+    let options = CurveOptions {
+        by_type,
+        effective,
+        lands,
+        algorithm: algorithm.to_algorithm(),
+        archetype: archetype.map(|a| a.to_archetype()),
+        export: export.as_deref(),
+        json: json.as_deref(),
+        html: html.as_deref(),
+    };
+    run_curve_analysis(&deck_list, &options).await;
+
+    let Some(mut watcher) = watcher else {
+        return;
+    };
+
+    let client = create_client(api.to_provider(), !no_fallback);
+    loop {
+        deck_list = wait_for_deck_change(&input, &mut watcher, &deck_list, client.as_ref()).await;
+        run_curve_analysis(&deck_list, &options).await;
+    }
+}
+
+/// Display and export settings for a curve analysis run
+struct CurveOptions<'a> {
+    by_type: bool,
+    effective: bool,
+    lands: Option<u32>,
+    algorithm: Algorithm,
+    archetype: Option<CurveArchetype>,
+    export: Option<&'a str>,
+    json: Option<&'a str>,
+    html: Option<&'a str>,
+}
+
+/// Analyze, display and export the mana curve for a hydrated deck
+async fn run_curve_analysis(deck_list: &DeckList, options: &CurveOptions<'_>) {
     // Run curve analysis
     display_progress("Calculating mana curve...");
-    let analyzer = CurveAnalyzer::new();
-    let mut analysis = analyzer.analyze(&deck_list);
+    let analyzer = CurveAnalyzer::with_effective_cmc(options.effective);
+    let mut analysis = analyzer.analyze(deck_list);
+
+    // Detect format for template selection and mana base calculation
+    let format = detect_format_from_deck(deck_list);
+    let algo = options.algorithm;
+
+    // Synergies pick the curve archetype and fill the HTML report
+    let matrix = (options.archetype.is_none() || options.html.is_some())
+        .then(|| get_detector(load_combo_database()).analyze(deck_list));
+
+    // Compare against the ideal curve, using strategy themes to pick the archetype
+    let archetype = match options.archetype {
+        Some(archetype) => archetype,
+        None => CurveArchetype::select(format, matrix.as_ref(), analysis.stats.average_cmc),
+    };
+    analysis.curve_fit = Some(CurveFit::evaluate(&analysis.buckets, archetype));
 
     // Display curve results
-    display_curve_analysis(&analysis, by_type);
+    display_curve_analysis(&analysis, options.by_type);
 
     // Determine target land count
-    let (target_lands, land_source) = determine_land_count(&deck_list, lands, excludes_lands);
-
-    // Detect format for mana base calculation
-    let format = detect_format_from_deck(&deck_list);
-    let algo = algorithm.to_algorithm();
+    let (target_lands, land_source) =
+        determine_land_count(deck_list, options.lands, deck_list.excludes_lands);
 
     // Calculate mana base recommendation (including dual land detection)
     display_progress("Calculating mana base recommendation...");
-    let mana_base = calculate_mana_base(&analysis, &deck_list, target_lands, format, algo);
+    let mana_base = calculate_mana_base(&analysis, deck_list, target_lands, format, algo);
 
     // Store results in analysis for export
     analysis.target_lands = Some(target_lands);
@@ -602,138 +803,962 @@ pub async fn handle_curve_command(
     display_mana_recommendation(&mana_base, target_lands, &land_source, algo);
 
     // Export if requested
-    if let Some(path) = export {
-        match CurveReportExporter::export(&analysis, &path) {
+    if let Some(path) = options.export {
+        match CurveReportExporter::export(&analysis, path) {
             Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
             Err(e) => display_error(&format!("Failed to export: {e}")),
         }
     }
 
-    if let Some(path) = json {
-        match JsonExporter::export(&analysis, &path) {
+    if let Some(path) = options.json {
+        match JsonExporter::export(&analysis, path) {
             Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
             Err(e) => display_error(&format!("Failed to export JSON: {e}")),
         }
     }
+
+    if let (Some(path), Some(matrix)) = (options.html, &matrix) {
+        let images = embed_card_images(deck_list).await;
+        match HtmlReportExporter::export(deck_list, &analysis, matrix, &images, path) {
+            Ok(_) => println!("{}", format!("HTML report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export HTML: {e}")),
+        }
+    }
 }
 
-/// Display mana base recommendation after curve analysis
-fn display_mana_recommendation(
-    mana_base: &ManaBase,
-    target_lands: u32,
-    land_source: &LandCountSource,
-    algorithm: Algorithm,
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_analyze_command(
+    input: String,
+    format: Option<String>,
+    lands: Option<u32>,
+    algorithm: AlgorithmArg,
+    no_curve: bool,
+    no_mana: bool,
+    no_synergy: bool,
+    no_legality: bool,
+    no_price: bool,
+    llm: bool,
+    llm_provider_arg: Option<LlmProviderArg>,
+    verbose: bool,
+    export: Option<String>,
+    json: Option<String>,
+    html: Option<String>,
+    api: ApiProviderArg,
+    no_fallback: bool,
+    excludes_lands: bool,
 ) {
+    if llm && no_synergy {
+        display_error("--llm needs synergy analysis; remove --no-synergy");
+        return;
+    }
+    let legality_format = match format.as_deref().map(parse_legality_format).transpose() {
+        Ok(format) => format,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
+    };
+
     println!();
-    println!("{}", "=== MANA BASE RECOMMENDATION ===".bold().green());
+    display_progress("Analyzing deck...");
     println!();
 
-    // Show how land count was determined
-    let source_str = match land_source {
-        LandCountSource::UserProvided => "user specified".to_string(),
-        LandCountSource::DetectedFromDeck(count) => format!("detected {count} lands in deck"),
-        LandCountSource::FormatDefault(fmt) => format!("{fmt} format default"),
+    // Hydrate once for every section
+    let deck_list = match parse_and_hydrate_deck(
+        &input,
+        api.to_provider(),
+        no_fallback,
+        excludes_lands,
+    )
+    .await
+    {
+        Ok(deck) => deck,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
     };
-    println!(
-        "{}: {} ({})",
-        "Target Lands".yellow(),
-        target_lands,
-        source_str.dimmed()
-    );
-    println!("{}: {}", "Algorithm".yellow(), algorithm.name());
-    println!();
 
-    // Dual lands (detected from deck)
-    if !mana_base.duals.is_empty() {
-        let total_duals: u32 = mana_base.duals.iter().map(|d| d.count).sum();
-        println!(
-            "{}",
-            format!("Dual/Multi-color Lands ({total_duals} detected):")
-                .cyan()
-                .bold()
-        );
+    let algorithm = algorithm.to_algorithm();
+    let options = ReportOptions {
+        curve: !no_curve,
+        mana: !no_curve && !no_mana,
+        synergy: !no_synergy,
+        legality: !no_legality,
+        price: !no_price,
+        legality_format,
+        lands,
+        algorithm,
+    };
+    let mut report = build_report(&deck_list, &options, load_combo_database());
+
+    if let Some(curve) = &report.curve {
+        display_curve_analysis(curve, false);
+        if let (Some(mana_base), Some(target_lands), Some(land_source)) =
+            (&curve.mana_base, curve.target_lands, &curve.land_source)
+        {
+            display_mana_recommendation(mana_base, target_lands, land_source, algorithm);
+        }
+    }
+    if let Some(matrix) = &report.synergy {
+        display_synergy_matrix(matrix, verbose);
+    }
+    match &report.legality {
+        Some(legality) => display_legality(legality),
+        None if options.legality => display_warning(
+            "Skipping legality: format unknown (pass --format, e.g. --format modern)",
+        ),
+        None => {}
+    }
+    if let Some(price) = &report.price {
+        display_price(price);
+    }
 
-        for dual in &mana_base.duals {
-            let colors_str: Vec<_> = dual.colors.iter().map(|c| c.symbol()).collect();
-            println!("  {} ({}): {}", dual.name, colors_str.join("/"), dual.count);
+    if let (true, Some(matrix)) = (llm, &report.synergy) {
+        let llm_provider = llm_provider_arg
+            .map(|p| p.to_provider())
+            .unwrap_or(crate::llm::LlmProvider::Anthropic);
+
+        display_progress(&format!(
+            "Running LLM-enhanced analysis with {}...",
+            llm_provider.name()
+        ));
+
+        let synergy_report = SynergyReportExporter::generate(matrix);
+        match crate::llm::create_llm_client(llm_provider) {
+            Ok(client) => match client
+                .analyze_synergies(&deck_list, matrix, &synergy_report)
+                .await
+            {
+                Ok(result) => {
+                    display_llm_insights(&result);
+                    report.llm_insights = Some(strip_ansi(&result.full_response));
+                }
+                Err(e) => display_error(&format!("LLM analysis failed: {e}")),
+            },
+            Err(e) => {
+                display_error(&format!("Failed to initialize LLM: {e}"));
+                display_warning(&format!(
+                    "Set {} in your environment.",
+                    llm_provider.env_var()
+                ));
+            }
         }
-        println!();
     }
 
-    // Basic lands
-    let total_basics: u32 = mana_base.basics.values().sum();
-    println!(
-        "{}",
-        format!("Basic Lands ({total_basics} recommended):")
-            .cyan()
-            .bold()
-    );
+    println!();
+    if let Some(path) = export {
+        match DeckReportExporter::export(&report, &path) {
+            Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export: {e}")),
+        }
+    }
 
-    let mut basics: Vec<_> = mana_base.basics.iter().collect();
-    basics.sort_by_key(|(c, _)| match c {
-        Color::White => 0,
-        Color::Blue => 1,
-        Color::Black => 2,
-        Color::Red => 3,
-        Color::Green => 4,
-        Color::Colorless => 5,
-    });
+    if let Some(path) = json {
+        match JsonExporter::export(&report, &path) {
+            Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+        }
+    }
 
-    for (color, count) in &basics {
-        let percentage = mana_base
-            .color_percentages
-            .get(color)
-            .copied()
-            .unwrap_or(0.0);
-        println!(
-            "  {} {}: {} ({:.1}%)",
-            color_to_emoji(color.symbol()),
-            color.basic_land(),
-            count,
-            percentage * 100.0
-        );
+    if let Some(path) = html {
+        let images = embed_card_images(&deck_list).await;
+        match HtmlReportExporter::export_report(&deck_list, &report, &images, &path) {
+            Ok(_) => println!("{}", format!("HTML report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export HTML: {e}")),
+        }
     }
+}
 
-    // Recommendations
-    if !mana_base.recommendations.is_empty() {
-        println!();
-        println!("{}", "Recommendations:".yellow());
-        for rec in &mana_base.recommendations {
-            println!("  {} {}", "!".yellow(), rec);
+/// Download the deck's card images to embed in an HTML report, warning about
+/// any that will be linked from Scryfall instead
+async fn embed_card_images(deck_list: &DeckList) -> HashMap<String, String> {
+    display_progress("Embedding card images...");
+    let mut cards: Vec<&Card> = Vec::new();
+    for card in deck_list.mainboard().filter_map(|e| e.card.as_ref()) {
+        if !cards.iter().any(|c| c.name == card.name) {
+            cards.push(card);
         }
     }
 
-    println!();
+    let images = fetch_image_data_uris(&cards).await;
+    let missing = cards
+        .iter()
+        .filter(|c| image_url(c).is_some() && !images.contains_key(&c.name))
+        .count();
+    if missing > 0 {
+        display_warning(&format!(
+            "{missing} card image{} couldn't be downloaded and will load from Scryfall",
+            if missing == 1 { "" } else { "s" }
+        ));
+    }
+    images
 }
 
-pub fn print_help() {
+/// Block until the watched decklist changes and parses cleanly, then return
+/// the re-hydrated deck and clear the screen for the next render
+async fn wait_for_deck_change(
+    path: &str,
+    watcher: &mut FileWatcher,
+    previous: &DeckList,
+    client: &dyn CardApi,
+) -> DeckList {
+    println!();
     println!(
         "{}",
-        "Scry - Magic: The Gathering Deck Building Utilities"
-            .bold()
-            .cyan()
+        format!("Watching {path} for changes (Ctrl+C to stop)...").dimmed()
     );
+
+    loop {
+        tokio::time::sleep(WATCH_INTERVAL).await;
+        if !watcher.changed() {
+            continue;
+        }
+
+        match reload_deck(path, previous, client).await {
+            Ok((deck_list, fetched)) => {
+                // Clear the screen so each render replaces the last
+                print!("\x1B[2J\x1B[H");
+                display_progress(&format!(
+                    "Reloaded {} at {} ({} new card{} fetched)",
+                    path,
+                    chrono::Local::now().format("%H:%M:%S"),
+                    fetched,
+                    if fetched == 1 { "" } else { "s" }
+                ));
+                for entry in deck_list.entries.iter().filter(|e| e.card.is_none()) {
+                    display_warning(&format!("Card not found: {}", entry.card_name));
+                }
+                return deck_list;
+            }
+            Err(e) => display_error(&e),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_composition_command(
+    input: String,
+    format: Option<FormatArg>,
+    verbose: bool,
+    export: Option<String>,
+    json: Option<String>,
+    api: ApiProviderArg,
+    no_fallback: bool,
+    excludes_lands: bool,
+) {
     println!();
-    println!("{}", "USAGE:".yellow());
-    println!("    scry <COMMAND>");
+    display_progress("Analyzing deck composition...");
     println!();
-    println!("{}", "COMMANDS:".yellow());
-    println!(
-        "    {}    Calculate optimal mana ratios for your deck",
-        "mana".green()
-    );
-    println!(
-        "    {}    Look up card information from Scryfall",
-        "card".green()
-    );
-    println!(
-        "    {} Analyze deck synergies from a decklist",
+
+    // Parse and hydrate the deck using shared helper
+    let deck_list = match parse_and_hydrate_deck(
+        &input,
+        api.to_provider(),
+        no_fallback,
+        excludes_lands,
+    )
+    .await
+    {
+        Ok(deck) => deck,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
+    };
+
+    // Use the requested template, otherwise infer it from the deck
+    let format = format
+        .map(|f| f.to_format())
+        .unwrap_or_else(|| detect_format_from_deck(&deck_list));
+
+    display_progress("Classifying card types and roles...");
+    let analysis = CompositionAnalyzer::new().analyze(&deck_list, format);
+
+    display_composition(&analysis, verbose);
+
+    // Export if requested
+    if let Some(path) = export {
+        match CompositionReportExporter::export(&analysis, &path) {
+            Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export: {e}")),
+        }
+    }
+
+    if let Some(path) = json {
+        match JsonExporter::export(&analysis, &path) {
+            Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_sections_command(
+    input: String,
+    format: Option<FormatArg>,
+    verbose: bool,
+    export: Option<String>,
+    json: Option<String>,
+    api: ApiProviderArg,
+    no_fallback: bool,
+    excludes_lands: bool,
+) {
+    println!();
+    display_progress("Analyzing sideboard and maybeboard...");
+    println!();
+
+    let deck_list = match parse_and_hydrate_deck(
+        &input,
+        api.to_provider(),
+        no_fallback,
+        excludes_lands,
+    )
+    .await
+    {
+        Ok(deck) => deck,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
+    };
+
+    let format = format
+        .map(|f| f.to_format())
+        .unwrap_or_else(|| detect_format_from_deck(&deck_list));
+
+    if deck_list.maybeboard().next().is_some() {
+        display_progress("Testing maybeboard swaps...");
+    }
+    let analysis = SectionAnalyzer::new().analyze(&deck_list, format, load_combo_database());
+
+    display_sections(&analysis, verbose);
+
+    // Export if requested
+    if let Some(path) = export {
+        match SectionReportExporter::export(&analysis, &path) {
+            Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export: {e}")),
+        }
+    }
+
+    if let Some(path) = json {
+        match JsonExporter::export(&analysis, &path) {
+            Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_limited_command(
+    input: String,
+    pool: bool,
+    splash: bool,
+    ratings: Option<String>,
+    set: Option<String>,
+    builds: usize,
+    export: Option<String>,
+    json: Option<String>,
+    api: ApiProviderArg,
+    no_fallback: bool,
+) {
+    println!();
+    display_progress(if pool {
+        "Building from sealed pool..."
+    } else {
+        "Evaluating Limited deck..."
+    });
+    println!();
+
+    let deck_list =
+        match parse_and_hydrate_deck(&input, api.to_provider(), no_fallback, false).await {
+            Ok(deck) => deck,
+            Err(e) => {
+                display_error(&e);
+                return;
+            }
+        };
+
+    // An explicit ratings file must load; set ratings are optional
+    let set = set
+        .map(|s| s.to_lowercase())
+        .or_else(|| detect_set(&deck_list));
+    let analyzer = if let Some(path) = ratings {
+        match CardRatings::load(std::path::Path::new(&path)) {
+            Ok(ratings) => LimitedAnalyzer::with_ratings(ratings, set),
+            Err(e) => {
+                display_error(&e);
+                return;
+            }
+        }
+    } else if let Some(set) = set {
+        match CardRatings::load_set(&set) {
+            Ok(ratings) => LimitedAnalyzer::with_ratings(ratings, Some(set)),
+            Err(_) => {
+                display_warning(&format!(
+                    "No ratings for {}; place a 17lands card ratings CSV at {}",
+                    set.to_uppercase(),
+                    CardRatings::default_dir()
+                        .join(format!("{set}.csv"))
+                        .display()
+                ));
+                LimitedAnalyzer::new()
+            }
+        }
+    } else {
+        display_warning("Could not detect the set; pass --set or --ratings to rate cards");
+        LimitedAnalyzer::new()
+    };
+
+    let analyzer = analyzer
+        .with_splash(splash)
+        .with_combos(load_combo_database());
+    if let Some(count) = analyzer.rating_count() {
+        display_progress(&format!("Loaded ratings for {count} cards"));
+    }
+    let mut report = analyzer.analyze(&deck_list, pool);
+    report.builds.truncate(builds);
+
+    display_limited(&report);
+
+    // Export if requested
+    if let Some(path) = export {
+        match LimitedReportExporter::export(&report, &path) {
+            Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export: {e}")),
+        }
+    }
+
+    if let Some(path) = json {
+        match JsonExporter::export(&report, &path) {
+            Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_recommend_command(
+    input: String,
+    cuts: usize,
+    adds: usize,
+    pool: Option<String>,
+    format: Option<FormatArg>,
+    export: Option<String>,
+    json: Option<String>,
+    api: ApiProviderArg,
+    no_fallback: bool,
+    excludes_lands: bool,
+) {
+    println!();
+    display_progress("Looking for cuts and additions...");
+    println!();
+
+    let deck_list = match parse_and_hydrate_deck(
+        &input,
+        api.to_provider(),
+        no_fallback,
+        excludes_lands,
+    )
+    .await
+    {
+        Ok(deck) => deck,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
+    };
+
+    if adds > 0 && pool.is_some() {
+        display_progress("Loading card pool...");
+    }
+    let card_pool = if adds > 0 {
+        match load_card_pool(pool.as_deref().map(std::path::Path::new)) {
+            Ok(cards) => cards,
+            Err(e) => {
+                display_error(&e);
+                return;
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    display_progress("Scoring cards...");
+    let recommendations = RecommendationEngine::with_counts(cuts, adds)
+        .with_combos(load_combo_database())
+        .recommend(&deck_list, format.map(|f| f.to_format()), &card_pool);
+
+    display_recommendations(&recommendations);
+
+    // Export if requested
+    if let Some(path) = export {
+        match RecommendationReportExporter::export(&recommendations, &path) {
+            Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export: {e}")),
+        }
+    }
+
+    if let Some(path) = json {
+        match JsonExporter::export(&recommendations, &path) {
+            Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+        }
+    }
+}
+
+pub async fn handle_dashboard_command(
+    input: String,
+    api: ApiProviderArg,
+    no_fallback: bool,
+    excludes_lands: bool,
+) {
+    println!();
+    let watcher = std::path::Path::new(&input)
+        .is_file()
+        .then(|| FileWatcher::new(&input));
+    let deck_list = match parse_and_hydrate_deck(
+        &input,
+        api.to_provider(),
+        no_fallback,
+        excludes_lands,
+    )
+    .await
+    {
+        Ok(deck) => deck,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
+    };
+
+    let client = create_client(api.to_provider(), !no_fallback);
+    if let Err(e) =
+        crate::tui::run_dashboard(deck_list, client, watcher, load_combo_database()).await
+    {
+        display_error(&format!("Dashboard failed: {e}"));
+    }
+}
+
+pub async fn handle_rules_command(action: RulesAction) {
+    match action {
+        RulesAction::Test {
+            name,
+            api,
+            no_fallback,
+        } => {
+            let client = create_client(api.to_provider(), !no_fallback);
+            let card = match client.search_card(&name).await {
+                Ok(card) => card,
+                Err(e) => {
+                    display_error(&e.message);
+                    return;
+                }
+            };
+
+            // Match against the same normalized text theme detection sees
+            let oracle = NormalizedOracle::new(&card);
+            let all_text = oracle.text();
+            let all_types = card.all_type_lines().join(" ");
+
+            println!();
+            println!("{}", format!("Theme rules for {}", card.name).bold().cyan());
+            println!("{}", "─".repeat(40));
+
+            let sources = [("built-in", &THEME_RULES[..]), ("custom", custom_rules())];
+            let mut any_matched = false;
+            for (source, rules) in sources {
+                for m in rules
+                    .iter()
+                    .filter_map(|r| match_rule(r, &all_text, &all_types))
+                {
+                    any_matched = true;
+                    let status = if m.passes() {
+                        "fires".green()
+                    } else {
+                        format!("below min confidence {:.2}", m.rule.min_confidence).yellow()
+                    };
+                    println!(
+                        "  {} {} ({}, confidence {:.2})",
+                        m.rule.theme.display_name().bold(),
+                        status,
+                        source,
+                        m.confidence
+                    );
+                    if let Some(role) = m.rule.role_hint {
+                        println!("    {}: {:?}", "Role hint".yellow(), role);
+                    }
+                    for pattern in &m.matched_patterns {
+                        println!("    {} {}", "matched".dimmed(), pattern);
+                    }
+                }
+            }
+            if !any_matched {
+                println!("  {}", "No theme rules matched this card.".dimmed());
+            }
+
+            let themes = detect_oracle_themes(&card, &oracle);
+            println!();
+            if themes.is_empty() {
+                println!("{}: none", "Detected themes".yellow());
+            } else {
+                let names: Vec<_> = themes
+                    .iter()
+                    .map(|(theme, _, _)| {
+                        format!(
+                            "{} ({:?})",
+                            theme.display_name(),
+                            classify_oracle_role(&card, &oracle, theme)
+                        )
+                    })
+                    .collect();
+                println!("{}: {}", "Detected themes".yellow(), names.join(", "));
+            }
+            println!();
+        }
+        RulesAction::List => {
+            match custom_rules_path() {
+                Some(path) => println!("{}: {}", "Rules file".yellow(), path.display()),
+                None => {
+                    let paths: Vec<_> = default_rules_paths()
+                        .iter()
+                        .map(|p| p.display().to_string())
+                        .collect();
+                    println!(
+                        "{}",
+                        format!(
+                            "No custom rules loaded. Create {} or pass --rules <file>.",
+                            paths.join(" or ")
+                        )
+                        .dimmed()
+                    );
+                    return;
+                }
+            }
+
+            for rule in custom_rules() {
+                let role = rule
+                    .role_hint
+                    .map(|r| format!("{r:?}"))
+                    .unwrap_or_else(|| "any".to_string());
+                println!(
+                    "  {} ({} role, {} oracle / {} type patterns, min confidence {:.2})",
+                    rule.theme.display_name().bold(),
+                    role,
+                    rule.oracle_patterns.len(),
+                    rule.type_patterns.len(),
+                    rule.min_confidence
+                );
+            }
+        }
+    }
+}
+
+pub fn handle_combos_command(action: ComboAction) {
+    let db_path = ComboDatabase::default_path();
+
+    match action {
+        ComboAction::Import { path } => {
+            display_progress(&format!("Importing combos from {path}..."));
+            let db = match ComboDatabase::import_spellbook(std::path::Path::new(&path)) {
+                Ok(db) => db,
+                Err(e) => {
+                    display_error(&e);
+                    return;
+                }
+            };
+
+            if db.is_empty() {
+                display_warning("No combos found in the export");
+                return;
+            }
+
+            match db.save(&db_path) {
+                Ok(()) => println!(
+                    "{}",
+                    format!("Imported {} combos to {}", db.len(), db_path.display()).green()
+                ),
+                Err(e) => display_error(&e),
+            }
+        }
+        ComboAction::Info => {
+            println!("{}: {}", "Database".yellow(), db_path.display());
+            match ComboDatabase::load(&db_path) {
+                Ok(db) => println!("{}: {}", "Combos".yellow(), db.len()),
+                Err(_) => println!(
+                    "{}",
+                    "No combos imported yet. Run: scry combos import <variants.json>".dimmed()
+                ),
+            }
+        }
+    }
+}
+
+pub async fn handle_cube_command(action: CubeAction) {
+    match action {
+        CubeAction::Analyze {
+            input,
+            legality,
+            export,
+            json,
+            api,
+            no_fallback,
+        } => {
+            if let Some(format) = &legality {
+                if !LEGALITY_FORMATS.contains(&format.to_lowercase().as_str()) {
+                    display_error(&format!(
+                        "Unknown format '{format}'. Choose one of: {}",
+                        LEGALITY_FORMATS.join(", ")
+                    ));
+                    return;
+                }
+            }
+
+            println!();
+            display_progress("Analyzing cube...");
+            println!();
+
+            let cube =
+                match parse_and_hydrate_deck(&input, api.to_provider(), no_fallback, false).await {
+                    Ok(cube) => cube,
+                    Err(e) => {
+                        display_error(&e);
+                        return;
+                    }
+                };
+
+            let analysis = CubeAnalyzer::with_legality(legality).analyze(&cube);
+
+            display_cube(&analysis);
+            if let Some(legality) = &analysis.legality {
+                display_legality(legality);
+            }
+
+            // Export if requested
+            if let Some(path) = export {
+                match CubeReportExporter::export(&analysis, &path) {
+                    Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+                    Err(e) => display_error(&format!("Failed to export: {e}")),
+                }
+            }
+
+            if let Some(path) = json {
+                match JsonExporter::export(&analysis, &path) {
+                    Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+                    Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+                }
+            }
+        }
+        CubeAction::Diff {
+            old,
+            new,
+            export,
+            json,
+            api,
+            no_fallback,
+        } => {
+            println!();
+            display_progress("Comparing cube versions...");
+            println!();
+
+            let mut cubes = Vec::new();
+            for input in [&old, &new] {
+                match parse_and_hydrate_deck(input, api.to_provider(), no_fallback, false).await {
+                    Ok(cube) => cubes.push(cube),
+                    Err(e) => {
+                        display_error(&e);
+                        return;
+                    }
+                }
+            }
+            let diff = diff_cubes(&cubes[0], &cubes[1]);
+
+            display_cube_diff(&diff);
+
+            // Export if requested
+            if let Some(path) = export {
+                match CubeReportExporter::export_diff(&diff, &path) {
+                    Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+                    Err(e) => display_error(&format!("Failed to export: {e}")),
+                }
+            }
+
+            if let Some(path) = json {
+                match JsonExporter::export(&diff, &path) {
+                    Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+                    Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+                }
+            }
+        }
+    }
+}
+
+/// Display mana base recommendation after curve analysis
+pub fn display_mana_recommendation(
+    mana_base: &ManaBase,
+    target_lands: u32,
+    land_source: &LandCountSource,
+    algorithm: Algorithm,
+) {
+    println!();
+    println!("{}", "=== MANA BASE RECOMMENDATION ===".bold().green());
+    println!();
+
+    // Show how land count was determined
+    let source_str = match land_source {
+        LandCountSource::UserProvided => "user specified".to_string(),
+        LandCountSource::DetectedFromDeck(count) => format!("detected {count} lands in deck"),
+        LandCountSource::FormatDefault(fmt) => format!("{fmt} format default"),
+    };
+    println!(
+        "{}: {} ({})",
+        "Target Lands".yellow(),
+        target_lands,
+        source_str.dimmed()
+    );
+    println!("{}: {}", "Algorithm".yellow(), algorithm.name());
+    let colors: Vec<Color> = mana_base.color_percentages.keys().copied().collect();
+    if !colors.is_empty() {
+        println!("{}: {}", "Colors".yellow(), color_label(&colors));
+    }
+    println!();
+
+    // Dual lands (detected from deck)
+    if !mana_base.duals.is_empty() {
+        let total_duals: u32 = mana_base.duals.iter().map(|d| d.count).sum();
+        println!(
+            "{}",
+            format!("Dual/Multi-color Lands ({total_duals} detected):")
+                .cyan()
+                .bold()
+        );
+
+        for dual in &mana_base.duals {
+            let colors_str: Vec<_> = dual.colors.iter().map(|c| c.symbol()).collect();
+            println!("  {} ({}): {}", dual.name, colors_str.join("/"), dual.count);
+        }
+        println!();
+    }
+
+    // Basic lands
+    let total_basics: u32 = mana_base.basics.values().sum();
+    println!(
+        "{}",
+        format!("Basic Lands ({total_basics} recommended):")
+            .cyan()
+            .bold()
+    );
+
+    let mut basics: Vec<_> = mana_base.basics.iter().collect();
+    basics.sort_by_key(|(c, _)| match c {
+        Color::White => 0,
+        Color::Blue => 1,
+        Color::Black => 2,
+        Color::Red => 3,
+        Color::Green => 4,
+        Color::Colorless => 5,
+    });
+
+    for (color, count) in &basics {
+        let percentage = mana_base
+            .color_percentages
+            .get(color)
+            .copied()
+            .unwrap_or(0.0);
+        println!(
+            "  {} {}: {} ({:.1}%)",
+            color_to_emoji(color.symbol()),
+            color.basic_land(),
+            count,
+            percentage * 100.0
+        );
+    }
+
+    // Recommendations
+    if !mana_base.recommendations.is_empty() {
+        println!();
+        println!("{}", "Recommendations:".yellow());
+        for rec in &mana_base.recommendations {
+            println!("  {} {}", "!".yellow(), rec);
+        }
+    }
+
+    println!();
+}
+
+pub fn print_help() {
+    println!(
+        "{}",
+        "Scry - Magic: The Gathering Deck Building Utilities"
+            .bold()
+            .cyan()
+    );
+    println!();
+    println!("{}", "USAGE:".yellow());
+    println!("    scry <COMMAND>");
+    println!();
+    println!("{}", "COMMANDS:".yellow());
+    println!(
+        "    {}    Calculate optimal mana ratios for your deck",
+        "mana".green()
+    );
+    println!(
+        "    {}    Look up card information from Scryfall",
+        "card".green()
+    );
+    println!(
+        "    {} Analyze deck synergies from a decklist",
         "synergy".green()
     );
     println!(
         "    {}   Analyze deck mana curve distribution",
         "curve".green()
     );
+    println!(
+        "    {} Run curve, mana, synergy, legality and price at once",
+        "analyze".green()
+    );
+    println!(
+        "    {} Break down card types and functional roles",
+        "composition".green()
+    );
+    println!(
+        "    {}  Summarize the sideboard and test maybeboard swaps",
+        "sections".green()
+    );
+    println!(
+        "    {}   Rate a Limited deck or build from a sealed pool",
+        "limited".green()
+    );
+    println!(
+        "    {} Suggest cuts and additions from synergy and curve",
+        "recommend".green()
+    );
+    println!(
+        "    {}    Edit a deck interactively with live analysis",
+        "repl".green()
+    );
+    println!(
+        "    {} Browse deck analysis in a full-screen dashboard",
+        "dashboard".green()
+    );
+    println!(
+        "    {}   Test which theme rules fire for a card",
+        "rules".green()
+    );
+    println!(
+        "    {}  Import and inspect the local combo database",
+        "combos".green()
+    );
+    println!(
+        "    {}    Analyze a cube list and compare cube versions",
+        "cube".green()
+    );
+    println!(
+        "    {}   Pick dual, tri and fetch lands for a mana base",
+        "lands".green()
+    );
     println!("    {}    Print this help message", "help".green());
     println!();
     println!("{}", "EXAMPLES:".yellow());
@@ -744,9 +1769,174 @@ pub fn print_help() {
     println!("    scry card --id <scryfall-id>        # Look up a card by ID");
     println!("    scry synergy -i deck.txt            # Analyze synergies from file");
     println!("    scry synergy -i https://moxfield.com/decks/xyz  # From Moxfield");
+    println!("    scry synergy -i deck.txt --graph deck.dot  # Export the synergy graph");
     println!("    scry curve -i deck.txt              # Analyze mana curve from file");
     println!("    scry curve -i deck.txt --by-type    # Show creatures vs non-creatures");
+    println!("    scry curve -i deck.txt --archetype aggro  # Compare against the aggro curve");
+    println!("    scry curve -i deck.txt --effective  # Bucket by effective mana value");
+    println!("    scry curve -i deck.txt --html deck.html  # Shareable HTML report");
+    println!("    scry curve -i deck.txt --watch      # Re-run every time the file is saved");
+    println!("    scry analyze -i deck.txt --html deck.html  # Everything in one pass");
+    println!("    scry composition -i deck.txt        # Count ramp, draw, removal and more");
+    println!("    scry sections -i deck.txt -v        # Sideboard roles and maybeboard swaps");
+    println!("    scry limited -i draft.txt --set mkm  # Rate a draft deck with 17lands data");
+    println!("    scry limited -i pool.txt --pool --splash  # Sealed builds, splashes included");
+    println!("    scry recommend -i deck.txt --pool oracle-cards.json  # Cuts and additions");
+    println!("    scry repl -i deck.txt               # Add, cut and swap cards interactively");
+    println!("    scry dashboard -i deck.txt          # Live dashboard, refreshes on save");
+    println!("    scry combos import variants.json    # Import a Commander Spellbook export");
+    println!("    scry cube analyze -i cube.txt       # Color balance and archetype support");
+    println!("    scry cube diff old.txt new.txt      # Cards added and removed");
+    println!("    scry lands -i deck.txt --budget 50  # Nonbasic lands within a budget");
+    println!("    scry lands -c UBR -f commander --collection owned.txt  # From your collection");
+    println!("    scry rules test \"Scute Swarm\"       # Show which theme rules fire for a card");
+    println!("    scry synergy -i deck.txt --rules my-rules.toml  # Add custom theme rules");
     println!();
     println!("{}", "For more information on a command, run:".dimmed());
     println!("    scry <COMMAND> --help");
 }
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_lands_command(
+    input: Option<String>,
+    colors: Option<String>,
+    format: Option<FormatArg>,
+    lands: Option<u32>,
+    legality: Option<String>,
+    budget: Option<f64>,
+    collection: Option<String>,
+    pool: Option<String>,
+    algorithm: AlgorithmArg,
+    export: Option<String>,
+    json: Option<String>,
+    api: ApiProviderArg,
+    no_fallback: bool,
+    excludes_lands: bool,
+) {
+    let legality = legality.map(|l| l.to_lowercase());
+    if let Some(format) = &legality {
+        if !LEGALITY_FORMATS.contains(&format.as_str()) {
+            display_error(&format!(
+                "Unknown format '{format}'. Choose one of: {}",
+                LEGALITY_FORMATS.join(", ")
+            ));
+            return;
+        }
+    }
+
+    println!();
+    display_progress("Optimizing mana base...");
+    println!();
+
+    // Colors, pip weights, land count, format and legality come from the deck
+    // when there is one, and from the flags otherwise
+    let (deck_colors, weights, target_lands, deck_format, legality) = if let Some(input) = input {
+        let deck_list =
+            match parse_and_hydrate_deck(&input, api.to_provider(), no_fallback, excludes_lands)
+                .await
+            {
+                Ok(deck) => deck,
+                Err(e) => {
+                    display_error(&e);
+                    return;
+                }
+            };
+        let pips = CurveAnalyzer::new().analyze(&deck_list).pip_breakdown;
+        let (target_lands, _) = determine_land_count(&deck_list, lands, excludes_lands);
+        let deck_format = format
+            .map(|f| f.to_format())
+            .unwrap_or_else(|| detect_format_from_deck(&deck_list));
+        let legality = legality.or_else(|| legality_format(&deck_list));
+        (
+            pips.colors(),
+            pips.to_mana_symbols(),
+            target_lands,
+            deck_format,
+            legality,
+        )
+    } else if let Some(colors) = colors {
+        let color_list = parse_colors(&colors);
+        if color_list.is_empty() {
+            display_error("No valid colors provided");
+            return;
+        }
+        let deck_format = format.map(|f| f.to_format()).unwrap_or(Format::Custom);
+        let weights = color_list.iter().map(|c| (*c, 20)).collect();
+        let legality = legality.or_else(|| match deck_format {
+            Format::Commander => Some("commander".to_string()),
+            Format::Standard => Some("standard".to_string()),
+            Format::Modern => Some("modern".to_string()),
+            Format::Limited | Format::Custom => None,
+        });
+        (
+            color_list,
+            weights,
+            lands.unwrap_or(deck_format.default_lands()),
+            deck_format,
+            legality,
+        )
+    } else {
+        display_error("Pass a decklist with --input or deck colors with --colors");
+        return;
+    };
+
+    if sorted_colors(&deck_colors).len() < 2 {
+        display_warning("Mono-colored decks don't need dual lands; only basics will be suggested");
+    }
+
+    let owned: Vec<(String, u32)> = match collection {
+        Some(path) => match TextDecklistParser::new().parse(&path).await {
+            Ok(list) => list
+                .entries
+                .into_iter()
+                .map(|e| (e.card_name, e.quantity))
+                .collect(),
+            Err(e) => {
+                display_error(&format!("Failed to read collection: {e}"));
+                return;
+            }
+        },
+        None => Vec::new(),
+    };
+
+    if pool.is_some() {
+        display_progress("Loading card pool...");
+    }
+    let card_pool = match load_card_pool(pool.as_deref().map(std::path::Path::new)) {
+        Ok(cards) => cards,
+        Err(e) => {
+            display_error(&e);
+            return;
+        }
+    };
+
+    let suite = ManaBaseOptimizer::new(deck_format)
+        .with_legality(legality)
+        .with_budget(budget)
+        .with_collection(owned)
+        .with_algorithm(algorithm.to_algorithm())
+        .optimize(&deck_colors, &weights, target_lands, &card_pool);
+
+    if suite.candidates == 0 {
+        display_warning(
+            "No eligible dual lands in the card pool; pass --pool with a Scryfall bulk data file (e.g. oracle-cards.json)",
+        );
+    }
+
+    display_land_suite(&suite);
+
+    // Export if requested
+    if let Some(path) = export {
+        match LandSuiteReportExporter::export(&suite, &path) {
+            Ok(_) => println!("{}", format!("Report saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export: {e}")),
+        }
+    }
+
+    if let Some(path) = json {
+        match JsonExporter::export(&suite, &path) {
+            Ok(_) => println!("{}", format!("JSON saved to: {path}").green()),
+            Err(e) => display_error(&format!("Failed to export JSON: {e}")),
+        }
+    }
+}
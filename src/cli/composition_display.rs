@@ -0,0 +1,85 @@
+use colored::Colorize;
+
+use crate::composition::{CompositionAnalysis, TargetStatus};
+
+const BAR_WIDTH: usize = 30;
+
+/// Display card type and role composition in the terminal
+pub fn display_composition(analysis: &CompositionAnalysis, verbose: bool) {
+    println!();
+    println!("{}", "=== DECK COMPOSITION ===".bold().green());
+    println!();
+
+    if let Some(name) = &analysis.deck_name {
+        println!("{}: {}", "Deck".yellow(), name);
+    }
+    if let Some(format) = &analysis.deck_format {
+        println!("{}: {}", "Format".yellow(), format);
+    }
+    println!("{}: {} cards", "Total".yellow(), analysis.total_cards);
+    println!();
+
+    // Card types
+    println!("{}", "Card Types:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    let max_count = analysis
+        .type_counts
+        .iter()
+        .map(|t| t.count)
+        .max()
+        .unwrap_or(1)
+        .max(1);
+    for type_count in analysis.type_counts.iter().filter(|t| t.count > 0) {
+        let bar_len = (type_count.count as f64 / max_count as f64 * BAR_WIDTH as f64) as usize;
+        println!(
+            "  {:<13} | {} {}",
+            type_count.card_type.name(),
+            "=".repeat(bar_len).green(),
+            format!(
+                "({}, {:.1}%)",
+                type_count.count,
+                type_count.percentage * 100.0
+            )
+            .dimmed()
+        );
+    }
+    println!();
+
+    // Functional roles
+    println!("{}", "Functional Roles:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    for role_count in &analysis.role_counts {
+        println!(
+            "  {:<15} {}",
+            role_count.role.name().bold(),
+            role_count.count
+        );
+        if verbose && !role_count.cards.is_empty() {
+            println!("    {}", role_count.cards.join(", ").dimmed());
+        }
+    }
+    println!();
+
+    // Template comparison
+    if !analysis.comparisons.is_empty() {
+        println!(
+            "{}",
+            format!("{} Template:", analysis.template_format)
+                .cyan()
+                .bold()
+        );
+        println!("{}", "-".repeat(60));
+        for comparison in &analysis.comparisons {
+            let line = format!(
+                "  {:<15} {:>3}  (target {}-{})",
+                comparison.label, comparison.count, comparison.min, comparison.max
+            );
+            match comparison.status {
+                TargetStatus::Below => println!("{}  {}", line.yellow(), "below target".yellow()),
+                TargetStatus::Above => println!("{}  {}", line.red(), "above target".red()),
+                TargetStatus::Within => println!("{}  {}", line, "ok".green()),
+            }
+        }
+        println!();
+    }
+}
@@ -0,0 +1,184 @@
+use colored::Colorize;
+
+use crate::cube::{ColorGroup, CubeAnalysis, CubeDiff, MAX_CURVE_CMC};
+
+/// Display a cube's color balance, curves, archetype support and list checks
+pub fn display_cube(analysis: &CubeAnalysis) {
+    println!();
+    println!("{}", "=== CUBE ANALYSIS ===".bold().green());
+    println!();
+
+    if let Some(name) = &analysis.cube_name {
+        println!("{}: {}", "Cube".yellow(), name);
+    }
+    println!(
+        "{}: {} ({} unique)",
+        "Cards".yellow(),
+        analysis.total_cards,
+        analysis.unique_cards
+    );
+    println!();
+
+    // Color balance and curves
+    println!("{}", "Color Balance:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    let header: Vec<String> = (0..=MAX_CURVE_CMC)
+        .map(|cmc| {
+            if cmc == MAX_CURVE_CMC {
+                format!("{cmc}+")
+            } else {
+                cmc.to_string()
+            }
+        })
+        .map(|label| format!("{label:>3}"))
+        .collect();
+    println!(
+        "  {:<12} {:>5} {:>6}  {}  {:>4}",
+        "",
+        "Cards",
+        "Share",
+        header.join(""),
+        "Avg"
+    );
+    for stats in &analysis.colors {
+        let curve = if stats.group == ColorGroup::Land {
+            " ".repeat(3 * (MAX_CURVE_CMC + 1))
+        } else {
+            stats
+                .curve
+                .iter()
+                .map(|count| format!("{count:>3}"))
+                .collect::<String>()
+        };
+        let average = if stats.group == ColorGroup::Land {
+            String::new()
+        } else {
+            format!("{:.2}", stats.average_cmc)
+        };
+        println!(
+            "  {:<12} {:>5} {:>5.1}%  {}  {:>4}",
+            stats.group.name().bold(),
+            stats.count,
+            stats.share * 100.0,
+            curve.dimmed(),
+            average
+        );
+    }
+    println!();
+
+    // Archetype support
+    println!("{}", "Archetype Support:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    for archetype in &analysis.archetypes {
+        let colors: String = archetype.colors.iter().map(|c| c.symbol()).collect();
+        let themes: Vec<String> = archetype
+            .themes
+            .iter()
+            .map(|t| format!("{} ({})", t.theme.display_name(), t.cards))
+            .collect();
+        println!(
+            "  {:<10} {:<3} {:>3} playables, {:>2} gold  {}",
+            archetype.name.bold(),
+            colors,
+            archetype.playables,
+            archetype.gold_cards,
+            if themes.is_empty() {
+                "no supported themes".yellow().to_string()
+            } else {
+                themes.join(", ")
+            }
+        );
+    }
+    println!();
+
+    // List checks
+    println!("{}", "Checks:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    if analysis.duplicates.is_empty() {
+        println!("  {}", "No duplicates".green());
+    }
+    for duplicate in &analysis.duplicates {
+        println!(
+            "  {} {} ({} copies)",
+            "✗".red(),
+            duplicate.name,
+            duplicate.copies
+        );
+    }
+    if !analysis.unknown_cards.is_empty() {
+        println!(
+            "  {} {}",
+            "No card data:".yellow(),
+            analysis.unknown_cards.join(", ").dimmed()
+        );
+    }
+    println!();
+}
+
+/// Display the cards added and removed between two cube versions
+pub fn display_cube_diff(diff: &CubeDiff) {
+    println!();
+    println!("{}", "=== CUBE DIFF ===".bold().green());
+    println!();
+
+    println!(
+        "{}: {} → {} cards",
+        "Size".yellow(),
+        diff.old_total,
+        diff.new_total
+    );
+    println!();
+
+    println!("{}", format!("Added ({}):", diff.added.len()).cyan().bold());
+    println!("{}", "-".repeat(60));
+    for change in &diff.added {
+        println!(
+            "  {} {}",
+            "+".green(),
+            quantity_name(change.quantity, &change.name)
+        );
+    }
+    println!();
+
+    println!(
+        "{}",
+        format!("Removed ({}):", diff.removed.len()).cyan().bold()
+    );
+    println!("{}", "-".repeat(60));
+    for change in &diff.removed {
+        println!(
+            "  {} {}",
+            "-".red(),
+            quantity_name(change.quantity, &change.name)
+        );
+    }
+    println!();
+
+    if !diff.colors.is_empty() {
+        println!("{}", "Color Balance Changes:".cyan().bold());
+        println!("{}", "-".repeat(60));
+        for change in &diff.colors {
+            let delta = change.after as i64 - change.before as i64;
+            let delta = format!("{delta:+}");
+            println!(
+                "  {:<12} {:>4} → {:<4} {}",
+                change.group.name().bold(),
+                change.before,
+                change.after,
+                if change.after > change.before {
+                    delta.green()
+                } else {
+                    delta.red()
+                }
+            );
+        }
+        println!();
+    }
+}
+
+fn quantity_name(quantity: u32, name: &str) -> String {
+    match quantity {
+        1 => name.to_string(),
+        n => format!("{n}x {name}"),
+    }
+}
@@ -1,6 +1,6 @@
 use colored::Colorize;
 
-use crate::curve::CurveAnalysis;
+use crate::curve::{BucketLoad, CurveAnalysis};
 
 const HISTOGRAM_WIDTH: usize = 40;
 const BAR_CHAR: &str = "=";
@@ -29,7 +29,12 @@ pub fn display_curve_analysis(analysis: &CurveAnalysis, by_type: bool) {
     println!();
 
     // ASCII Histogram
-    println!("{}", "Curve Distribution:".cyan().bold());
+    let title = if analysis.bucketed_by_effective {
+        "Curve Distribution (effective mana value):"
+    } else {
+        "Curve Distribution:"
+    };
+    println!("{}", title.cyan().bold());
     println!("{}", "-".repeat(60));
     println!();
 
@@ -43,6 +48,9 @@ pub fn display_curve_analysis(analysis: &CurveAnalysis, by_type: bool) {
 
     println!();
 
+    // Ideal curve comparison
+    display_curve_fit(analysis);
+
     // Statistics Summary
     println!("{}", "Statistics:".cyan().bold());
     println!("{}", "-".repeat(60));
@@ -57,6 +65,13 @@ pub fn display_curve_analysis(analysis: &CurveAnalysis, by_type: bool) {
         analysis.stats.median_cmc
     );
     println!("  {}: {}", "Mode CMC".yellow(), analysis.stats.mode_cmc);
+    if analysis.effective_costs.iter().any(|c| c.is_reduced()) {
+        println!(
+            "  {}: {:.2}",
+            "Effective Avg CMC".yellow(),
+            analysis.stats.effective_average_cmc
+        );
+    }
     println!();
     println!(
         "  {}: {}",
@@ -87,10 +102,49 @@ pub fn display_curve_analysis(analysis: &CurveAnalysis, by_type: bool) {
     );
     println!();
 
+    // Cost reductions
+    display_effective_costs(analysis);
+
     // Pip Breakdown
     display_pip_breakdown(analysis);
 }
 
+fn display_effective_costs(analysis: &CurveAnalysis) {
+    let mut reduced: Vec<_> = analysis
+        .effective_costs
+        .iter()
+        .filter(|c| c.is_reduced())
+        .collect();
+
+    if reduced.is_empty() {
+        return;
+    }
+
+    reduced.sort_by(|a, b| {
+        (b.cmc - b.effective_cmc)
+            .partial_cmp(&(a.cmc - a.effective_cmc))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    println!("{}", "Cost Reductions:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    for cost in reduced {
+        println!(
+            "  {}: {} → {} {}",
+            cost.card_name,
+            cost.cmc,
+            cost.effective_cmc,
+            format!(
+                "(turn {}, {})",
+                cost.cast_turn,
+                cost.reason.as_deref().unwrap_or("reduced")
+            )
+            .dimmed()
+        );
+    }
+    println!();
+}
+
 fn display_combined_histogram(analysis: &CurveAnalysis) {
     let max_count = analysis.max_count.max(1);
 
@@ -106,10 +160,11 @@ fn display_combined_histogram(analysis: &CurveAnalysis) {
             .unwrap_or(0.0)
             * 100.0;
         println!(
-            "  {:>2} | {} {}",
+            "  {:>2} | {} {}{}",
             bucket.cmc,
             bar.green(),
-            format!("({}, {:.1}%)", bucket.total_count, pct).dimmed()
+            format!("({}, {:.1}%)", bucket.total_count, pct).dimmed(),
+            load_marker(analysis, bucket.cmc)
         );
     }
 }
@@ -155,7 +210,7 @@ fn display_split_histogram(analysis: &CurveAnalysis) {
             .unwrap_or(0.0)
             * 100.0;
         println!(
-            "  {:>2} | {}{}  {}",
+            "  {:>2} | {}{}  {}{}",
             bucket.cmc,
             creature_bar.green(),
             non_creature_bar.cyan(),
@@ -163,11 +218,66 @@ fn display_split_histogram(analysis: &CurveAnalysis) {
                 "({}/{}, {:.1}%)",
                 bucket.creature_count, bucket.non_creature_count, pct
             )
-            .dimmed()
+            .dimmed(),
+            load_marker(analysis, bucket.cmc)
         );
     }
 }
 
+/// Marker shown after a histogram bar when its template slot is over- or under-loaded
+fn load_marker(analysis: &CurveAnalysis, cmc: u32) -> String {
+    let Some(deviation) = analysis
+        .curve_fit
+        .as_ref()
+        .and_then(|fit| fit.deviation_for(cmc))
+    else {
+        return String::new();
+    };
+
+    match deviation.load {
+        BucketLoad::Over => format!(" {}", "▲ over".red()),
+        BucketLoad::Under => format!(" {}", "▼ under".yellow()),
+        BucketLoad::OnTarget => String::new(),
+    }
+}
+
+fn display_curve_fit(analysis: &CurveAnalysis) {
+    let Some(fit) = &analysis.curve_fit else {
+        return;
+    };
+
+    println!(
+        "{}",
+        format!("Ideal Curve ({}):", fit.archetype).cyan().bold()
+    );
+    println!("{}", "-".repeat(60));
+    println!("  {}: {:.0}/100", "Fit Score".yellow(), fit.score);
+    if fit.flagged().next().is_none() {
+        println!(
+            "    {}",
+            "(every CMC slot is within 5% of the template)".dimmed()
+        );
+    }
+    println!();
+
+    for deviation in &fit.deviations {
+        let line = format!(
+            "  {:>3} | {:>3} cards ({:>5.1}%)  target {:>5.1}%  {:+.1}%",
+            deviation.label(),
+            deviation.actual_count,
+            deviation.actual_share * 100.0,
+            deviation.target_share * 100.0,
+            deviation.deviation * 100.0
+        );
+        match deviation.load {
+            BucketLoad::Over => println!("{}  {}", line.red(), "over-loaded".red()),
+            BucketLoad::Under => println!("{}  {}", line.yellow(), "under-loaded".yellow()),
+            BucketLoad::OnTarget => println!("{}", line.dimmed()),
+        }
+    }
+    println!();
+}
+
 fn display_pip_breakdown(analysis: &CurveAnalysis) {
     let pip = &analysis.pip_breakdown;
     let total = pip.total();
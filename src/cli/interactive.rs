@@ -3,7 +3,9 @@ use dialoguer::{Confirm, Input, MultiSelect, Select};
 use std::collections::HashMap;
 
 use crate::calculator::get_calculator;
-use crate::deck::{guild_name, Algorithm, Color, Deck, DualLand, Format, FormatPreset};
+use crate::deck::{
+    color_combination_name, color_label, Algorithm, Color, Deck, DualLand, Format, FormatPreset,
+};
 use crate::export::MarkdownExporter;
 
 use super::commands::run_calculation;
@@ -214,27 +216,11 @@ fn prompt_dual_lands(colors: &[Color]) -> Result<Vec<DualLand>, Box<dyn std::err
     for i in 0..colors.len() {
         for j in (i + 1)..colors.len() {
             let pair = vec![colors[i], colors[j]];
-            let name = guild_name(&pair)
-                .map(|g| g.to_string())
-                .unwrap_or_else(|| format!("{}/{}", colors[i].symbol(), colors[j].symbol()));
-
-            let count: u32 = Input::new()
-                .with_prompt(format!(
-                    "How many {} ({}/{}) lands?",
-                    name,
-                    colors[i].symbol(),
-                    colors[j].symbol()
-                ))
-                .default(0)
-                .interact_text()?;
-
-            if count > 0 {
-                dual_lands.push(DualLand::new(name, pair, count));
-            }
+            dual_lands.extend(prompt_multicolor_land_count(&pair, "lands")?);
         }
     }
 
-    // If 3+ colors, ask about tri-lands
+    // If 3+ colors, ask about tri-lands for each three-color combination
     if colors.len() >= 3 {
         let has_tri = Confirm::new()
             .with_prompt("Do you have any tri-color lands?")
@@ -242,24 +228,49 @@ fn prompt_dual_lands(colors: &[Color]) -> Result<Vec<DualLand>, Box<dyn std::err
             .interact()?;
 
         if has_tri {
-            let name: String = Input::new()
-                .with_prompt("Name of tri-land (e.g., 'Command Tower')")
-                .interact_text()?;
-
-            let count: u32 = Input::new()
-                .with_prompt("How many copies?")
-                .default(1)
-                .interact_text()?;
-
-            if count > 0 {
-                dual_lands.push(DualLand::new(name, colors.to_vec(), count));
+            for i in 0..colors.len() {
+                for j in (i + 1)..colors.len() {
+                    for k in (j + 1)..colors.len() {
+                        let triple = vec![colors[i], colors[j], colors[k]];
+                        dual_lands.extend(prompt_multicolor_land_count(&triple, "tri-lands")?);
+                    }
+                }
             }
         }
     }
 
+    // Lands that tap for every color the deck plays (Command Tower, City of Brass)
+    if colors.len() >= 4 {
+        dual_lands.extend(prompt_multicolor_land_count(
+            colors,
+            "lands that make all these colors",
+        )?);
+    }
+
     Ok(dual_lands)
 }
 
+/// Ask how many lands producing `colors` the deck runs, named for the color
+/// combination (e.g. "How many U/B/R (Grixis) tri-lands?")
+fn prompt_multicolor_land_count(
+    colors: &[Color],
+    kind: &str,
+) -> Result<Option<DualLand>, Box<dyn std::error::Error>> {
+    let name = color_combination_name(colors)
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| {
+            let symbols: Vec<_> = colors.iter().map(|c| c.symbol()).collect();
+            symbols.join("/")
+        });
+
+    let count: u32 = Input::new()
+        .with_prompt(format!("How many {} {kind}?", color_label(colors)))
+        .default(0)
+        .interact_text()?;
+
+    Ok((count > 0).then(|| DualLand::new(name, colors.to_vec(), count)))
+}
+
 fn prompt_export() -> Result<Option<String>, Box<dyn std::error::Error>> {
     let should_export = Confirm::new()
         .with_prompt("Export results to file?")
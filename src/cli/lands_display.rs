@@ -0,0 +1,101 @@
+use colored::Colorize;
+
+use crate::deck::color_label;
+use crate::lands::{LandEntry, LandSuite, SuggestedLand};
+
+/// Display an optimized land suite in the terminal
+pub fn display_land_suite(suite: &LandSuite) {
+    println!();
+    println!("{}", "=== MANA BASE OPTIMIZER ===".bold().green());
+    println!();
+
+    println!("{}: {}", "Colors".yellow(), color_label(&suite.colors));
+    println!("{}: {}", "Format".yellow(), suite.format.name());
+    if let Some(legality) = &suite.legality {
+        println!("{}: {}", "Legal in".yellow(), legality);
+    }
+    println!("{}: {}", "Lands".yellow(), suite.total_lands);
+    println!(
+        "{}: {} eligible",
+        "Candidate lands".yellow(),
+        suite.candidates
+    );
+    println!();
+
+    let nonbasic_count: u32 = suite.nonbasics.iter().map(|l| l.copies).sum();
+    println!(
+        "{}",
+        format!("Nonbasic Lands ({nonbasic_count}):").cyan().bold()
+    );
+    println!("{}", "-".repeat(60));
+    if suite.nonbasics.is_empty() {
+        println!("  {}", "None".dimmed());
+    }
+    for land in &suite.nonbasics {
+        println!("  {}", land_line(land));
+    }
+    println!();
+
+    let basic_count: u32 = suite.basics.iter().map(|b| b.count).sum();
+    println!("{}", format!("Basic Lands ({basic_count}):").cyan().bold());
+    println!("{}", "-".repeat(60));
+    for basic in &suite.basics {
+        println!("  {}x {}", basic.count, basic.color.basic_land());
+    }
+    println!();
+
+    println!("{}", "Colored Sources:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    for sources in &suite.sources {
+        let line = format!(
+            "  {:<6} {:>3}  ({} basic, {} nonbasic; target {})",
+            sources.color.name(),
+            sources.total,
+            sources.basics,
+            sources.nonbasics,
+            sources.target
+        );
+        if sources.meets_target() {
+            println!("{}  {}", line, "ok".green());
+        } else {
+            println!("{}  {}", line.yellow(), "below target".yellow());
+        }
+    }
+    println!();
+
+    if suite.tapped > 0 {
+        println!("{}: {}", "Tapped lands".yellow(), suite.tapped);
+    }
+    match suite.budget {
+        Some(budget) => println!(
+            "{}: ${:.2} of ${:.2} budget",
+            "Cost".yellow(),
+            suite.cost_usd,
+            budget
+        ),
+        None => println!("{}: ${:.2}", "Cost".yellow(), suite.cost_usd),
+    }
+}
+
+fn land_line(land: &SuggestedLand) -> String {
+    let symbols: Vec<&str> = land.colors.iter().map(|c| c.symbol()).collect();
+    let mut line = format!(
+        "{}x {:<28} {:<6} {:<10}",
+        land.copies,
+        land.name,
+        symbols.join("/"),
+        land.kind.name()
+    );
+    match land.entry {
+        LandEntry::Tapped => line.push_str(&format!(" {}", "tapped".red())),
+        LandEntry::Conditional => line.push_str(&format!(" {}", "conditional".yellow())),
+        LandEntry::Untapped => line.push_str(&format!(" {}", "untapped".green())),
+    }
+    if land.owned > 0 {
+        line.push_str(&format!(" {}", format!("({} owned)", land.owned).dimmed()));
+    }
+    if let Some(usd) = land.unit_usd {
+        line.push_str(&format!(" ${usd:.2}"));
+    }
+    line
+}
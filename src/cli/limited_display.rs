@@ -0,0 +1,194 @@
+use colored::Colorize;
+
+use crate::composition::TargetStatus;
+use crate::curve::BucketLoad;
+use crate::limited::{grade, LimitedReport, RatedCard};
+
+/// Display a Limited deck evaluation or sealed pool builds in the terminal
+pub fn display_limited(report: &LimitedReport) {
+    println!();
+    println!("{}", "=== LIMITED ANALYSIS ===".bold().green());
+    println!();
+
+    if let Some(name) = &report.deck_name {
+        println!("{}: {}", "Deck".yellow(), name);
+    }
+    match (&report.set, &report.metric) {
+        (Some(set), Some(metric)) => println!(
+            "{}: {} ({})",
+            "Ratings".yellow(),
+            set.to_uppercase(),
+            metric
+        ),
+        (None, Some(metric)) => println!("{}: {}", "Ratings".yellow(), metric),
+        _ => println!("{}: {}", "Ratings".yellow(), "none loaded".dimmed()),
+    }
+    println!();
+
+    if let Some(evaluation) = &report.evaluation {
+        println!("{}: {} cards", "Maindeck".yellow(), evaluation.total_cards);
+        if let Some(average) = evaluation.average_rating {
+            println!(
+                "{}: {} ({:.0}th percentile, {} of {} cards rated)",
+                "Average rating".yellow(),
+                grade(average).bold(),
+                average * 100.0,
+                evaluation.rated_cards,
+                evaluation.rated_cards as usize + evaluation.unrated.len()
+            );
+        }
+        println!();
+
+        println!("{}", "Limited Norms:".cyan().bold());
+        println!("{}", "-".repeat(60));
+        for comparison in &evaluation.comparisons {
+            let line = format!(
+                "  {:<15} {:>3}  (target {}-{})",
+                comparison.label, comparison.count, comparison.min, comparison.max
+            );
+            match comparison.status {
+                TargetStatus::Below => println!("{}  {}", line.yellow(), "below target".yellow()),
+                TargetStatus::Above => println!("{}  {}", line.red(), "above target".red()),
+                TargetStatus::Within => println!("{}  {}", line, "ok".green()),
+            }
+        }
+        println!(
+            "  {:<15} {:>3.0}/100",
+            "Curve fit", evaluation.curve_fit.score
+        );
+        for deviation in &evaluation.curve_fit.deviations {
+            match deviation.load {
+                BucketLoad::Over => println!(
+                    "    {}",
+                    format!("Too many cards at {}", deviation.label()).yellow()
+                ),
+                BucketLoad::Under => println!(
+                    "    {}",
+                    format!("Too few cards at {}", deviation.label()).yellow()
+                ),
+                BucketLoad::OnTarget => {}
+            }
+        }
+        println!();
+
+        if !evaluation.best.is_empty() {
+            println!("{}", "Best Cards:".cyan().bold());
+            println!("{}", "-".repeat(60));
+            for card in &evaluation.best {
+                println!("  {}", rated_line(card));
+            }
+            println!();
+
+            println!("{}", "Weakest Cards:".cyan().bold());
+            println!("{}", "-".repeat(60));
+            for card in &evaluation.weakest {
+                println!("  {}", rated_line(card));
+            }
+            println!();
+        }
+
+        if !evaluation.upgrades.is_empty() {
+            println!("{}", "Sideboard Upgrades:".cyan().bold());
+            println!("{}", "-".repeat(60));
+            for upgrade in &evaluation.upgrades {
+                println!(
+                    "  {} {} {} {}",
+                    upgrade.add.green(),
+                    "for".dimmed(),
+                    upgrade.cut.red(),
+                    format!("(+{:.0} percentile)", upgrade.gain * 100.0).dimmed()
+                );
+            }
+            println!();
+        }
+
+        if !evaluation.unrated.is_empty() && evaluation.rated_cards > 0 {
+            println!(
+                "{} {}",
+                "Unrated:".yellow(),
+                evaluation.unrated.join(", ").dimmed()
+            );
+            println!();
+        }
+    }
+
+    if !report.builds.is_empty() {
+        println!("{}", "Suggested Builds:".cyan().bold());
+        println!("{}", "-".repeat(60));
+        for (rank, build) in report.builds.iter().enumerate() {
+            let colors: String = build.colors.iter().map(|c| c.symbol()).collect();
+            let splash = build
+                .splash
+                .map(|c| format!(" splashing {}", c.symbol()))
+                .unwrap_or_default();
+            println!(
+                "  {}. {} ({}){}",
+                rank + 1,
+                build.name.bold(),
+                colors,
+                splash.magenta()
+            );
+            println!(
+                "     {} {:.0}/100  {} {:.0}%  {} {:.0}/100  {} {:.0}%",
+                "Score:".yellow(),
+                build.score,
+                "Quality:".yellow(),
+                build.quality * 100.0,
+                "Curve fit:".yellow(),
+                build.curve_fit,
+                "Synergy density:".yellow(),
+                build.synergy_density * 100.0
+            );
+            println!(
+                "     {} {}  {} {}  {} {}",
+                "Playables:".yellow(),
+                build.playables,
+                "Creatures:".yellow(),
+                build.creatures,
+                "Removal:".yellow(),
+                build.removal
+            );
+            if !build.lands.is_empty() {
+                let lands: Vec<String> = build
+                    .lands
+                    .iter()
+                    .map(|l| format!("{} {}", l.count, l.color.basic_land()))
+                    .collect();
+                println!("     {} {}", "Lands:".yellow(), lands.join(", "));
+            }
+            if !build.splash_cards.is_empty() {
+                println!(
+                    "     {} {}",
+                    "Splash:".yellow(),
+                    build.splash_cards.join(", ")
+                );
+            }
+            let cards: Vec<String> = build
+                .cards
+                .iter()
+                .map(|c| match c.quantity {
+                    1 => c.name.clone(),
+                    n => format!("{n}x {}", c.name),
+                })
+                .collect();
+            println!("     {}", cards.join(", ").dimmed());
+        }
+        println!();
+    }
+}
+
+/// A card's name with its letter grade and percentile
+fn rated_line(card: &RatedCard) -> String {
+    let rating = card.rating.unwrap_or(0.0);
+    let value = card
+        .value
+        .map(|v| format!("  ({v:.1})"))
+        .unwrap_or_default();
+    format!(
+        "{:<2} {:<30} {:>3.0}%{}",
+        grade(rating).bold(),
+        card.name,
+        rating * 100.0,
+        value.dimmed()
+    )
+}
@@ -1,8 +1,17 @@
 pub mod args;
 pub mod commands;
+pub mod composition_display;
+pub mod cube_display;
 pub mod curve_display;
 pub mod interactive;
+pub mod lands_display;
+pub mod limited_display;
+pub mod recommend_display;
+pub mod repl;
+pub mod report_display;
+pub mod sections_display;
 pub mod synergy_display;
 
 pub use args::*;
 pub use commands::*;
+pub use repl::handle_repl_command;
@@ -0,0 +1,78 @@
+use colored::Colorize;
+
+use crate::recommend::Recommendations;
+
+/// Display proposed cuts and additions in the terminal
+pub fn display_recommendations(recommendations: &Recommendations) {
+    println!();
+    println!("{}", "=== RECOMMENDATIONS ===".bold().green());
+    println!();
+
+    if let Some(name) = &recommendations.deck_name {
+        println!("{}: {}", "Deck".yellow(), name);
+    }
+    if let Some(theme) = &recommendations.primary_theme {
+        println!("{}: {}", "Primary Theme".yellow(), theme.display_name());
+    }
+    println!();
+
+    println!("{}", "Suggested Cuts:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    if recommendations.cuts.is_empty() {
+        println!("  {}", "No cards to cut.".dimmed());
+    }
+    for (i, cut) in recommendations.cuts.iter().enumerate() {
+        println!(
+            "  {} {} {}",
+            format!("{}.", i + 1).yellow(),
+            cut.card.bold(),
+            format!(
+                "(keep score {:.0}: synergy {:.0}, curve {:.0}, role {:.0})",
+                cut.score * 100.0,
+                cut.synergy * 100.0,
+                cut.curve * 100.0,
+                cut.role * 100.0
+            )
+            .dimmed()
+        );
+        for reason in &cut.reasons {
+            println!("     - {reason}");
+        }
+    }
+    println!();
+
+    println!("{}", "Suggested Additions:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    if recommendations.pool_size == 0 {
+        println!(
+            "  {}",
+            "No card pool available. Look up cards or pass --pool with a Scryfall bulk data file."
+                .dimmed()
+        );
+    } else if recommendations.adds.is_empty() {
+        println!(
+            "  {}",
+            format!(
+                "None of the {} cards in the pool add synergy to the primary theme.",
+                recommendations.pool_size
+            )
+            .dimmed()
+        );
+    }
+    for (i, add) in recommendations.adds.iter().enumerate() {
+        println!(
+            "  {} {} {} {}",
+            format!("{}.", i + 1).yellow(),
+            add.card.bold(),
+            add.mana_cost.as_deref().unwrap_or("").dimmed(),
+            format!(
+                "(+{} synergies, {} with the primary theme)",
+                add.new_edges, add.primary_edges
+            )
+            .green()
+        );
+        println!("     {} {}", "With:".dimmed(), add.partners.join(", "));
+        println!("     - {}", add.reason);
+    }
+    println!();
+}
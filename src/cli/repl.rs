@@ -0,0 +1,594 @@
+use colored::Colorize;
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+
+use crate::api::traits::CardApi;
+use crate::api::{create_client, ApiProvider, Card};
+use crate::combo::ComboDatabase;
+use crate::curve::{
+    calculate_mana_base, detect_format_from_deck, determine_land_count, CurveAnalysis,
+    CurveAnalyzer, CurveArchetype, CurveFit,
+};
+use crate::deck::Algorithm;
+use crate::input::{DeckEntry, DeckList, DeckSection, DeckSource};
+use crate::synergy::{AnalysisCache, RuleBasedDetector, SynergyMatrix};
+
+use super::commands::{display_mana_recommendation, load_combo_database, parse_and_hydrate_deck};
+use super::curve_display::display_curve_analysis;
+use super::synergy_display::{display_error, display_progress, display_synergy_matrix};
+
+/// Maximum number of deck states kept for `undo`
+const UNDO_LIMIT: usize = 50;
+
+/// A single command entered at the REPL prompt
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplCommand {
+    /// Add copies of a card to the mainboard
+    Add {
+        quantity: u32,
+        name: String,
+    },
+    /// Remove copies of a card (all copies when no quantity is given)
+    Cut {
+        quantity: Option<u32>,
+        name: String,
+    },
+    /// Replace every copy of one card with the same number of another
+    Swap {
+        out: String,
+        into: String,
+    },
+    List,
+    Curve,
+    Synergy,
+    Mana,
+    Undo,
+    Save(Option<String>),
+    Help,
+    Quit,
+}
+
+impl ReplCommand {
+    /// Parse a line of input into a command
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        let (verb, rest) = match line.split_once(char::is_whitespace) {
+            Some((verb, rest)) => (verb, rest.trim()),
+            None => (line, ""),
+        };
+
+        match verb.to_lowercase().as_str() {
+            "add" => {
+                let (quantity, name) = split_quantity(rest);
+                if name.is_empty() {
+                    return Err("Usage: add [N] <card name>".to_string());
+                }
+                Ok(ReplCommand::Add {
+                    quantity: quantity.unwrap_or(1),
+                    name,
+                })
+            }
+            "cut" | "remove" => {
+                let (quantity, name) = split_quantity(rest);
+                if name.is_empty() {
+                    return Err("Usage: cut [N] <card name>".to_string());
+                }
+                Ok(ReplCommand::Cut { quantity, name })
+            }
+            "swap" => match rest.split_once("->") {
+                Some((out, into)) if !out.trim().is_empty() && !into.trim().is_empty() => {
+                    Ok(ReplCommand::Swap {
+                        out: out.trim().to_string(),
+                        into: into.trim().to_string(),
+                    })
+                }
+                _ => Err("Usage: swap <card out> -> <card in>".to_string()),
+            },
+            "list" | "ls" => Ok(ReplCommand::List),
+            "curve" => Ok(ReplCommand::Curve),
+            "synergy" => Ok(ReplCommand::Synergy),
+            "mana" => Ok(ReplCommand::Mana),
+            "undo" => Ok(ReplCommand::Undo),
+            "save" => Ok(ReplCommand::Save(
+                Some(rest.to_string()).filter(|p| !p.is_empty()),
+            )),
+            "help" | "?" => Ok(ReplCommand::Help),
+            "quit" | "exit" | "q" => Ok(ReplCommand::Quit),
+            "" => Err(String::new()),
+            other => Err(format!(
+                "Unknown command '{other}'. Type 'help' for commands."
+            )),
+        }
+    }
+}
+
+/// Split an optional leading quantity ("2 Counterspell", "2x Counterspell") from a card name
+fn split_quantity(input: &str) -> (Option<u32>, String) {
+    if let Some((first, rest)) = input.split_once(char::is_whitespace) {
+        if let Ok(quantity) = first.trim_end_matches(['x', 'X']).parse::<u32>() {
+            return (Some(quantity), rest.trim().to_string());
+        }
+    }
+    (None, input.to_string())
+}
+
+/// Snapshot of the numbers shown after each edit, used to report deltas
+#[derive(Debug, Clone, Default)]
+struct SessionSummary {
+    total_cards: u32,
+    land_count: u32,
+    average_cmc: f64,
+    themes: Vec<String>,
+}
+
+/// An in-progress deck editing session
+pub struct ReplSession {
+    pub deck_list: DeckList,
+    history: Vec<DeckList>,
+    client: Box<dyn CardApi>,
+    detector: RuleBasedDetector,
+    /// Per-card analysis and edges kept between edits
+    synergy_cache: AnalysisCache,
+    curve: CurveAnalysis,
+    matrix: SynergyMatrix,
+}
+
+impl ReplSession {
+    pub fn new(deck_list: DeckList, client: Box<dyn CardApi>, combos: Arc<ComboDatabase>) -> Self {
+        let mut session = Self {
+            deck_list,
+            history: Vec::new(),
+            client,
+            detector: RuleBasedDetector::new().with_combos(combos),
+            synergy_cache: AnalysisCache::default(),
+            curve: CurveAnalysis::new(),
+            matrix: SynergyMatrix::new(),
+        };
+        session.refresh();
+        session
+    }
+
+    /// Re-run the curve analyzer and synergy detector on the current deck,
+    /// re-analyzing only the cards that changed since the last refresh
+    fn refresh(&mut self) {
+        self.curve = CurveAnalyzer::new().analyze(&self.deck_list);
+        self.matrix = self
+            .detector
+            .analyze_cached(&self.deck_list, &mut self.synergy_cache);
+    }
+
+    fn summary(&self) -> SessionSummary {
+        SessionSummary {
+            total_cards: self.deck_list.mainboard().map(|e| e.quantity).sum(),
+            land_count: self.deck_list.count_lands(),
+            average_cmc: self.curve.stats.average_cmc,
+            themes: self
+                .matrix
+                .detected_themes
+                .iter()
+                .take(3)
+                .map(|t| t.theme.display_name())
+                .collect(),
+        }
+    }
+
+    /// Save the current state so the next edit can be undone
+    fn checkpoint(&mut self) {
+        self.history.push(self.deck_list.clone());
+        if self.history.len() > UNDO_LIMIT {
+            self.history.remove(0);
+        }
+    }
+
+    /// Find a card already present in the deck (case-insensitive)
+    fn find_entry(&self, name: &str) -> Option<&DeckEntry> {
+        self.deck_list
+            .entries
+            .iter()
+            .find(|e| e.card_name.eq_ignore_ascii_case(name))
+    }
+
+    /// Look up card data, reusing hydrated entries before hitting the card cache or API
+    async fn resolve_card(&self, name: &str) -> Result<Card, String> {
+        if let Some(card) = self.find_entry(name).and_then(|e| e.card.clone()) {
+            return Ok(card);
+        }
+
+        self.client
+            .search_card(name)
+            .await
+            .map_err(|e| format!("Card not found: {name} ({})", e.message))
+    }
+
+    /// Add copies of a hydrated card to the mainboard
+    pub fn add_card(&mut self, quantity: u32, card: Card) {
+        self.checkpoint();
+
+        if let Some(entry) = self.deck_list.entries.iter_mut().find(|e| {
+            e.section == DeckSection::Mainboard && e.card_name.eq_ignore_ascii_case(&card.name)
+        }) {
+            entry.quantity += quantity;
+            return;
+        }
+
+        self.deck_list.entries.push(DeckEntry {
+            quantity,
+            card_name: card.name.clone(),
+            card: Some(card),
+            section: DeckSection::Mainboard,
+        });
+    }
+
+    /// Remove copies of a card, returning how many were removed
+    pub fn cut_card(&mut self, name: &str, quantity: Option<u32>) -> Result<u32, String> {
+        let index = self
+            .deck_list
+            .entries
+            .iter()
+            .position(|e| {
+                e.section != DeckSection::Sideboard
+                    && e.section != DeckSection::Maybeboard
+                    && e.card_name.eq_ignore_ascii_case(name)
+            })
+            .ok_or_else(|| format!("{name} is not in the deck"))?;
+
+        self.checkpoint();
+
+        let entry = &mut self.deck_list.entries[index];
+        let removed = quantity.unwrap_or(entry.quantity).min(entry.quantity);
+        entry.quantity -= removed;
+        if entry.quantity == 0 {
+            self.deck_list.entries.remove(index);
+        }
+
+        Ok(removed)
+    }
+
+    /// Restore the deck to the state before the last edit
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(previous) => {
+                self.deck_list = previous;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Run a parsed command, returning false when the session should end
+    async fn execute(&mut self, command: ReplCommand) -> bool {
+        let before = self.summary();
+
+        match command {
+            ReplCommand::Add { quantity, name } => match self.resolve_card(&name).await {
+                Ok(card) => {
+                    let card_name = card.name.clone();
+                    self.add_card(quantity, card);
+                    self.after_edit(&before, &format!("Added {quantity} {card_name}"));
+                }
+                Err(e) => display_error(&e),
+            },
+            ReplCommand::Cut { quantity, name } => match self.cut_card(&name, quantity) {
+                Ok(removed) => self.after_edit(&before, &format!("Cut {removed} {name}")),
+                Err(e) => display_error(&e),
+            },
+            ReplCommand::Swap { out, into } => {
+                let card = match self.resolve_card(&into).await {
+                    Ok(card) => card,
+                    Err(e) => {
+                        display_error(&e);
+                        return true;
+                    }
+                };
+                match self.cut_card(&out, None) {
+                    Ok(removed) => {
+                        let card_name = card.name.clone();
+                        self.add_card(removed, card);
+                        // Collapse the cut and the add into a single undo step
+                        self.history.pop();
+                        self.after_edit(&before, &format!("Swapped {out} for {card_name}"));
+                    }
+                    Err(e) => display_error(&e),
+                }
+            }
+            ReplCommand::List => self.display_list(),
+            ReplCommand::Curve => self.display_curve(),
+            ReplCommand::Synergy => display_synergy_matrix(&self.matrix, false),
+            ReplCommand::Mana => self.display_mana(),
+            ReplCommand::Undo => {
+                if self.undo() {
+                    self.after_edit(&before, "Undid last change");
+                } else {
+                    display_error("Nothing to undo");
+                }
+            }
+            ReplCommand::Save(path) => self.save(path),
+            ReplCommand::Help => display_repl_help(),
+            ReplCommand::Quit => return false,
+        }
+
+        true
+    }
+
+    /// Re-analyze after an edit and show what changed
+    fn after_edit(&mut self, before: &SessionSummary, message: &str) {
+        self.refresh();
+        let after = self.summary();
+
+        let card_delta = after.total_cards as i64 - before.total_cards as i64;
+        let cmc_delta = after.average_cmc - before.average_cmc;
+
+        println!("{} {}", "✓".green(), message);
+        println!(
+            "  {} cards ({}), {} lands, avg CMC {:.2} ({})",
+            after.total_cards,
+            format_delta(card_delta as f64, 0),
+            after.land_count,
+            after.average_cmc,
+            format_delta(cmc_delta, 2)
+        );
+
+        if after.themes != before.themes {
+            let themes = if after.themes.is_empty() {
+                "none".to_string()
+            } else {
+                after.themes.join(", ")
+            };
+            println!("  {}: {}", "Themes".yellow(), themes);
+        }
+    }
+
+    fn display_list(&self) {
+        println!();
+        for entry in &self.deck_list.entries {
+            let section = match entry.section {
+                DeckSection::Commander => " (commander)".dimmed().to_string(),
+                DeckSection::Sideboard => " (sideboard)".dimmed().to_string(),
+                DeckSection::Maybeboard => " (maybeboard)".dimmed().to_string(),
+                DeckSection::Mainboard => String::new(),
+            };
+            println!("  {} {}{}", entry.quantity, entry.card_name, section);
+        }
+        println!();
+        println!("  {} cards total", self.deck_list.total_cards());
+    }
+
+    fn display_curve(&self) {
+        let mut analysis = self.curve.clone();
+        let format = detect_format_from_deck(&self.deck_list);
+        let archetype =
+            CurveArchetype::select(format, Some(&self.matrix), analysis.stats.average_cmc);
+        analysis.curve_fit = Some(CurveFit::evaluate(&analysis.buckets, archetype));
+        display_curve_analysis(&analysis, false);
+    }
+
+    fn display_mana(&self) {
+        let format = detect_format_from_deck(&self.deck_list);
+        let (target_lands, land_source) =
+            determine_land_count(&self.deck_list, None, self.deck_list.excludes_lands);
+        let mana_base = calculate_mana_base(
+            &self.curve,
+            &self.deck_list,
+            target_lands,
+            format,
+            Algorithm::Simple,
+        );
+        display_mana_recommendation(&mana_base, target_lands, &land_source, Algorithm::Simple);
+    }
+
+    fn save(&mut self, path: Option<String>) {
+        let path = match path.or_else(|| match &self.deck_list.source {
+            DeckSource::TextFile(path) => Some(path.clone()),
+            _ => None,
+        }) {
+            Some(path) => path,
+            None => {
+                display_error("No file to save to. Usage: save <path>");
+                return;
+            }
+        };
+
+        match std::fs::write(&path, self.deck_list.to_text()) {
+            Ok(_) => {
+                self.deck_list.source = DeckSource::TextFile(path.clone());
+                println!("{}", format!("Deck saved to: {path}").green());
+            }
+            Err(e) => display_error(&format!("Failed to save deck: {e}")),
+        }
+    }
+}
+
+fn format_delta(delta: f64, precision: usize) -> String {
+    if delta.abs() < 0.005 {
+        format!("{:+.precision$}", 0.0).dimmed().to_string()
+    } else if delta > 0.0 {
+        format!("{delta:+.precision$}").yellow().to_string()
+    } else {
+        format!("{delta:+.precision$}").cyan().to_string()
+    }
+}
+
+fn display_repl_help() {
+    println!();
+    println!("{}", "Commands:".yellow());
+    println!("  {}  Add copies of a card", "add [N] <card>".green());
+    println!(
+        "  {}  Remove copies of a card (all copies if N is omitted)",
+        "cut [N] <card>".green()
+    );
+    println!(
+        "  {}  Replace a card with another",
+        "swap <out> -> <in>".green()
+    );
+    println!("  {}  Show the current decklist", "list".green());
+    println!("  {}  Show the mana curve", "curve".green());
+    println!("  {}  Show the synergy analysis", "synergy".green());
+    println!("  {}  Show the mana base recommendation", "mana".green());
+    println!("  {}  Revert the last change", "undo".green());
+    println!(
+        "  {}  Write the decklist (defaults to the loaded file)",
+        "save [path]".green()
+    );
+    println!("  {}  Leave the session", "quit".green());
+    println!();
+}
+
+/// Run an interactive deck building session
+pub async fn handle_repl_command(
+    input: Option<String>,
+    api: ApiProvider,
+    no_fallback: bool,
+    excludes_lands: bool,
+) {
+    println!();
+    let deck_list = match input {
+        Some(input) => match parse_and_hydrate_deck(&input, api, no_fallback, excludes_lands).await
+        {
+            Ok(deck) => deck,
+            Err(e) => {
+                display_error(&e);
+                return;
+            }
+        },
+        None => {
+            let mut deck = DeckList::new(DeckSource::Manual);
+            deck.excludes_lands = excludes_lands;
+            deck
+        }
+    };
+
+    display_progress("Analyzing deck...");
+    let mut session = ReplSession::new(
+        deck_list,
+        create_client(api, !no_fallback),
+        load_combo_database(),
+    );
+
+    println!();
+    println!("{}", "=== SCRY DECK BUILDER ===".bold().green());
+    println!("Type {} for a list of commands.", "help".green());
+
+    let stdin = io::stdin();
+    loop {
+        print!("{} ", "scry>".cyan().bold());
+        io::stdout().flush().ok();
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+
+        match ReplCommand::parse(&line) {
+            Ok(command) => {
+                if !session.execute(command).await {
+                    break;
+                }
+            }
+            Err(e) if e.is_empty() => {}
+            Err(e) => display_error(&e),
+        }
+    }
+
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::scryfall::ScryfallClient;
+    use crate::testing::{card, entry};
+
+    fn session() -> ReplSession {
+        ReplSession::new(
+            DeckList::new(DeckSource::Manual),
+            Box::new(ScryfallClient::new()),
+            Arc::new(ComboDatabase::default()),
+        )
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            ReplCommand::parse("add 2 Counterspell"),
+            Ok(ReplCommand::Add {
+                quantity: 2,
+                name: "Counterspell".to_string()
+            })
+        );
+        assert_eq!(
+            ReplCommand::parse("cut Opt"),
+            Ok(ReplCommand::Cut {
+                quantity: None,
+                name: "Opt".to_string()
+            })
+        );
+        assert_eq!(
+            ReplCommand::parse("swap Opt -> Consider"),
+            Ok(ReplCommand::Swap {
+                out: "Opt".to_string(),
+                into: "Consider".to_string()
+            })
+        );
+        assert_eq!(
+            ReplCommand::parse("save deck.txt"),
+            Ok(ReplCommand::Save(Some("deck.txt".to_string())))
+        );
+        assert!(ReplCommand::parse("swap Opt").is_err());
+        assert!(ReplCommand::parse("frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_add_cut_and_undo() {
+        let mut session = session();
+        session.add_card(
+            2,
+            card("Counterspell").cmc(2.0).type_line("Instant").build(),
+        );
+        session.add_card(
+            1,
+            card("Counterspell").cmc(2.0).type_line("Instant").build(),
+        );
+        session.add_card(4, card("Opt").cmc(1.0).type_line("Instant").build());
+        assert_eq!(session.deck_list.total_cards(), 7);
+        assert_eq!(session.deck_list.unique_cards(), 2);
+
+        assert_eq!(session.cut_card("opt", Some(1)), Ok(1));
+        assert_eq!(session.cut_card("Counterspell", None), Ok(3));
+        assert_eq!(session.deck_list.total_cards(), 3);
+        assert!(session.cut_card("Brainstorm", None).is_err());
+
+        assert!(session.undo());
+        assert_eq!(session.deck_list.total_cards(), 6);
+        assert!(session.undo());
+        assert!(session.undo());
+        assert!(session.undo());
+        assert!(session.undo());
+        assert!(!session.undo());
+        assert_eq!(session.deck_list.total_cards(), 0);
+    }
+
+    #[test]
+    fn test_add_leaves_the_commander_alone() {
+        let mut session = session();
+        let commander = card("Edgar Markov")
+            .type_line("Legendary Creature — Vampire Knight")
+            .build();
+        session
+            .deck_list
+            .entries
+            .push(entry(1, commander.clone(), DeckSection::Commander));
+
+        session.add_card(1, commander);
+        let sections: Vec<(DeckSection, u32)> = session
+            .deck_list
+            .entries
+            .iter()
+            .map(|e| (e.section, e.quantity))
+            .collect();
+        assert_eq!(
+            sections,
+            vec![(DeckSection::Commander, 1), (DeckSection::Mainboard, 1)]
+        );
+    }
+}
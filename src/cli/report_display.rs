@@ -0,0 +1,60 @@
+use colored::Colorize;
+
+use crate::report::{LegalityReport, PriceReport};
+
+/// Number of most expensive cards listed
+const TOP_PRICED: usize = 10;
+
+/// Display the deck's legality check in the terminal
+pub fn display_legality(legality: &LegalityReport) {
+    println!();
+    println!("{}", "=== LEGALITY ===".bold().green());
+    println!();
+
+    if legality.is_legal() {
+        println!("{} {}", "Legal in".green(), legality.format.green().bold());
+    } else {
+        println!(
+            "{} {} ({} issues)",
+            "Not legal in".red(),
+            legality.format.red().bold(),
+            legality.issues.len()
+        );
+        for issue in &legality.issues {
+            println!("  {} {}: {}", "✗".red(), issue.card, issue.reason.dimmed());
+        }
+    }
+}
+
+/// Display the deck's price and most expensive cards in the terminal
+pub fn display_price(price: &PriceReport) {
+    println!();
+    println!("{}", "=== PRICE ===".bold().green());
+    println!();
+
+    println!("{}: ${:.2}", "Total (USD)".yellow(), price.total_usd);
+    if !price.cards.is_empty() {
+        println!();
+        println!("{}", "Most Expensive:".cyan().bold());
+        for card in price.cards.iter().take(TOP_PRICED) {
+            let quantity = if card.quantity > 1 {
+                format!(" x{}", card.quantity)
+            } else {
+                String::new()
+            };
+            println!(
+                "  {:>9}  {}{}",
+                format!("${:.2}", card.total_usd()),
+                card.card,
+                quantity.dimmed()
+            );
+        }
+    }
+    if !price.unpriced.is_empty() {
+        println!();
+        println!(
+            "{}",
+            format!("No price for {} cards", price.unpriced.len()).dimmed()
+        );
+    }
+}
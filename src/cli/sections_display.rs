@@ -0,0 +1,101 @@
+use colored::Colorize;
+
+use crate::sections::SectionAnalysis;
+
+/// Display sideboard roles and maybeboard swap effects in the terminal
+pub fn display_sections(analysis: &SectionAnalysis, verbose: bool) {
+    println!();
+    println!("{}", "=== SIDEBOARD & MAYBEBOARD ===".bold().green());
+    println!();
+
+    if let Some(name) = &analysis.deck_name {
+        println!("{}: {}", "Deck".yellow(), name);
+    }
+    println!("{}: {}", "Format".yellow(), analysis.format);
+    println!(
+        "{}: {} cards",
+        "Mainboard".yellow(),
+        analysis.mainboard_cards
+    );
+    println!();
+
+    // Sideboard
+    println!("{}", "Sideboard:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    match &analysis.sideboard {
+        None => println!("  {}", "No sideboard.".dimmed()),
+        Some(sideboard) => {
+            let size = match sideboard.max_size {
+                Some(max) if sideboard.is_oversized() => format!(
+                    "{} cards (over the {max}-card limit)",
+                    sideboard.total_cards
+                )
+                .red()
+                .to_string(),
+                Some(max) => format!("{}/{max} cards", sideboard.total_cards)
+                    .green()
+                    .to_string(),
+                None => format!("{} cards", sideboard.total_cards),
+            };
+            println!("  {}: {}", "Size".yellow(), size);
+            for role_count in &sideboard.roles {
+                println!(
+                    "  {:<20} {}",
+                    role_count.role.name().bold(),
+                    role_count.count
+                );
+                if verbose {
+                    println!("    {}", role_count.cards.join(", ").dimmed());
+                }
+            }
+        }
+    }
+    println!();
+
+    // Maybeboard
+    println!("{}", "Maybeboard Swaps:".cyan().bold());
+    println!("{}", "-".repeat(60));
+    if analysis.maybeboard.is_empty() {
+        println!("  {}", "No maybeboard cards.".dimmed());
+    }
+    for swap in &analysis.maybeboard {
+        let target = swap
+            .swap_out
+            .as_deref()
+            .map(|out| format!(" for {out}"))
+            .unwrap_or_default();
+        println!("  {}{}", swap.card.bold(), target.dimmed());
+        println!(
+            "    {} {}  {} {}  {} {}  {} {}",
+            "Synergies:".yellow(),
+            swap.new_edges,
+            "Score:".yellow(),
+            signed(swap.synergy_score_change, 0),
+            "Avg CMC:".yellow(),
+            signed(swap.average_cmc_change, 2),
+            "Curve fit:".yellow(),
+            signed(swap.curve_fit_change, 0)
+        );
+        if !swap.theme_changes.is_empty() {
+            let changes: Vec<String> = swap
+                .theme_changes
+                .iter()
+                .map(|c| format!("{} {} -> {}", c.theme.display_name(), c.before, c.after))
+                .collect();
+            println!("    {} {}", "Themes:".yellow(), changes.join(", "));
+        }
+    }
+    println!();
+}
+
+/// Format a change with its sign, colored by direction
+fn signed(change: f64, precision: usize) -> String {
+    let text = format!("{change:+.precision$}");
+    if change > 0.0 {
+        text.green().to_string()
+    } else if change < 0.0 {
+        text.red().to_string()
+    } else {
+        text.dimmed().to_string()
+    }
+}
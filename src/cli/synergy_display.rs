@@ -1,6 +1,7 @@
 use colored::Colorize;
 
 use crate::llm::LlmAnalysisResult;
+use crate::synergy::archetype::MIN_ARCHETYPE_SCORE;
 use crate::synergy::SynergyMatrix;
 
 /// Display synergy analysis results in the terminal
@@ -61,9 +62,65 @@ pub fn display_synergy_matrix(matrix: &SynergyMatrix, verbose: bool) {
         println!();
     }
 
+    // Strategy archetype
+    if let Some(archetype) = matrix.likely_archetype() {
+        println!(
+            "{}: {} ({:.0}% fit)",
+            "Strategy".cyan().bold(),
+            archetype.theme.display_name().bold(),
+            archetype.score * 100.0
+        );
+        for evidence in &archetype.evidence {
+            println!("  - {evidence}");
+        }
+        let runners_up: Vec<_> = matrix
+            .archetypes
+            .iter()
+            .skip(1)
+            .take(2)
+            .filter(|a| a.score >= MIN_ARCHETYPE_SCORE)
+            .map(|a| format!("{} ({:.0}%)", a.theme.display_name(), a.score * 100.0))
+            .collect();
+        if !runners_up.is_empty() {
+            println!(
+                "  {} {}",
+                "Also leans:".dimmed(),
+                runners_up.join(", ").dimmed()
+            );
+        }
+        if verbose {
+            for archetype in matrix.archetypes.iter().skip(1) {
+                println!(
+                    "    {}: {:.0}%",
+                    archetype.theme.display_name(),
+                    archetype.score * 100.0
+                );
+            }
+        }
+        println!();
+    }
+
     // Statistics
     println!("{}", "Statistics:".cyan().bold());
     println!("{}", "-".repeat(50));
+    println!(
+        "  {}: {:.0}/100",
+        "Synergy Score".yellow(),
+        matrix.stats.synergy_score
+    );
+    println!(
+        "    {}",
+        "(theme coverage, coherence and card connectivity combined)".dimmed()
+    );
+    println!(
+        "  {}: {:.1}%",
+        "Theme Coherence".yellow(),
+        matrix.stats.theme_coherence * 100.0
+    );
+    println!(
+        "    {}",
+        "(how focused and interconnected the detected themes are)".dimmed()
+    );
     println!(
         "  {}: {:.1}%",
         "Theme Coverage".yellow(),
@@ -95,13 +152,59 @@ pub fn display_synergy_matrix(matrix: &SynergyMatrix, verbose: bool) {
 
     // Hub cards
     if !matrix.stats.hub_cards.is_empty() {
-        println!("{}", "Hub Cards (Most Synergies):".green());
+        println!("{}", "Hub Cards (Most Central):".green());
         for card in &matrix.stats.hub_cards {
             println!("  + {card}");
         }
         println!();
     }
 
+    // Synergy packages
+    if matrix.clusters.len() > 1 {
+        println!("{}", "Synergy Packages:".cyan().bold());
+        println!("{}", "-".repeat(50));
+        for (i, cluster) in matrix.clusters.iter().enumerate() {
+            let themes: Vec<_> = cluster.themes.iter().map(|t| t.display_name()).collect();
+            println!(
+                "  {} {} {}",
+                format!("{}.", i + 1).yellow(),
+                if themes.is_empty() {
+                    "Mixed".to_string()
+                } else {
+                    themes.join(" / ")
+                },
+                format!("({} cards)", cluster.cards.len()).dimmed()
+            );
+            println!(
+                "     {}",
+                truncate_list(&cluster.cards, if verbose { 20 } else { 6 })
+            );
+            if !cluster.bridges.is_empty() {
+                println!(
+                    "     {} {}",
+                    "Bridges:".dimmed(),
+                    truncate_list(&cluster.bridges, 3)
+                );
+            }
+        }
+        println!();
+    }
+
+    // Directed card-pair interactions
+    let interactions = matrix.key_interactions(8);
+    if !interactions.is_empty() {
+        println!("{}", "Key Interactions:".green());
+        for edge in interactions {
+            println!("  > {}", edge.reason);
+        }
+        println!();
+    }
+
+    // Combos
+    if !matrix.combos.is_empty() {
+        display_combos(matrix, verbose);
+    }
+
     // Orphan cards
     if !matrix.stats.orphan_cards.is_empty() {
         let orphan_count = matrix.stats.orphan_cards.len();
@@ -184,14 +287,73 @@ pub fn display_synergy_matrix(matrix: &SynergyMatrix, verbose: bool) {
                 println!("    {}: {}", "Keywords".yellow(), keyword_names.join(", "));
             }
 
+            if !profile.granted_keywords.is_empty() {
+                let granted_names: Vec<_> = profile
+                    .granted_keywords
+                    .iter()
+                    .map(|k| k.display_name())
+                    .collect();
+                println!("    {}: {}", "Grants".yellow(), granted_names.join(", "));
+            }
+
             if let Some(role) = &profile.role {
                 println!("    {}: {:?}", "Role".yellow(), role);
             }
+
+            if !profile.synergizes_with.is_empty() {
+                println!(
+                    "    {}: {:.2} ({})",
+                    "Synergy".yellow(),
+                    profile.synergy_score,
+                    truncate_list(&profile.synergizes_with, 5)
+                );
+            }
         }
         println!();
     }
 }
 
+/// Display complete combos and combos one piece away
+fn display_combos(matrix: &SynergyMatrix, verbose: bool) {
+    let (complete, partial): (Vec<_>, Vec<_>) = matrix.combos.iter().partition(|m| m.is_complete());
+
+    println!("{}", "Combos:".cyan().bold());
+    println!("{}", "-".repeat(50));
+    for combo_match in &complete {
+        println!(
+            "  {} {} {}",
+            "!".green().bold(),
+            combo_match.combo.label().bold(),
+            format!("=> {}", combo_match.combo.results_summary()).dimmed()
+        );
+    }
+
+    if !partial.is_empty() {
+        let shown = if verbose { partial.len() } else { 5 };
+        println!("  {}", "One piece away:".yellow());
+        for combo_match in partial.iter().take(shown) {
+            println!(
+                "  {} {} {} {}",
+                "?".yellow(),
+                combo_match.present.join(" + "),
+                format!("(missing: {})", combo_match.missing.join(", ")).yellow(),
+                format!("=> {}", combo_match.combo.results_summary()).dimmed()
+            );
+        }
+        if partial.len() > shown {
+            println!(
+                "    {}",
+                format!(
+                    "+{} more (use --verbose to list all)",
+                    partial.len() - shown
+                )
+                .dimmed()
+            );
+        }
+    }
+    println!();
+}
+
 /// Generate a simple bar chart
 fn generate_bar(percentage: f64, width: usize) -> String {
     let filled = ((percentage / 100.0) * width as f64).round() as usize;
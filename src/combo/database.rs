@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::types::Combo;
+
+/// A local collection of known combos
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComboDatabase {
+    pub combos: Vec<Combo>,
+}
+
+impl ComboDatabase {
+    /// Location of the imported combo database
+    pub fn default_path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("scry")
+            .join("combos.json")
+    }
+
+    /// Load a database previously saved with `save`
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse combo database {}: {}", path.display(), e))
+    }
+
+    /// Load the imported database, or an empty one if nothing has been
+    /// imported
+    pub fn load_default() -> Result<Self, String> {
+        let path = Self::default_path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::load(&path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize combo database: {e}"))?;
+        fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Import combos from a Commander Spellbook JSON export on disk
+    pub fn import_spellbook(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_spellbook_json(&contents)
+    }
+
+    /// Parse a Commander Spellbook export.
+    ///
+    /// Accepts the bulk `variants.json` export, a page of API `results`, or a
+    /// bare array of variants. Variants that aren't marked OK are skipped.
+    pub fn from_spellbook_json(json: &str) -> Result<Self, String> {
+        let export: SpellbookExport = serde_json::from_str(json)
+            .map_err(|e| format!("Not a Commander Spellbook export: {e}"))?;
+
+        let variants = match export {
+            SpellbookExport::Variants { variants } => variants,
+            SpellbookExport::Results { results } => results,
+            SpellbookExport::List(variants) => variants,
+        };
+
+        let combos = variants
+            .into_iter()
+            .filter(|v| v.status.as_deref().is_none_or(|s| s == "OK"))
+            .filter(|v| !v.uses.is_empty())
+            .map(|v| Combo {
+                id: match v.id {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                },
+                cards: v.uses.into_iter().map(|u| u.card.name).collect(),
+                color_identity: normalize_identity(&v.identity),
+                results: v.produces.into_iter().map(|p| p.feature.name).collect(),
+                description: v.description,
+            })
+            .collect();
+
+        Ok(Self { combos })
+    }
+
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+}
+
+/// Put a color identity string in WUBRG order, treating "C" as colorless
+fn normalize_identity(identity: &str) -> String {
+    let upper = identity.to_uppercase();
+    "WUBRG".chars().filter(|c| upper.contains(*c)).collect()
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SpellbookExport {
+    Variants { variants: Vec<SpellbookVariant> },
+    Results { results: Vec<SpellbookVariant> },
+    List(Vec<SpellbookVariant>),
+}
+
+#[derive(Deserialize)]
+struct SpellbookVariant {
+    #[serde(default)]
+    id: serde_json::Value,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    uses: Vec<SpellbookUse>,
+    #[serde(default)]
+    produces: Vec<SpellbookProduce>,
+    #[serde(default)]
+    identity: String,
+    #[serde(default)]
+    description: String,
+}
+
+#[derive(Deserialize)]
+struct SpellbookUse {
+    card: SpellbookName,
+}
+
+#[derive(Deserialize)]
+struct SpellbookProduce {
+    feature: SpellbookName,
+}
+
+#[derive(Deserialize)]
+struct SpellbookName {
+    name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{
+        "timestamp": "2024-01-01T00:00:00Z",
+        "variants": [
+            {
+                "id": "1414-2730",
+                "status": "OK",
+                "uses": [
+                    {"card": {"name": "Thassa's Oracle"}, "quantity": 1},
+                    {"card": {"name": "Demonic Consultation"}, "quantity": 1}
+                ],
+                "produces": [{"feature": {"name": "Win the game"}}],
+                "identity": "BU",
+                "description": "Cast Demonic Consultation naming a card not in your deck."
+            },
+            {
+                "id": "99-100",
+                "status": "D",
+                "uses": [{"card": {"name": "Draft Card"}}],
+                "identity": "C"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_import_spellbook_variants() {
+        let db = ComboDatabase::from_spellbook_json(EXPORT).unwrap();
+        assert_eq!(db.len(), 1);
+
+        let combo = &db.combos[0];
+        assert_eq!(combo.id, "1414-2730");
+        assert_eq!(combo.cards, vec!["Thassa's Oracle", "Demonic Consultation"]);
+        assert_eq!(combo.color_identity, "UB");
+        assert_eq!(combo.results, vec!["Win the game"]);
+    }
+
+    #[test]
+    fn test_import_rejects_other_json() {
+        assert!(ComboDatabase::from_spellbook_json(r#"{"cards": 3}"#).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use crate::input::DeckList;
+use crate::synergy::{SynergyEdge, SynergyRelation, Theme};
+
+use super::database::ComboDatabase;
+use super::types::ComboMatch;
+
+/// Partial combos missing more pieces than this aren't reported
+pub const MAX_MISSING_PIECES: usize = 1;
+
+/// Lowercased lookup key for a card name, using the front face of split and
+/// double-faced cards so "Delver of Secrets" matches its full DFC name
+fn name_key(name: &str) -> String {
+    name.split(" // ")
+        .next()
+        .unwrap_or(name)
+        .trim()
+        .to_lowercase()
+}
+
+/// The deck's color identity in WUBRG order.
+///
+/// Uses the commanders when the deck has any, otherwise every mainboard card.
+pub fn deck_color_identity(deck: &DeckList) -> String {
+    let mut entries: Vec<_> = deck.commanders().collect();
+    if entries.is_empty() {
+        entries = deck.mainboard().collect();
+    }
+
+    let colors: Vec<&String> = entries
+        .iter()
+        .filter_map(|e| e.card.as_ref())
+        .flat_map(|c| c.color_identity.iter())
+        .collect();
+
+    "WUBRG"
+        .chars()
+        .filter(|c| colors.iter().any(|color| color.contains(*c)))
+        .collect()
+}
+
+/// Find combos that are fully in the deck, or one piece away with the missing
+/// pieces inside the deck's color identity.
+///
+/// Complete combos come first, then partial combos with the most pieces present.
+pub fn find_combos(db: &ComboDatabase, deck: &DeckList) -> Vec<ComboMatch> {
+    let mut deck_names: HashMap<String, String> = HashMap::new();
+    for entry in deck.mainboard() {
+        let name = entry
+            .card
+            .as_ref()
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| entry.card_name.clone());
+        deck_names.insert(name_key(&name), name);
+    }
+
+    let identity = deck_color_identity(deck);
+
+    let mut matches: Vec<ComboMatch> = db
+        .combos
+        .iter()
+        .filter_map(|combo| {
+            let mut present = Vec::new();
+            let mut missing = Vec::new();
+            for card in &combo.cards {
+                match deck_names.get(&name_key(card)) {
+                    Some(name) => present.push(name.clone()),
+                    None => missing.push(card.clone()),
+                }
+            }
+
+            let complete = missing.is_empty();
+            let within_identity = combo.color_identity.chars().all(|c| identity.contains(c));
+            let partial = !present.is_empty()
+                && missing.len() <= MAX_MISSING_PIECES
+                && present.len() >= missing.len()
+                && within_identity;
+
+            (complete || partial).then(|| ComboMatch {
+                combo: combo.clone(),
+                present,
+                missing,
+            })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        a.missing
+            .len()
+            .cmp(&b.missing.len())
+            .then_with(|| b.present.len().cmp(&a.present.len()))
+            .then_with(|| a.combo.label().cmp(&b.combo.label()))
+    });
+    matches
+}
+
+/// Build `Combos` edges between every pair of pieces in complete combos
+pub fn combo_edges(matches: &[ComboMatch]) -> Vec<SynergyEdge> {
+    let mut edges = Vec::new();
+
+    for combo_match in matches.iter().filter(|m| m.is_complete()) {
+        let pieces = &combo_match.present;
+        for (i, a) in pieces.iter().enumerate() {
+            for b in pieces.iter().skip(i + 1) {
+                let others: Vec<_> = pieces
+                    .iter()
+                    .filter(|p| *p != a && *p != b)
+                    .cloned()
+                    .collect();
+                let reason = if others.is_empty() {
+                    format!(
+                        "{} and {} combo: {}",
+                        a,
+                        b,
+                        combo_match.combo.results_summary()
+                    )
+                } else {
+                    format!(
+                        "{} and {} combo with {}: {}",
+                        a,
+                        b,
+                        others.join(", "),
+                        combo_match.combo.results_summary()
+                    )
+                };
+
+                edges.push(SynergyEdge {
+                    card_a: a.clone(),
+                    card_b: b.clone(),
+                    relation: SynergyRelation::Combos,
+                    themes: vec![Theme::Combo],
+                    strength: 1.0,
+                    reason,
+                });
+            }
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combo::Combo;
+    use crate::input::{DeckSection, DeckSource};
+    use crate::testing::{card, entry};
+
+    fn deck(cards: &[(&str, &[&str])]) -> DeckList {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        for (name, identity) in cards {
+            deck.entries.push(entry(
+                1,
+                card(name).identity(identity).build(),
+                DeckSection::Mainboard,
+            ));
+        }
+        deck
+    }
+
+    fn combo(id: &str, cards: &[&str], identity: &str) -> Combo {
+        Combo {
+            id: id.to_string(),
+            cards: cards.iter().map(|c| c.to_string()).collect(),
+            color_identity: identity.to_string(),
+            results: vec!["Infinite mana".to_string()],
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_complete_and_partial_combos() {
+        let db = ComboDatabase {
+            combos: vec![
+                combo("1", &["Basalt Monolith", "Rings of Brighthearth"], ""),
+                combo("2", &["Dramatic Reversal", "Isochron Scepter"], "U"),
+                combo("3", &["Exquisite Blood", "Sanguine Bond"], "B"),
+                combo("4", &["Pili-Pala", "Grand Architect", "Lotus Cobra"], "UG"),
+            ],
+        };
+        let deck = deck(&[
+            ("Basalt Monolith", &[]),
+            ("Rings of Brighthearth", &[]),
+            ("Isochron Scepter", &[]),
+            ("Ponder", &["U"]),
+            ("Pili-Pala", &[]),
+        ]);
+
+        let matches = find_combos(&db, &deck);
+        assert_eq!(matches.len(), 2);
+        assert!(matches[0].is_complete());
+        assert_eq!(matches[0].combo.id, "1");
+        assert_eq!(matches[1].missing, vec!["Dramatic Reversal"]);
+    }
+
+    #[test]
+    fn test_partial_combos_respect_color_identity() {
+        let db = ComboDatabase {
+            combos: vec![combo("1", &["Dramatic Reversal", "Isochron Scepter"], "U")],
+        };
+        let deck = deck(&[("Isochron Scepter", &[]), ("Lightning Bolt", &["R"])]);
+        assert!(find_combos(&db, &deck).is_empty());
+    }
+
+    #[test]
+    fn test_combo_edges_use_deck_names() {
+        let db = ComboDatabase {
+            combos: vec![combo("1", &["Delver of Secrets", "Ponder"], "U")],
+        };
+        let deck = deck(&[
+            ("Delver of Secrets // Insectile Aberration", &["U"]),
+            ("Ponder", &["U"]),
+        ]);
+
+        let edges = combo_edges(&find_combos(&db, &deck));
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].relation, SynergyRelation::Combos);
+        assert_eq!(edges[0].card_a, "Delver of Secrets // Insectile Aberration");
+    }
+}
@@ -0,0 +1,7 @@
+pub mod database;
+pub mod finder;
+pub mod types;
+
+pub use database::*;
+pub use finder::*;
+pub use types::*;
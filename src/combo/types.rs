@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// A known combo: a set of cards that together produce one or more results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Combo {
+    pub id: String,
+    /// Card names required for the combo
+    pub cards: Vec<String>,
+    /// Color identity of the combo in WUBRG order (e.g. "UB", empty for colorless)
+    #[serde(default)]
+    pub color_identity: String,
+    /// What the combo produces (e.g. "Infinite mana")
+    #[serde(default)]
+    pub results: Vec<String>,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl Combo {
+    /// Short label for the combo, e.g. "Thassa's Oracle + Demonic Consultation"
+    pub fn label(&self) -> String {
+        self.cards.join(" + ")
+    }
+
+    /// Summary of what the combo produces
+    pub fn results_summary(&self) -> String {
+        if self.results.is_empty() {
+            "combo".to_string()
+        } else {
+            self.results.join(", ")
+        }
+    }
+}
+
+/// A combo found fully or partially in a deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComboMatch {
+    pub combo: Combo,
+    /// Pieces found in the deck, using the deck's card names
+    pub present: Vec<String>,
+    /// Pieces the deck is missing
+    pub missing: Vec<String>,
+}
+
+impl ComboMatch {
+    /// Whether every piece of the combo is in the deck
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
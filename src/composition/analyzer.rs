@@ -0,0 +1,287 @@
+use crate::api::Card;
+use crate::deck::Format;
+use crate::input::DeckList;
+use crate::synergy::themes::detect_card_themes;
+use crate::synergy::Theme;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+use super::templates::CompositionTemplate;
+use super::types::{
+    CardRole, CardType, CompositionAnalysis, RoleCount, TargetComparison, TypeCount,
+};
+
+/// Creatures at or above this power count as a win condition on their own
+const THREAT_POWER: i32 = 6;
+
+lazy_static! {
+    /// Oracle patterns for roles not covered by the synergy theme rules
+    static ref ROLE_PATTERNS: Vec<(CardRole, Vec<Regex>)> = vec![
+        (
+            CardRole::Wipe,
+            vec![
+                Regex::new(r"(?i)destroy all (creatures|nonland permanents|permanents|artifacts|enchantments)").unwrap(),
+                Regex::new(r"(?i)exile all (creatures|nonland permanents|permanents)").unwrap(),
+                Regex::new(r"(?i)all creatures get -\d+/-\d+").unwrap(),
+                Regex::new(r"(?i)deals \d+ damage to each creature").unwrap(),
+                Regex::new(r"(?i)return all (creatures|nonland permanents) to their owners'? hands?").unwrap(),
+                Regex::new(r"(?i)each player sacrifices (all|a|two|three)").unwrap(),
+            ],
+        ),
+        (
+            CardRole::Counterspell,
+            vec![
+                Regex::new(r"(?i)counter target").unwrap(),
+                Regex::new(r"(?i)counter (it|that spell) unless").unwrap(),
+            ],
+        ),
+        (
+            CardRole::Protection,
+            vec![
+                Regex::new(r"(?i)(gains?|have|has) (hexproof|shroud|indestructible|protection from)").unwrap(),
+                Regex::new(r"(?i)phases? out").unwrap(),
+                Regex::new(r"(?i)can't be countered").unwrap(),
+                Regex::new(r"(?i)prevent all (combat )?damage that would be dealt to (you|creatures you control)").unwrap(),
+            ],
+        ),
+        (
+            CardRole::WinCondition,
+            vec![
+                Regex::new(r"(?i)you win the game").unwrap(),
+                Regex::new(r"(?i)(each|target) opponent loses the game").unwrap(),
+                Regex::new(r"(?i)each opponent loses \d+ life").unwrap(),
+                Regex::new(r"(?i)additional combat phase").unwrap(),
+                Regex::new(r"(?i)take an extra turn").unwrap(),
+                Regex::new(r"(?i)creatures you control get \+\d+/\+\d+ and gain trample").unwrap(),
+            ],
+        ),
+    ];
+
+    /// Library searches; tutors are the ones that don't fetch a land
+    static ref LIBRARY_SEARCH: Regex = Regex::new(r"(?i)search your library for ([^.]*)").unwrap();
+}
+
+pub struct CompositionAnalyzer;
+
+impl CompositionAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Count card types and roles in the mainboard and compare to the format template
+    pub fn analyze(&self, deck_list: &DeckList, format: Format) -> CompositionAnalysis {
+        let mut analysis = CompositionAnalysis::new();
+        analysis.deck_name = deck_list.name.clone();
+        analysis.deck_format = deck_list.format.clone();
+
+        let mut type_counts: HashMap<CardType, u32> = HashMap::new();
+        let mut role_cards: HashMap<CardRole, (u32, Vec<String>)> = HashMap::new();
+
+        for entry in deck_list.mainboard() {
+            analysis.total_cards += entry.quantity;
+
+            let Some(card) = &entry.card else {
+                continue;
+            };
+
+            for card_type in card_types(card) {
+                *type_counts.entry(card_type).or_insert(0) += entry.quantity;
+            }
+
+            for role in card_roles(card) {
+                let (count, names) = role_cards.entry(role).or_default();
+                *count += entry.quantity;
+                names.push(card.name.clone());
+            }
+        }
+
+        let total = analysis.total_cards.max(1) as f64;
+        analysis.type_counts = CardType::all()
+            .into_iter()
+            .map(|card_type| {
+                let count = type_counts.get(&card_type).copied().unwrap_or(0);
+                TypeCount {
+                    card_type,
+                    count,
+                    percentage: count as f64 / total,
+                }
+            })
+            .collect();
+
+        analysis.role_counts = CardRole::all()
+            .into_iter()
+            .map(|role| {
+                let (count, cards) = role_cards.remove(&role).unwrap_or_default();
+                RoleCount { role, count, cards }
+            })
+            .collect();
+
+        let template = CompositionTemplate::for_format(format);
+        analysis.template_format = template.format.name().to_string();
+        for (role, min, max) in &template.role_targets {
+            analysis.comparisons.push(TargetComparison::new(
+                role.name().to_string(),
+                analysis.role_count(*role),
+                *min,
+                *max,
+            ));
+        }
+        for (card_type, min, max) in &template.type_targets {
+            // Lands missing from a Moxfield export would always look short
+            if *card_type == CardType::Land && deck_list.excludes_lands {
+                continue;
+            }
+            analysis.comparisons.push(TargetComparison::new(
+                format!("{}s", card_type.name()),
+                analysis.type_count(*card_type),
+                *min,
+                *max,
+            ));
+        }
+
+        analysis
+    }
+}
+
+impl Default for CompositionAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Card types present on any face of a card
+pub fn card_types(card: &Card) -> Vec<CardType> {
+    let type_lines = card.all_type_lines().join(" ");
+    CardType::all()
+        .into_iter()
+        .filter(|t| type_lines.contains(t.name()))
+        .collect()
+}
+
+/// Functional roles a card fills
+pub fn card_roles(card: &Card) -> Vec<CardRole> {
+    let oracle = card.all_oracle_text().join("\n");
+    let is_land = card_types(card).contains(&CardType::Land);
+    let mut roles = Vec::new();
+
+    // Ramp, draw and removal reuse the synergy theme rules
+    let themes: Vec<Theme> = detect_card_themes(card)
+        .into_iter()
+        .map(|(theme, _, _)| theme)
+        .collect();
+
+    if themes.contains(&Theme::Ramp) && !is_land {
+        roles.push(CardRole::Ramp);
+    }
+    if themes.contains(&Theme::Draw) {
+        roles.push(CardRole::Draw);
+    }
+
+    for (role, patterns) in ROLE_PATTERNS.iter() {
+        if patterns.iter().any(|p| p.is_match(&oracle)) {
+            roles.push(*role);
+        }
+    }
+
+    // Sweepers also match the removal rules; count them once as wipes
+    if themes.contains(&Theme::Removal) && !roles.contains(&CardRole::Wipe) {
+        roles.push(CardRole::Removal);
+    }
+
+    if LIBRARY_SEARCH
+        .captures_iter(&oracle)
+        .any(|caps| !caps[1].to_lowercase().contains("land"))
+    {
+        roles.push(CardRole::Tutor);
+    }
+
+    let big_threat = card
+        .power
+        .as_deref()
+        .and_then(|p| p.parse::<i32>().ok())
+        .is_some_and(|p| p >= THREAT_POWER);
+    if big_threat && !roles.contains(&CardRole::WinCondition) {
+        roles.push(CardRole::WinCondition);
+    }
+
+    roles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition::TargetStatus;
+    use crate::input::{DeckSection, DeckSource};
+    use crate::testing::{card, entry};
+
+    #[test]
+    fn test_roles_for_staples() {
+        let cultivate = card("Cultivate")
+            .type_line("Sorcery")
+            .oracle("Search your library for up to two basic land cards, reveal those cards, put one onto the battlefield tapped and the other into your hand, then shuffle.")
+            .build();
+        assert_eq!(card_roles(&cultivate), vec![CardRole::Ramp]);
+
+        let wrath = card("Wrath of God")
+            .type_line("Sorcery")
+            .oracle("Destroy all creatures. They can't be regenerated.")
+            .build();
+        assert_eq!(card_roles(&wrath), vec![CardRole::Wipe]);
+
+        let counterspell = card("Counterspell")
+            .type_line("Instant")
+            .oracle("Counter target spell.")
+            .build();
+        assert_eq!(card_roles(&counterspell), vec![CardRole::Counterspell]);
+
+        let tutor = card("Demonic Tutor")
+            .type_line("Sorcery")
+            .oracle("Search your library for a card, put that card into your hand, then shuffle.")
+            .build();
+        assert_eq!(card_roles(&tutor), vec![CardRole::Tutor]);
+    }
+
+    #[test]
+    fn test_lands_are_not_ramp() {
+        let land = card("Command Tower")
+            .type_line("Land")
+            .oracle("{T}: Add one mana of any color in your commander's color identity.")
+            .build();
+        assert!(card_roles(&land).is_empty());
+        assert_eq!(card_types(&land), vec![CardType::Land]);
+    }
+
+    #[test]
+    fn test_analyze_compares_against_template() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        deck.entries.push(entry(
+            1,
+            card("Swords to Plowshares")
+                .type_line("Instant")
+                .oracle("Exile target creature. Its controller gains life equal to its power.")
+                .build(),
+            DeckSection::Mainboard,
+        ));
+        deck.entries.push(entry(
+            30,
+            card("Forest")
+                .type_line("Basic Land — Forest")
+                .oracle("({T}: Add {G}.)")
+                .build(),
+            DeckSection::Mainboard,
+        ));
+
+        let analysis = CompositionAnalyzer::new().analyze(&deck, Format::Commander);
+        assert_eq!(analysis.role_count(CardRole::Removal), 1);
+        assert_eq!(analysis.type_count(CardType::Land), 30);
+        assert_eq!(analysis.type_count(CardType::Instant), 1);
+
+        let removal = analysis
+            .comparisons
+            .iter()
+            .find(|c| c.label == "Removal")
+            .unwrap();
+        assert_eq!(removal.status, TargetStatus::Below);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod analyzer;
+pub mod templates;
+pub mod types;
+
+pub use analyzer::*;
+pub use types::*;
@@ -0,0 +1,75 @@
+//! Per-format composition targets.
+//!
+//! Ranges are rules of thumb from common deck building guides, e.g. the
+//! Commander "10 ramp / 10 draw / 10 removal" baseline.
+
+use crate::deck::Format;
+
+use super::types::{CardRole, CardType};
+
+/// Target counts for a format
+pub struct CompositionTemplate {
+    pub format: Format,
+    pub role_targets: Vec<(CardRole, u32, u32)>,
+    pub type_targets: Vec<(CardType, u32, u32)>,
+}
+
+impl CompositionTemplate {
+    pub fn for_format(format: Format) -> Self {
+        let (land_min, land_max) = format.recommended_land_range();
+
+        let (role_targets, type_targets) = match format {
+            Format::Commander => (
+                vec![
+                    (CardRole::Ramp, 10, 14),
+                    (CardRole::Draw, 10, 14),
+                    (CardRole::Removal, 8, 12),
+                    (CardRole::Wipe, 2, 4),
+                    (CardRole::Protection, 2, 6),
+                    (CardRole::WinCondition, 2, 6),
+                ],
+                vec![(CardType::Land, land_min, land_max)],
+            ),
+            Format::Limited => (
+                vec![(CardRole::Removal, 3, 8), (CardRole::Draw, 1, 4)],
+                vec![
+                    (CardType::Creature, 14, 18),
+                    (CardType::Land, land_min, land_max),
+                ],
+            ),
+            Format::Standard | Format::Modern | Format::Custom => (
+                vec![
+                    (CardRole::Removal, 6, 12),
+                    (CardRole::Draw, 4, 10),
+                    (CardRole::WinCondition, 4, 12),
+                ],
+                vec![(CardType::Land, land_min, land_max)],
+            ),
+        };
+
+        Self {
+            format,
+            role_targets,
+            type_targets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commander_template_has_core_roles() {
+        let template = CompositionTemplate::for_format(Format::Commander);
+        assert!(template.role_targets.contains(&(CardRole::Ramp, 10, 14)));
+        assert!(template.role_targets.contains(&(CardRole::Draw, 10, 14)));
+        assert!(template.role_targets.contains(&(CardRole::Removal, 8, 12)));
+    }
+
+    #[test]
+    fn test_land_target_follows_format_range() {
+        let template = CompositionTemplate::for_format(Format::Limited);
+        assert!(template.type_targets.contains(&(CardType::Land, 16, 18)));
+    }
+}
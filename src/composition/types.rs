@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Card types counted in a composition breakdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardType {
+    Creature,
+    Artifact,
+    Enchantment,
+    Planeswalker,
+    Battle,
+    Instant,
+    Sorcery,
+    Land,
+}
+
+impl CardType {
+    pub fn all() -> Vec<CardType> {
+        vec![
+            CardType::Creature,
+            CardType::Artifact,
+            CardType::Enchantment,
+            CardType::Planeswalker,
+            CardType::Battle,
+            CardType::Instant,
+            CardType::Sorcery,
+            CardType::Land,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardType::Creature => "Creature",
+            CardType::Artifact => "Artifact",
+            CardType::Enchantment => "Enchantment",
+            CardType::Planeswalker => "Planeswalker",
+            CardType::Battle => "Battle",
+            CardType::Instant => "Instant",
+            CardType::Sorcery => "Sorcery",
+            CardType::Land => "Land",
+        }
+    }
+}
+
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Functional role a card plays in the deck
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardRole {
+    Ramp,
+    Draw,
+    Removal,
+    Wipe,
+    Counterspell,
+    Tutor,
+    Protection,
+    WinCondition,
+}
+
+impl CardRole {
+    pub fn all() -> Vec<CardRole> {
+        vec![
+            CardRole::Ramp,
+            CardRole::Draw,
+            CardRole::Removal,
+            CardRole::Wipe,
+            CardRole::Counterspell,
+            CardRole::Tutor,
+            CardRole::Protection,
+            CardRole::WinCondition,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardRole::Ramp => "Ramp",
+            CardRole::Draw => "Card Draw",
+            CardRole::Removal => "Removal",
+            CardRole::Wipe => "Board Wipes",
+            CardRole::Counterspell => "Counterspells",
+            CardRole::Tutor => "Tutors",
+            CardRole::Protection => "Protection",
+            CardRole::WinCondition => "Win Conditions",
+        }
+    }
+}
+
+impl fmt::Display for CardRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Count of cards of a single type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeCount {
+    pub card_type: CardType,
+    pub count: u32,
+    pub percentage: f64,
+}
+
+/// Count of cards filling a single role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleCount {
+    pub role: CardRole,
+    pub count: u32,
+    pub cards: Vec<String>,
+}
+
+/// Where a count falls relative to a template range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TargetStatus {
+    Below,
+    Within,
+    Above,
+}
+
+/// Comparison of one count against the format template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetComparison {
+    pub label: String,
+    pub count: u32,
+    pub min: u32,
+    pub max: u32,
+    pub status: TargetStatus,
+}
+
+impl TargetComparison {
+    pub fn new(label: String, count: u32, min: u32, max: u32) -> Self {
+        let status = if count < min {
+            TargetStatus::Below
+        } else if count > max {
+            TargetStatus::Above
+        } else {
+            TargetStatus::Within
+        };
+
+        Self {
+            label,
+            count,
+            min,
+            max,
+            status,
+        }
+    }
+}
+
+/// Complete card type and role composition of a deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositionAnalysis {
+    pub deck_name: Option<String>,
+    pub deck_format: Option<String>,
+    /// Format whose template the deck was compared against
+    pub template_format: String,
+    pub total_cards: u32,
+    pub type_counts: Vec<TypeCount>,
+    pub role_counts: Vec<RoleCount>,
+    pub comparisons: Vec<TargetComparison>,
+}
+
+impl CompositionAnalysis {
+    pub fn new() -> Self {
+        Self {
+            deck_name: None,
+            deck_format: None,
+            template_format: String::new(),
+            total_cards: 0,
+            type_counts: Vec::new(),
+            role_counts: Vec::new(),
+            comparisons: Vec::new(),
+        }
+    }
+
+    pub fn role_count(&self, role: CardRole) -> u32 {
+        self.role_counts
+            .iter()
+            .find(|r| r.role == role)
+            .map(|r| r.count)
+            .unwrap_or(0)
+    }
+
+    pub fn type_count(&self, card_type: CardType) -> u32 {
+        self.type_counts
+            .iter()
+            .find(|t| t.card_type == card_type)
+            .map(|t| t.count)
+            .unwrap_or(0)
+    }
+}
+
+impl Default for CompositionAnalysis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,410 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::Card;
+use crate::deck::{guild_name, Color};
+use crate::input::DeckList;
+use crate::limited::pool::card_colors;
+use crate::report::legality::check_legality;
+use crate::synergy::themes::detect_card_themes;
+use crate::synergy::Theme;
+
+use super::types::{
+    ArchetypeSupport, ColorGroup, ColorGroupChange, ColorGroupStats, CubeAnalysis, CubeChange,
+    CubeDiff, DuplicateCard, ThemeSupport, MAX_CURVE_CMC,
+};
+
+/// Fewest cards for a theme to count as supported in a color pair
+const MIN_THEME_SUPPORT: u32 = 3;
+
+/// Themes listed per color pair
+const LISTED_THEMES: usize = 3;
+
+/// The color group a card is drafted from; lands go by their front face, so
+/// spells with a land back count as spells
+pub fn color_group(card: &Card) -> ColorGroup {
+    let front = card.type_line.split(" // ").next().unwrap_or_default();
+    if front.contains("Land") {
+        return ColorGroup::Land;
+    }
+    match card_colors(card).as_slice() {
+        [] => ColorGroup::Colorless,
+        [color] => ColorGroup::Mono(*color),
+        _ => ColorGroup::Multicolor,
+    }
+}
+
+pub struct CubeAnalyzer {
+    legality_format: Option<String>,
+}
+
+impl CubeAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            legality_format: None,
+        }
+    }
+
+    /// Also check every card against a Scryfall format, e.g. "vintage" or "pauper"
+    pub fn with_legality(format: Option<String>) -> Self {
+        Self {
+            legality_format: format.map(|f| f.to_lowercase()),
+        }
+    }
+
+    /// Analyze the cube's mainboard; sideboard and maybeboard cards are
+    /// treated as cards being considered, not part of the cube
+    pub fn analyze(&self, cube: &DeckList) -> CubeAnalysis {
+        let mut copies: BTreeMap<&str, u32> = BTreeMap::new();
+        let mut unknown_cards = Vec::new();
+        let mut cards: Vec<(&Card, u32)> = Vec::new();
+        for entry in cube.mainboard() {
+            *copies.entry(entry.card_name.as_str()).or_insert(0) += entry.quantity;
+            match &entry.card {
+                Some(card) => cards.push((card, entry.quantity)),
+                None => unknown_cards.push(entry.card_name.clone()),
+            }
+        }
+        let total_cards: u32 = copies.values().sum();
+
+        // Cubes are singleton, apart from basic lands
+        let basics: Vec<&str> = cards
+            .iter()
+            .filter(|(card, _)| card.type_line.contains("Basic"))
+            .map(|(card, _)| card.name.as_str())
+            .collect();
+        let duplicates = copies
+            .iter()
+            .filter(|(name, count)| **count > 1 && !basics.contains(name))
+            .map(|(name, count)| DuplicateCard {
+                name: name.to_string(),
+                copies: *count,
+            })
+            .collect();
+
+        CubeAnalysis {
+            cube_name: cube.name.clone(),
+            total_cards,
+            unique_cards: copies.len() as u32,
+            colors: color_balance(&cards, total_cards),
+            archetypes: archetype_support(&cards),
+            duplicates,
+            unknown_cards,
+            legality: self
+                .legality_format
+                .as_deref()
+                .map(|format| check_legality(cube, format)),
+        }
+    }
+}
+
+impl Default for CubeAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Count, share and curve of every color group
+fn color_balance(cards: &[(&Card, u32)], total_cards: u32) -> Vec<ColorGroupStats> {
+    ColorGroup::all()
+        .into_iter()
+        .map(|group| {
+            let members: Vec<&(&Card, u32)> = cards
+                .iter()
+                .filter(|(card, _)| color_group(card) == group)
+                .collect();
+            let count: u32 = members.iter().map(|(_, quantity)| quantity).sum();
+
+            let mut curve = Vec::new();
+            let mut total_cmc = 0.0;
+            if group != ColorGroup::Land {
+                curve = vec![0; MAX_CURVE_CMC + 1];
+                for (card, quantity) in &members {
+                    curve[(card.cmc as usize).min(MAX_CURVE_CMC)] += quantity;
+                    total_cmc += card.cmc * *quantity as f64;
+                }
+            }
+
+            ColorGroupStats {
+                group,
+                count,
+                share: if total_cards > 0 {
+                    count as f64 / total_cards as f64
+                } else {
+                    0.0
+                },
+                curve,
+                average_cmc: if count > 0 && group != ColorGroup::Land {
+                    total_cmc / count as f64
+                } else {
+                    0.0
+                },
+                creatures: members
+                    .iter()
+                    .filter(|(card, _)| card.type_line.contains("Creature"))
+                    .map(|(_, quantity)| quantity)
+                    .sum(),
+            }
+        })
+        .collect()
+}
+
+/// Themes supported by the colored cards each guild pair can play
+fn archetype_support(cards: &[(&Card, u32)]) -> Vec<ArchetypeSupport> {
+    let profiles: Vec<(Vec<Color>, Vec<Theme>, u32)> = cards
+        .iter()
+        .filter(|(card, _)| color_group(card) != ColorGroup::Land)
+        .map(|(card, quantity)| {
+            let mut themes: Vec<Theme> = Vec::new();
+            for (theme, _, _) in detect_card_themes(card) {
+                if !themes.contains(&theme) {
+                    themes.push(theme);
+                }
+            }
+            (card_colors(card), themes, *quantity)
+        })
+        .filter(|(colors, _, _)| !colors.is_empty())
+        .collect();
+
+    let colors = Color::all_colors();
+    let mut archetypes = Vec::new();
+    for (i, first) in colors.iter().enumerate() {
+        for second in &colors[i + 1..] {
+            let pair = vec![*first, *second];
+            let playable: Vec<&(Vec<Color>, Vec<Theme>, u32)> = profiles
+                .iter()
+                .filter(|(colors, _, _)| colors.iter().all(|c| pair.contains(c)))
+                .collect();
+
+            let mut theme_counts: HashMap<&Theme, u32> = HashMap::new();
+            for (_, themes, quantity) in &playable {
+                for theme in themes {
+                    *theme_counts.entry(theme).or_insert(0) += quantity;
+                }
+            }
+            let mut themes: Vec<ThemeSupport> = theme_counts
+                .into_iter()
+                .filter(|(_, count)| *count >= MIN_THEME_SUPPORT)
+                .map(|(theme, cards)| ThemeSupport {
+                    theme: theme.clone(),
+                    cards,
+                })
+                .collect();
+            themes.sort_by(|a, b| {
+                b.cards
+                    .cmp(&a.cards)
+                    .then_with(|| a.theme.display_name().cmp(&b.theme.display_name()))
+            });
+            themes.truncate(LISTED_THEMES);
+
+            archetypes.push(ArchetypeSupport {
+                name: guild_name(&pair).unwrap_or_default().to_string(),
+                playables: playable.iter().map(|(_, _, quantity)| quantity).sum(),
+                gold_cards: playable
+                    .iter()
+                    .filter(|(colors, _, _)| colors.len() == 2)
+                    .map(|(_, _, quantity)| quantity)
+                    .sum(),
+                colors: pair,
+                themes,
+            });
+        }
+    }
+    archetypes
+}
+
+/// Cards added and removed between two versions of a cube, and how each
+/// color group's size changed
+pub fn diff_cubes(old: &DeckList, new: &DeckList) -> CubeDiff {
+    let counts = |cube: &DeckList| {
+        let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+        for entry in cube.mainboard() {
+            *counts.entry(entry.card_name.clone()).or_insert(0) += entry.quantity;
+        }
+        counts
+    };
+    let old_counts = counts(old);
+    let new_counts = counts(new);
+
+    let changes = |from: &BTreeMap<String, u32>, to: &BTreeMap<String, u32>| -> Vec<CubeChange> {
+        to.iter()
+            .filter_map(|(name, count)| {
+                let before = from.get(name).copied().unwrap_or(0);
+                (*count > before).then(|| CubeChange {
+                    name: name.clone(),
+                    quantity: count - before,
+                })
+            })
+            .collect()
+    };
+
+    let analyzer = CubeAnalyzer::new();
+    let old_analysis = analyzer.analyze(old);
+    let new_analysis = analyzer.analyze(new);
+    let colors = old_analysis
+        .colors
+        .iter()
+        .zip(&new_analysis.colors)
+        .filter(|(before, after)| before.count != after.count)
+        .map(|(before, after)| ColorGroupChange {
+            group: before.group,
+            before: before.count,
+            after: after.count,
+        })
+        .collect();
+
+    CubeDiff {
+        old_name: old.name.clone(),
+        new_name: new.name.clone(),
+        old_total: old_analysis.total_cards,
+        new_total: new_analysis.total_cards,
+        added: changes(&old_counts, &new_counts),
+        removed: changes(&new_counts, &old_counts),
+        colors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{DeckSection, DeckSource};
+    use crate::testing::{card, entry};
+
+    fn cube(cards: Vec<(u32, Card)>) -> DeckList {
+        let mut cube = DeckList::new(DeckSource::Manual);
+        for (quantity, card) in cards {
+            cube.entries
+                .push(entry(quantity, card, DeckSection::Mainboard));
+        }
+        cube
+    }
+
+    fn token_maker(name: &str, colors: &[&str]) -> Card {
+        card(name)
+            .cmc(2.0)
+            .type_line("Creature — Soldier")
+            .oracle("When this creature enters, create a 1/1 white Soldier creature token.")
+            .colors(colors)
+            .build()
+    }
+
+    #[test]
+    fn test_color_balance_archetypes_and_duplicates() {
+        let cube = cube(vec![
+            (1, token_maker("Token Maker A", &["W"])),
+            (1, token_maker("Token Maker B", &["W"])),
+            (2, token_maker("Token Maker C", &["W", "R"])),
+            (
+                1,
+                card("Big Spell")
+                    .cmc(9.0)
+                    .type_line("Sorcery")
+                    .colors(&["R"])
+                    .build(),
+            ),
+            (
+                1,
+                card("Signet")
+                    .cmc(2.0)
+                    .type_line("Artifact")
+                    .colors(&[])
+                    .build(),
+            ),
+            (
+                1,
+                card("Plateau")
+                    .cmc(0.0)
+                    .type_line("Land — Mountain Plains")
+                    .colors(&[])
+                    .build(),
+            ),
+        ]);
+        let analysis = CubeAnalyzer::new().analyze(&cube);
+
+        assert_eq!(analysis.total_cards, 7);
+        assert_eq!(analysis.unique_cards, 6);
+        let group = |group: ColorGroup| {
+            analysis
+                .colors
+                .iter()
+                .find(|s| s.group == group)
+                .unwrap()
+                .clone()
+        };
+        let white = group(ColorGroup::Mono(Color::White));
+        assert_eq!(white.count, 2);
+        assert_eq!(white.creatures, 2);
+        assert_eq!(white.curve[2], 2);
+        assert_eq!(group(ColorGroup::Mono(Color::Red)).curve[MAX_CURVE_CMC], 1);
+        assert_eq!(group(ColorGroup::Multicolor).count, 2);
+        assert_eq!(group(ColorGroup::Colorless).count, 1);
+        assert_eq!(group(ColorGroup::Land).count, 1);
+
+        let boros = analysis
+            .archetypes
+            .iter()
+            .find(|a| a.name == "Boros")
+            .unwrap();
+        assert_eq!(boros.playables, 5);
+        assert_eq!(boros.gold_cards, 2);
+        assert_eq!(boros.themes[0].theme, Theme::Tokens);
+        assert_eq!(boros.themes[0].cards, 4);
+        // Colorless cards don't count toward any pair
+        let dimir = analysis
+            .archetypes
+            .iter()
+            .find(|a| a.name == "Dimir")
+            .unwrap();
+        assert_eq!(dimir.playables, 0);
+
+        assert_eq!(analysis.duplicates.len(), 1);
+        assert_eq!(analysis.duplicates[0].name, "Token Maker C");
+        assert_eq!(analysis.duplicates[0].copies, 2);
+    }
+
+    #[test]
+    fn test_diff_lists_changes_and_color_shifts() {
+        let old = cube(vec![
+            (1, token_maker("Token Maker A", &["W"])),
+            (1, token_maker("Token Maker B", &["W"])),
+            (
+                1,
+                card("Signet")
+                    .cmc(2.0)
+                    .type_line("Artifact")
+                    .colors(&[])
+                    .build(),
+            ),
+        ]);
+        let new = cube(vec![
+            (1, token_maker("Token Maker A", &["W"])),
+            (
+                1,
+                card("Signet")
+                    .cmc(2.0)
+                    .type_line("Artifact")
+                    .colors(&[])
+                    .build(),
+            ),
+            (
+                1,
+                card("Shock")
+                    .cmc(1.0)
+                    .type_line("Instant")
+                    .colors(&["R"])
+                    .build(),
+            ),
+        ]);
+        let diff = diff_cubes(&old, &new);
+
+        assert_eq!(diff.old_total, 3);
+        assert_eq!(diff.new_total, 3);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].name, "Shock");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].name, "Token Maker B");
+        assert_eq!(diff.colors.len(), 2);
+        assert_eq!(diff.colors[0].group, ColorGroup::Mono(Color::White));
+        assert_eq!((diff.colors[0].before, diff.colors[0].after), (2, 1));
+        assert_eq!(diff.colors[1].group, ColorGroup::Mono(Color::Red));
+    }
+}
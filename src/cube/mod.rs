@@ -0,0 +1,5 @@
+pub mod analyzer;
+pub mod types;
+
+pub use analyzer::*;
+pub use types::*;
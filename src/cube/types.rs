@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use crate::deck::Color;
+use crate::report::LegalityReport;
+use crate::synergy::Theme;
+
+/// Highest CMC bucket in per-color curves; costlier cards are counted in it
+pub const MAX_CURVE_CMC: usize = 7;
+
+/// The part of a cube a card is drafted from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorGroup {
+    Mono(Color),
+    Multicolor,
+    Colorless,
+    Land,
+}
+
+impl ColorGroup {
+    /// Every group in display order: WUBRG, then multicolor, colorless and lands
+    pub fn all() -> Vec<ColorGroup> {
+        let mut groups: Vec<ColorGroup> = Color::all_colors()
+            .into_iter()
+            .map(ColorGroup::Mono)
+            .collect();
+        groups.extend([
+            ColorGroup::Multicolor,
+            ColorGroup::Colorless,
+            ColorGroup::Land,
+        ]);
+        groups
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorGroup::Mono(color) => color.name(),
+            ColorGroup::Multicolor => "Multicolor",
+            ColorGroup::Colorless => "Colorless",
+            ColorGroup::Land => "Land",
+        }
+    }
+}
+
+/// Card count and curve for one color group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorGroupStats {
+    pub group: ColorGroup,
+    pub count: u32,
+    /// Share of the whole cube, 0-1
+    pub share: f64,
+    /// Cards at each CMC from 0 to `MAX_CURVE_CMC`+, empty for lands
+    pub curve: Vec<u32>,
+    pub average_cmc: f64,
+    pub creatures: u32,
+}
+
+/// How many cards support a theme within a color pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeSupport {
+    pub theme: Theme,
+    pub cards: u32,
+}
+
+/// Cards available to a two-color archetype and the themes they support
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchetypeSupport {
+    pub colors: Vec<Color>,
+    /// Guild name for the pair
+    pub name: String,
+    /// Colored cards castable with only these two colors
+    pub playables: u32,
+    /// Cards that are exactly these two colors
+    pub gold_cards: u32,
+    /// Best-supported themes, most cards first
+    pub themes: Vec<ThemeSupport>,
+}
+
+/// A card with more copies than a singleton cube allows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCard {
+    pub name: String,
+    pub copies: u32,
+}
+
+/// Color balance, curves, archetype support and list checks for a cube
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CubeAnalysis {
+    pub cube_name: Option<String>,
+    pub total_cards: u32,
+    pub unique_cards: u32,
+    pub colors: Vec<ColorGroupStats>,
+    /// Support for each guild pair, in WUBRG pair order
+    pub archetypes: Vec<ArchetypeSupport>,
+    pub duplicates: Vec<DuplicateCard>,
+    /// Cards without card data
+    pub unknown_cards: Vec<String>,
+    /// Banned and illegal cards, when checked against a format
+    pub legality: Option<LegalityReport>,
+}
+
+/// A card added to or removed from a cube, with its copy count
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CubeChange {
+    pub name: String,
+    pub quantity: u32,
+}
+
+/// Card count of one color group before and after a change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorGroupChange {
+    pub group: ColorGroup,
+    pub before: u32,
+    pub after: u32,
+}
+
+/// Differences between two versions of a cube
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CubeDiff {
+    pub old_name: Option<String>,
+    pub new_name: Option<String>,
+    pub old_total: u32,
+    pub new_total: u32,
+    pub added: Vec<CubeChange>,
+    pub removed: Vec<CubeChange>,
+    /// Color groups whose card count changed
+    pub colors: Vec<ColorGroupChange>,
+}
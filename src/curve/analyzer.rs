@@ -1,13 +1,23 @@
 use crate::input::DeckList;
 use std::collections::HashMap;
 
+use super::effective::estimate_effective_cost;
 use super::types::{CmcBucket, ColorPipBreakdown, CurveAnalysis, CurveStats};
 
-pub struct CurveAnalyzer;
+pub struct CurveAnalyzer {
+    /// Bucket cards by estimated effective cost instead of printed CMC
+    use_effective_cmc: bool,
+}
 
 impl CurveAnalyzer {
     pub fn new() -> Self {
-        Self
+        Self {
+            use_effective_cmc: false,
+        }
+    }
+
+    pub fn with_effective_cmc(use_effective_cmc: bool) -> Self {
+        Self { use_effective_cmc }
     }
 
     /// Analyze a deck's mana curve
@@ -15,12 +25,14 @@ impl CurveAnalyzer {
         let mut analysis = CurveAnalysis::new();
         analysis.deck_name = deck_list.name.clone();
         analysis.deck_format = deck_list.format.clone();
-        analysis.total_cards = deck_list.total_cards();
-        analysis.unique_cards = deck_list.unique_cards() as u32;
+        analysis.total_cards = deck_list.mainboard_cards();
+        analysis.unique_cards = deck_list.mainboard().count() as u32;
+        analysis.bucketed_by_effective = self.use_effective_cmc;
 
         // Collect CMC data from hydrated cards (mainboard only, excluding lands)
         let mut cmc_map: HashMap<u32, CmcBucket> = HashMap::new();
         let mut all_cmcs: Vec<f64> = Vec::new();
+        let mut all_effective_cmcs: Vec<f64> = Vec::new();
         let mut pip_breakdown = ColorPipBreakdown::default();
 
         for entry in deck_list.mainboard() {
@@ -30,13 +42,21 @@ impl CurveAnalyzer {
                     continue;
                 }
 
-                let cmc = card.cmc.round() as u32;
+                let effective = estimate_effective_cost(card);
+                let bucket_cmc = if self.use_effective_cmc {
+                    effective.effective_cmc
+                } else {
+                    card.cmc
+                };
+                let cmc = bucket_cmc.round() as u32;
                 let is_creature = Self::is_creature(&card.type_line);
 
                 // Track for statistics (accounting for quantity)
                 for _ in 0..entry.quantity {
-                    all_cmcs.push(card.cmc);
+                    all_cmcs.push(bucket_cmc);
+                    all_effective_cmcs.push(effective.effective_cmc);
                 }
+                analysis.effective_costs.push(effective);
 
                 // Count color pips from mana cost
                 if let Some(mana_cost) = &card.mana_cost {
@@ -64,7 +84,11 @@ impl CurveAnalyzer {
         buckets.sort_by_key(|b| b.cmc);
 
         // Calculate statistics
-        let stats = Self::calculate_stats(&buckets, &all_cmcs);
+        let mut stats = Self::calculate_stats(&buckets, &all_cmcs);
+        if !all_effective_cmcs.is_empty() {
+            stats.effective_average_cmc =
+                all_effective_cmcs.iter().sum::<f64>() / all_effective_cmcs.len() as f64;
+        }
 
         // Calculate max values for histogram scaling
         let max_cmc = buckets.iter().map(|b| b.cmc).max().unwrap_or(0);
@@ -202,6 +226,7 @@ impl CurveAnalyzer {
             cmc_distribution,
             creature_distribution,
             non_creature_distribution,
+            effective_average_cmc: 0.0,
         }
     }
 }
@@ -0,0 +1,284 @@
+//! Effective mana value estimation.
+//!
+//! Raw CMC overstates cards that are routinely cast for less than their printed
+//! cost. This module estimates a realistic cost using keyword abilities
+//! (Convoke, Delve, Affinity, Improvise, Evoke, Foretell) and oracle text cost
+//! reductions or alternative costs.
+
+use crate::api::Card;
+use crate::synergy::keywords::extract_keywords;
+use crate::synergy::Keyword;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Generic mana typically covered by Delve once the game is underway
+const DELVE_DISCOUNT: f64 = 3.0;
+/// Generic mana typically covered by Convoke, Affinity or Improvise
+const BOARD_DISCOUNT: f64 = 2.0;
+/// Assumed count for "for each" / "where X is" cost reductions
+const SCALING_REDUCTION_COUNT: f64 = 2.0;
+/// Mana spent on the turn a card is foretold
+const FORETELL_SETUP_COST: f64 = 2.0;
+
+lazy_static! {
+    static ref EVOKE_COST: Regex = Regex::new(r"(?i)\bevoke ((?:\{[^}]+\})+)").unwrap();
+    static ref FORETELL_COST: Regex = Regex::new(r"(?i)\bforetell ((?:\{[^}]+\})+)").unwrap();
+    static ref ALTERNATIVE_MANA_COST: Regex =
+        Regex::new(r"(?i)you may pay ((?:\{[^}]+\})+) rather than pay this spell's mana cost")
+            .unwrap();
+    static ref ALTERNATIVE_COST: Regex =
+        Regex::new(r"(?i)rather than pay this spell's mana cost").unwrap();
+    static ref SELF_REDUCTION: Regex =
+        Regex::new(r"(?i)this spell costs \{(\d+)\} less to cast( for each| where)?").unwrap();
+    static ref SELF_REDUCTION_X: Regex =
+        Regex::new(r"(?i)this spell costs \{X\} less to cast").unwrap();
+}
+
+/// Raw and effective cost for a single card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveCost {
+    pub card_name: String,
+    pub cmc: f64,
+    pub effective_cmc: f64,
+    /// Earliest realistic turn to cast the card on curve
+    pub cast_turn: u32,
+    /// What lowered the cost, if anything
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl EffectiveCost {
+    pub fn is_reduced(&self) -> bool {
+        self.effective_cmc < self.cmc
+    }
+}
+
+/// Estimate the effective mana value of a card
+pub fn estimate_effective_cost(card: &Card) -> EffectiveCost {
+    let mana_cost = card
+        .mana_cost
+        .clone()
+        .filter(|c| !c.is_empty())
+        .or_else(|| {
+            card.card_faces
+                .as_ref()
+                .and_then(|faces| faces.first())
+                .and_then(|face| face.mana_cost.clone())
+        })
+        .unwrap_or_default();
+
+    let cmc = card.cmc;
+    let symbols = ManaSymbols::parse(&mana_cost);
+    // Colored pips can never be paid by generic reductions
+    let floor = symbols.colored;
+
+    let oracle = card.all_oracle_text().join("\n");
+    let keywords = extract_keywords(card);
+
+    let mut best = cmc;
+    let mut reason: Option<String> = None;
+    let mut consider = |estimate: f64, why: String| {
+        let estimate = estimate.max(0.0);
+        if estimate < best {
+            best = estimate;
+            reason = Some(why);
+        }
+    };
+
+    if symbols.phyrexian > 0.0 {
+        consider(
+            cmc - symbols.phyrexian,
+            "Phyrexian mana paid with life".to_string(),
+        );
+    }
+
+    for keyword in &keywords {
+        match keyword {
+            Keyword::Delve => consider((cmc - DELVE_DISCOUNT).max(floor), "Delve".to_string()),
+            Keyword::Convoke | Keyword::Affinity | Keyword::Improvise => {
+                consider((cmc - BOARD_DISCOUNT).max(floor), keyword.display_name())
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(caps) = EVOKE_COST.captures(&oracle) {
+        let evoke = ManaSymbols::parse(&caps[1]).mana_value;
+        consider(evoke, format!("Evoke for {}", &caps[1]));
+    }
+
+    if let Some(caps) = FORETELL_COST.captures(&oracle) {
+        let foretell = ManaSymbols::parse(&caps[1]).mana_value;
+        consider(
+            foretell.max(FORETELL_SETUP_COST),
+            format!("Foretold, then cast for {}", &caps[1]),
+        );
+    }
+
+    if let Some(caps) = ALTERNATIVE_MANA_COST.captures(&oracle) {
+        let alternative = ManaSymbols::parse(&caps[1]).mana_value;
+        consider(alternative, format!("Alternative cost {}", &caps[1]));
+    } else if ALTERNATIVE_COST.is_match(&oracle) {
+        consider(0.0, "Free alternative cost".to_string());
+    }
+
+    if let Some(caps) = SELF_REDUCTION.captures(&oracle) {
+        let amount: f64 = caps[1].parse().unwrap_or(0.0);
+        let reduction = if caps.get(2).is_some() {
+            amount * SCALING_REDUCTION_COUNT
+        } else {
+            amount
+        };
+        consider(
+            (cmc - reduction).max(floor),
+            format!("Costs {{{}}} less", reduction as u32),
+        );
+    } else if SELF_REDUCTION_X.is_match(&oracle) {
+        consider(
+            (cmc - SCALING_REDUCTION_COUNT).max(floor),
+            format!("Costs {{{}}} less", SCALING_REDUCTION_COUNT as u32),
+        );
+    }
+
+    EffectiveCost {
+        card_name: card.name.clone(),
+        cmc,
+        effective_cmc: best,
+        cast_turn: (best.ceil() as u32).max(1),
+        reason,
+    }
+}
+
+/// Summary of the symbols in a mana cost string
+#[derive(Debug, Default, Clone, Copy)]
+struct ManaSymbols {
+    mana_value: f64,
+    colored: f64,
+    phyrexian: f64,
+}
+
+impl ManaSymbols {
+    /// Parse a mana cost like "{2}{U}{B/P}" (X counts as zero)
+    fn parse(cost: &str) -> Self {
+        let mut symbols = ManaSymbols::default();
+
+        for symbol in cost
+            .split('{')
+            .filter_map(|part| part.split('}').next())
+            .filter(|s| !s.is_empty())
+        {
+            let upper = symbol.to_uppercase();
+            if let Ok(generic) = upper.parse::<f64>() {
+                symbols.mana_value += generic;
+            } else if upper == "X" || upper == "Y" || upper == "Z" {
+                continue;
+            } else if upper.ends_with("/P") {
+                symbols.mana_value += 1.0;
+                symbols.phyrexian += 1.0;
+            } else if let Some((left, _)) = upper.split_once('/') {
+                // Hybrid: {2/W} counts as 2, {W/U} as 1
+                let value = left.parse::<f64>().unwrap_or(1.0);
+                symbols.mana_value += value;
+                if value == 1.0 {
+                    symbols.colored += 1.0;
+                }
+            } else {
+                symbols.mana_value += 1.0;
+                symbols.colored += 1.0;
+            }
+        }
+
+        symbols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    #[test]
+    fn test_plain_card_is_unchanged() {
+        let spell = card("Divination")
+            .mana_cost("{2}{U}")
+            .cmc(3.0)
+            .oracle("Draw two cards.")
+            .build();
+        let cost = estimate_effective_cost(&spell);
+        assert_eq!(cost.effective_cmc, 3.0);
+        assert_eq!(cost.cast_turn, 3);
+        assert!(cost.reason.is_none());
+    }
+
+    #[test]
+    fn test_delve_keeps_colored_pips() {
+        let spell = card("Treasure Cruise")
+            .mana_cost("{7}{U}")
+            .cmc(8.0)
+            .oracle("Delve (Each card you exile from your graveyard while casting this spell pays for {1}.)\nDraw three cards.")
+            .build();
+        let cost = estimate_effective_cost(&spell);
+        assert_eq!(cost.effective_cmc, 5.0);
+        assert_eq!(cost.reason.as_deref(), Some("Delve"));
+    }
+
+    #[test]
+    fn test_evoke_uses_alternative_cost() {
+        let spell = card("Fury")
+            .mana_cost("{3}{R}{R}")
+            .cmc(5.0)
+            .oracle("Double strike\nWhen Fury enters, it deals 4 damage divided as you choose among any number of target creatures and/or planeswalkers.\nEvoke—Exile a red card from your hand.")
+            .build();
+        // Evoke without a mana cost is not parsed as mana
+        assert_eq!(estimate_effective_cost(&spell).effective_cmc, 5.0);
+
+        let spell = card("Shriekmaw")
+            .mana_cost("{4}{B}")
+            .cmc(5.0)
+            .oracle("Fear\nWhen Shriekmaw enters, destroy target nonartifact, nonblack creature.\nEvoke {1}{B}")
+            .build();
+        let cost = estimate_effective_cost(&spell);
+        assert_eq!(cost.effective_cmc, 2.0);
+        assert_eq!(cost.cast_turn, 2);
+    }
+
+    #[test]
+    fn test_free_alternative_cost() {
+        let spell = card("Force of Will")
+            .mana_cost("{3}{U}{U}")
+            .cmc(5.0)
+            .oracle("You may pay 1 life and exile a blue card from your hand rather than pay this spell's mana cost.\nCounter target spell.")
+            .build();
+        let cost = estimate_effective_cost(&spell);
+        assert_eq!(cost.effective_cmc, 0.0);
+        assert_eq!(cost.cast_turn, 1);
+    }
+
+    #[test]
+    fn test_scaling_self_reduction() {
+        let spell = card("Ghalta, Primal Hunger")
+            .mana_cost("{10}{G}{G}")
+            .cmc(12.0)
+            .oracle("This spell costs {X} less to cast, where X is the total power of creatures you control.\nTrample")
+            .build();
+        assert_eq!(estimate_effective_cost(&spell).effective_cmc, 10.0);
+
+        let spell = card("Blasphemous Act")
+            .mana_cost("{8}{R}")
+            .cmc(9.0)
+            .oracle("This spell costs {1} less to cast for each creature on the battlefield.")
+            .build();
+        assert_eq!(estimate_effective_cost(&spell).effective_cmc, 7.0);
+    }
+
+    #[test]
+    fn test_phyrexian_mana() {
+        let spell = card("Gitaxian Probe")
+            .mana_cost("{U/P}")
+            .cmc(1.0)
+            .oracle("Look at target player's hand.")
+            .build();
+        assert_eq!(estimate_effective_cost(&spell).effective_cmc, 0.0);
+    }
+}
@@ -5,7 +5,9 @@
 
 use crate::calculator::get_calculator;
 use crate::curve::{CurveAnalysis, LandCountSource};
-use crate::deck::{guild_name, Algorithm, Color, Deck, DualLand, Format, ManaBase};
+use crate::deck::{
+    color_combination_name, sorted_colors, Algorithm, Color, Deck, DualLand, Format, ManaBase,
+};
 use crate::input::DeckList;
 use std::collections::HashMap;
 
@@ -58,8 +60,9 @@ pub fn detect_format_from_deck(deck_list: &DeckList) -> Format {
         }
     }
 
-    // Fallback to card count heuristics
-    let total = deck_list.total_cards();
+    // Fallback to card count heuristics; sideboard and maybeboard cards don't
+    // count toward deck size
+    let total = deck_list.mainboard_cards();
     let has_commander = deck_list.commanders().count() > 0;
 
     if has_commander || total >= 99 {
@@ -86,52 +89,47 @@ pub fn detect_dual_lands(deck_list: &DeckList) -> Vec<DualLand> {
             continue;
         }
 
-        // Check if it has multiple colors in its identity
-        if card.color_identity.len() < 2 {
-            continue;
-        }
-
-        // Convert color identity strings to Color enum
-        let mut colors: Vec<Color> = card
+        // Convert color identity strings to Color enum, in WUBRG order for
+        // consistent grouping
+        let identity: Vec<Color> = card
             .color_identity
             .iter()
             .filter_map(|s| Color::from_symbol(s))
             .collect();
+        let colors = sorted_colors(&identity);
 
-        // Sort colors for consistent grouping
-        colors.sort_by_key(|c| match c {
-            Color::White => 0,
-            Color::Blue => 1,
-            Color::Black => 2,
-            Color::Red => 3,
-            Color::Green => 4,
-            Color::Colorless => 5,
-        });
+        // Check if it makes more than one color
+        if colors.len() < 2 {
+            continue;
+        }
 
         // Add to the group
         *land_groups.entry(colors).or_insert(0) += entry.quantity;
     }
 
-    // Convert groups to DualLand structs
-    land_groups
+    // Convert groups to DualLand structs, named for their color combination
+    // (guild, shard, wedge, four- or five-color)
+    let mut duals: Vec<DualLand> = land_groups
         .into_iter()
         .map(|(colors, count)| {
-            // Generate a name for the land group
-            let name = if colors.len() == 2 {
-                guild_name(&colors)
-                    .map(|g| format!("{g} lands"))
-                    .unwrap_or_else(|| {
-                        let symbols: Vec<_> = colors.iter().map(|c| c.symbol()).collect();
-                        format!("{} lands", symbols.join("/"))
-                    })
-            } else {
-                let symbols: Vec<_> = colors.iter().map(|c| c.symbol()).collect();
-                format!("{}-color lands", symbols.join("/"))
+            let name = match color_combination_name(&colors) {
+                Some(combination) => format!("{combination} lands"),
+                None => {
+                    let symbols: Vec<_> = colors.iter().map(|c| c.symbol()).collect();
+                    format!("{} lands", symbols.join("/"))
+                }
             };
 
             DualLand::new(name, colors, count)
         })
-        .collect()
+        .collect();
+    duals.sort_by(|a, b| {
+        a.colors
+            .len()
+            .cmp(&b.colors.len())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    duals
 }
 
 /// Build a Deck struct from CurveAnalysis for calculator input
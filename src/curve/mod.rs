@@ -1,7 +1,10 @@
 pub mod analyzer;
+pub mod effective;
 pub mod mana_bridge;
+pub mod templates;
 pub mod types;
 
 pub use analyzer::*;
 pub use mana_bridge::*;
+pub use templates::*;
 pub use types::*;
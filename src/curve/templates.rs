@@ -0,0 +1,327 @@
+//! Ideal mana curve templates and deviation scoring.
+//!
+//! Each archetype has a target share of non-land cards per CMC slot. A deck's
+//! actual distribution is compared slot-by-slot against the template to find
+//! over- and under-loaded parts of the curve.
+
+use crate::deck::Format;
+use crate::synergy::archetype::MIN_ARCHETYPE_SCORE;
+use crate::synergy::{SynergyMatrix, Theme};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::types::CmcBucket;
+
+/// Lowest CMC slot in a template (0 and 1 drops are grouped together)
+pub const MIN_TEMPLATE_CMC: u32 = 1;
+/// Highest CMC slot in a template (everything at or above is grouped together)
+pub const MAX_TEMPLATE_CMC: u32 = 6;
+
+/// Deviation (as a fraction of non-land cards) before a slot is flagged
+const DEVIATION_TOLERANCE: f64 = 0.05;
+
+/// Archetypes with a known target curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CurveArchetype {
+    Aggro,
+    Midrange,
+    Control,
+    Commander,
+    Limited,
+}
+
+impl CurveArchetype {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CurveArchetype::Aggro => "Aggro",
+            CurveArchetype::Midrange => "Midrange",
+            CurveArchetype::Control => "Control",
+            CurveArchetype::Commander => "Commander",
+            CurveArchetype::Limited => "Limited",
+        }
+    }
+
+    /// Target share of non-land cards for CMC slots 1 through 6+
+    pub fn target_distribution(&self) -> [f64; 6] {
+        match self {
+            CurveArchetype::Aggro => [0.30, 0.35, 0.20, 0.10, 0.05, 0.00],
+            CurveArchetype::Midrange => [0.12, 0.28, 0.25, 0.20, 0.10, 0.05],
+            CurveArchetype::Control => [0.10, 0.25, 0.20, 0.20, 0.12, 0.13],
+            CurveArchetype::Commander => [0.10, 0.20, 0.22, 0.20, 0.13, 0.15],
+            CurveArchetype::Limited => [0.05, 0.30, 0.27, 0.20, 0.12, 0.06],
+        }
+    }
+
+    /// Pick an archetype from the deck format and classified strategy.
+    ///
+    /// Format wins for Commander and Limited since their curves are shaped by
+    /// deck size rather than strategy. For constructed decks the highest
+    /// scoring strategy archetype with a known curve decides, as long as it
+    /// clears the classifier's confidence threshold, falling back to average CMC.
+    pub fn select(format: Format, matrix: Option<&SynergyMatrix>, average_cmc: f64) -> Self {
+        match format {
+            Format::Commander => return CurveArchetype::Commander,
+            Format::Limited => return CurveArchetype::Limited,
+            _ => {}
+        }
+
+        let classified = matrix.and_then(|matrix| {
+            matrix
+                .archetypes
+                .iter()
+                .filter(|a| a.score >= MIN_ARCHETYPE_SCORE)
+                .find_map(|a| Self::from_theme(&a.theme))
+        });
+        if let Some(archetype) = classified {
+            return archetype;
+        }
+
+        if average_cmc < 2.3 {
+            CurveArchetype::Aggro
+        } else if average_cmc > 3.3 {
+            CurveArchetype::Control
+        } else {
+            CurveArchetype::Midrange
+        }
+    }
+
+    /// Map a strategy theme onto the curve it usually wants
+    fn from_theme(theme: &Theme) -> Option<Self> {
+        match theme {
+            Theme::Aggro | Theme::Voltron => Some(CurveArchetype::Aggro),
+            Theme::Control | Theme::Stax | Theme::Spellslinger => Some(CurveArchetype::Control),
+            Theme::Midrange | Theme::Aristocrats | Theme::Reanimator => {
+                Some(CurveArchetype::Midrange)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CurveArchetype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Template slot a CMC falls into
+pub fn template_slot(cmc: u32) -> u32 {
+    cmc.clamp(MIN_TEMPLATE_CMC, MAX_TEMPLATE_CMC)
+}
+
+/// Display label for a template slot
+pub fn slot_label(slot: u32) -> String {
+    if slot <= MIN_TEMPLATE_CMC {
+        format!("≤{MIN_TEMPLATE_CMC}")
+    } else if slot >= MAX_TEMPLATE_CMC {
+        format!("{MAX_TEMPLATE_CMC}+")
+    } else {
+        slot.to_string()
+    }
+}
+
+/// Whether a CMC slot has more or fewer cards than the template wants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BucketLoad {
+    Over,
+    Under,
+    OnTarget,
+}
+
+/// Comparison of one CMC slot against the template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketDeviation {
+    pub slot: u32,
+    pub actual_count: u32,
+    pub actual_share: f64,
+    pub target_share: f64,
+    /// Actual minus target share (positive = over-loaded)
+    pub deviation: f64,
+    /// Card count the template suggests for this deck size
+    pub target_count: f64,
+    pub load: BucketLoad,
+}
+
+impl BucketDeviation {
+    pub fn label(&self) -> String {
+        slot_label(self.slot)
+    }
+}
+
+/// How closely a deck's curve matches an archetype template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurveFit {
+    pub archetype: CurveArchetype,
+    pub deviations: Vec<BucketDeviation>,
+    /// 0-100, where 100 is an exact match to the template
+    pub score: f64,
+}
+
+impl CurveFit {
+    /// Score a set of CMC buckets against an archetype template
+    pub fn evaluate(buckets: &[CmcBucket], archetype: CurveArchetype) -> Self {
+        let total: u32 = buckets.iter().map(|b| b.total_count).sum();
+        let mut slot_counts = [0u32; 6];
+        for bucket in buckets {
+            slot_counts[template_slot(bucket.cmc) as usize - 1] += bucket.total_count;
+        }
+
+        let targets = archetype.target_distribution();
+        let deviations: Vec<BucketDeviation> = (MIN_TEMPLATE_CMC..=MAX_TEMPLATE_CMC)
+            .map(|slot| {
+                let index = slot as usize - 1;
+                let actual_count = slot_counts[index];
+                let actual_share = if total > 0 {
+                    actual_count as f64 / total as f64
+                } else {
+                    0.0
+                };
+                let target_share = targets[index];
+                let deviation = actual_share - target_share;
+                let load = if deviation > DEVIATION_TOLERANCE {
+                    BucketLoad::Over
+                } else if deviation < -DEVIATION_TOLERANCE {
+                    BucketLoad::Under
+                } else {
+                    BucketLoad::OnTarget
+                };
+
+                BucketDeviation {
+                    slot,
+                    actual_count,
+                    actual_share,
+                    target_share,
+                    deviation,
+                    target_count: target_share * total as f64,
+                    load,
+                }
+            })
+            .collect();
+
+        // Total variation distance between the two distributions
+        let distance: f64 = deviations.iter().map(|d| d.deviation.abs()).sum::<f64>() / 2.0;
+        let score = if total > 0 {
+            ((1.0 - distance) * 100.0).clamp(0.0, 100.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            archetype,
+            deviations,
+            score,
+        }
+    }
+
+    /// Deviation for the slot a CMC falls into
+    pub fn deviation_for(&self, cmc: u32) -> Option<&BucketDeviation> {
+        let slot = template_slot(cmc);
+        self.deviations.iter().find(|d| d.slot == slot)
+    }
+
+    /// Slots flagged as over- or under-loaded
+    pub fn flagged(&self) -> impl Iterator<Item = &BucketDeviation> {
+        self.deviations
+            .iter()
+            .filter(|d| d.load != BucketLoad::OnTarget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(cmc: u32, count: u32) -> CmcBucket {
+        let mut bucket = CmcBucket::new(cmc);
+        bucket.total_count = count;
+        bucket
+    }
+
+    fn archetype(theme: Theme, score: f64) -> crate::synergy::ArchetypeScore {
+        crate::synergy::ArchetypeScore {
+            theme,
+            score,
+            evidence: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_templates_sum_to_one() {
+        for archetype in [
+            CurveArchetype::Aggro,
+            CurveArchetype::Midrange,
+            CurveArchetype::Control,
+            CurveArchetype::Commander,
+            CurveArchetype::Limited,
+        ] {
+            let sum: f64 = archetype.target_distribution().iter().sum();
+            assert!((sum - 1.0).abs() < 1e-9, "{archetype} sums to {sum}");
+        }
+    }
+
+    #[test]
+    fn test_exact_match_scores_100() {
+        // 20 cards matching the aggro template exactly
+        let buckets = vec![
+            bucket(1, 6),
+            bucket(2, 7),
+            bucket(3, 4),
+            bucket(4, 2),
+            bucket(5, 1),
+        ];
+        let fit = CurveFit::evaluate(&buckets, CurveArchetype::Aggro);
+        assert!((fit.score - 100.0).abs() < 1e-9);
+        assert_eq!(fit.flagged().count(), 0);
+    }
+
+    #[test]
+    fn test_top_heavy_curve_flags_over_and_under() {
+        let buckets = vec![bucket(2, 2), bucket(6, 10), bucket(8, 8)];
+        let fit = CurveFit::evaluate(&buckets, CurveArchetype::Aggro);
+
+        assert_eq!(fit.deviation_for(7).unwrap().load, BucketLoad::Over);
+        assert_eq!(fit.deviation_for(6).unwrap().actual_count, 18);
+        assert_eq!(fit.deviation_for(1).unwrap().load, BucketLoad::Under);
+        assert!(fit.score < 30.0);
+    }
+
+    #[test]
+    fn test_zero_drops_group_with_one_drops() {
+        let buckets = vec![bucket(0, 2), bucket(1, 2)];
+        let fit = CurveFit::evaluate(&buckets, CurveArchetype::Midrange);
+        assert_eq!(fit.deviation_for(0).unwrap().actual_count, 4);
+    }
+
+    #[test]
+    fn test_select_prefers_format_then_archetype() {
+        assert_eq!(
+            CurveArchetype::select(Format::Commander, None, 2.0),
+            CurveArchetype::Commander
+        );
+
+        let mut matrix = SynergyMatrix::new();
+        matrix.archetypes = vec![archetype(Theme::Stax, 0.8), archetype(Theme::Aggro, 0.35)];
+        assert_eq!(
+            CurveArchetype::select(Format::Modern, Some(&matrix), 2.0),
+            CurveArchetype::Control
+        );
+
+        // Storm has no curve of its own, so the next confident archetype decides
+        matrix.archetypes = vec![archetype(Theme::Storm, 0.9), archetype(Theme::Control, 0.6)];
+        assert_eq!(
+            CurveArchetype::select(Format::Modern, Some(&matrix), 2.0),
+            CurveArchetype::Control
+        );
+
+        // Low-confidence archetypes are ignored in favor of average CMC
+        matrix.archetypes = vec![archetype(Theme::Control, 0.2)];
+        assert_eq!(
+            CurveArchetype::select(Format::Standard, Some(&matrix), 2.0),
+            CurveArchetype::Aggro
+        );
+        assert_eq!(
+            CurveArchetype::select(Format::Standard, None, 2.0),
+            CurveArchetype::Aggro
+        );
+    }
+}
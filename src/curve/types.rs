@@ -2,6 +2,9 @@ use crate::deck::{Color, ManaBase};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::effective::EffectiveCost;
+use super::templates::CurveFit;
+
 /// Breakdown of colored mana pips in a deck
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ColorPipBreakdown {
@@ -113,6 +116,9 @@ pub struct CurveStats {
     pub cmc_distribution: HashMap<u32, f64>,
     pub creature_distribution: HashMap<u32, f64>,
     pub non_creature_distribution: HashMap<u32, f64>,
+    /// Average effective mana value after cost reductions and alternative costs
+    #[serde(default)]
+    pub effective_average_cmc: f64,
 }
 
 /// How the target land count was determined
@@ -138,6 +144,12 @@ pub struct CurveAnalysis {
     pub max_cmc: u32,
     pub max_count: u32,
     pub pip_breakdown: ColorPipBreakdown,
+    /// Whether buckets use effective cost instead of printed CMC
+    #[serde(default)]
+    pub bucketed_by_effective: bool,
+    /// Printed and effective cost for each non-land card
+    #[serde(default)]
+    pub effective_costs: Vec<EffectiveCost>,
     /// Optional mana base recommendation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mana_base: Option<ManaBase>,
@@ -147,6 +159,9 @@ pub struct CurveAnalysis {
     /// How the land count was determined
     #[serde(skip_serializing_if = "Option::is_none")]
     pub land_source: Option<LandCountSource>,
+    /// Comparison against the ideal curve for the deck's archetype
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curve_fit: Option<CurveFit>,
 }
 
 impl CurveAnalysis {
@@ -161,9 +176,12 @@ impl CurveAnalysis {
             max_cmc: 0,
             max_count: 0,
             pip_breakdown: ColorPipBreakdown::default(),
+            bucketed_by_effective: false,
+            effective_costs: Vec::new(),
             mana_base: None,
             target_lands: None,
             land_source: None,
+            curve_fit: None,
         }
     }
 }
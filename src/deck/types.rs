@@ -83,13 +83,19 @@ pub struct DualLand {
 }
 
 impl DualLand {
+    /// A group of lands producing `colors`, stored in WUBRG order
     pub fn new(name: String, colors: Vec<Color>, count: u32) -> Self {
         Self {
             name,
-            colors,
+            colors: sorted_colors(&colors),
             count,
         }
     }
+
+    /// Name of the land's color combination, e.g. "Grixis" for a U/B/R tri-land
+    pub fn combination_name(&self) -> Option<&'static str> {
+        color_combination_name(&self.colors)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -132,6 +138,14 @@ impl Format {
         }
     }
 
+    /// Largest sideboard the format allows; Commander and Limited don't cap it
+    pub fn max_sideboard(&self) -> Option<u32> {
+        match self {
+            Format::Standard | Format::Modern | Format::Custom => Some(15),
+            Format::Commander | Format::Limited => None,
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Format::Commander => "Commander",
@@ -177,6 +191,21 @@ impl Deck {
         self.mana_symbols.values().sum()
     }
 
+    /// Sources each deck color gets from dual and multi-color lands. A land
+    /// counts once for every deck color it makes, however many colors it has,
+    /// and colors the deck doesn't use are ignored.
+    pub fn dual_sources(&self) -> HashMap<Color, f64> {
+        let mut sources: HashMap<Color, f64> = HashMap::new();
+        for dual in &self.dual_lands {
+            for color in sorted_colors(&dual.colors) {
+                if self.colors.contains(&color) {
+                    *sources.entry(color).or_insert(0.0) += dual.count as f64;
+                }
+            }
+        }
+        sources
+    }
+
     pub fn dual_land_count(&self) -> u32 {
         self.dual_lands.iter().map(|d| d.count).sum()
     }
@@ -234,33 +263,99 @@ impl fmt::Display for Algorithm {
     }
 }
 
+/// Colors in WUBRG order without duplicates; colorless is dropped
+pub fn sorted_colors(colors: &[Color]) -> Vec<Color> {
+    Color::all_colors()
+        .into_iter()
+        .filter(|c| colors.contains(c))
+        .collect()
+}
+
+/// Name of a color combination: guilds for two colors, shards and wedges for
+/// three, the Nephilim names for four, and "Five-Color" for all five
+pub fn color_combination_name(colors: &[Color]) -> Option<&'static str> {
+    let symbols: String = sorted_colors(colors).iter().map(|c| c.symbol()).collect();
+    match symbols.as_str() {
+        // Guilds
+        "WU" => Some("Azorius"),
+        "WB" => Some("Orzhov"),
+        "WR" => Some("Boros"),
+        "WG" => Some("Selesnya"),
+        "UB" => Some("Dimir"),
+        "UR" => Some("Izzet"),
+        "UG" => Some("Simic"),
+        "BR" => Some("Rakdos"),
+        "BG" => Some("Golgari"),
+        "RG" => Some("Gruul"),
+        // Shards
+        "WUG" => Some("Bant"),
+        "WUB" => Some("Esper"),
+        "UBR" => Some("Grixis"),
+        "BRG" => Some("Jund"),
+        "WRG" => Some("Naya"),
+        // Wedges
+        "WBG" => Some("Abzan"),
+        "WUR" => Some("Jeskai"),
+        "UBG" => Some("Sultai"),
+        "WBR" => Some("Mardu"),
+        "URG" => Some("Temur"),
+        // Four colors, named for the missing one
+        "UBRG" => Some("Glint-Eye"),
+        "WBRG" => Some("Dune-Brood"),
+        "WURG" => Some("Ink-Treader"),
+        "WUBG" => Some("Witch-Maw"),
+        "WUBR" => Some("Yore-Tiller"),
+        "WUBRG" => Some("Five-Color"),
+        _ => None,
+    }
+}
+
+/// Color symbols joined with slashes, followed by the combination name when
+/// there is one, e.g. "U/B/R (Grixis)"
+pub fn color_label(colors: &[Color]) -> String {
+    let sorted = sorted_colors(colors);
+    let symbols: Vec<&str> = sorted.iter().map(|c| c.symbol()).collect();
+    match color_combination_name(&sorted) {
+        Some(name) => format!("{} ({name})", symbols.join("/")),
+        None => symbols.join("/"),
+    }
+}
+
 // Guild names for dual color combinations
 pub fn guild_name(colors: &[Color]) -> Option<&'static str> {
-    if colors.len() != 2 {
+    if sorted_colors(colors).len() != 2 {
         return None;
     }
+    color_combination_name(colors)
+}
 
-    let mut sorted = colors.to_vec();
-    sorted.sort_by_key(|c| match c {
-        Color::White => 0,
-        Color::Blue => 1,
-        Color::Black => 2,
-        Color::Red => 3,
-        Color::Green => 4,
-        Color::Colorless => 5,
-    });
-
-    match (sorted.first(), sorted.get(1)) {
-        (Some(Color::White), Some(Color::Blue)) => Some("Azorius"),
-        (Some(Color::White), Some(Color::Black)) => Some("Orzhov"),
-        (Some(Color::White), Some(Color::Red)) => Some("Boros"),
-        (Some(Color::White), Some(Color::Green)) => Some("Selesnya"),
-        (Some(Color::Blue), Some(Color::Black)) => Some("Dimir"),
-        (Some(Color::Blue), Some(Color::Red)) => Some("Izzet"),
-        (Some(Color::Blue), Some(Color::Green)) => Some("Simic"),
-        (Some(Color::Black), Some(Color::Red)) => Some("Rakdos"),
-        (Some(Color::Black), Some(Color::Green)) => Some("Golgari"),
-        (Some(Color::Red), Some(Color::Green)) => Some("Gruul"),
-        _ => None,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_combination_names() {
+        use Color::*;
+        assert_eq!(color_combination_name(&[Black, Blue]), Some("Dimir"));
+        assert_eq!(color_combination_name(&[Red, Blue, Black]), Some("Grixis"));
+        assert_eq!(color_combination_name(&[Green, Black, Red]), Some("Jund"));
+        assert_eq!(
+            color_combination_name(&[White, Black, Green]),
+            Some("Abzan")
+        );
+        assert_eq!(
+            color_combination_name(&[Blue, Black, Red, Green]),
+            Some("Glint-Eye")
+        );
+        assert_eq!(
+            color_combination_name(&Color::all_colors()),
+            Some("Five-Color")
+        );
+        assert_eq!(color_combination_name(&[Red]), None);
+        assert_eq!(guild_name(&[Red, Blue, Black]), None);
+        assert_eq!(
+            color_label(&[Red, Colorless, Blue, Black]),
+            "U/B/R (Grixis)"
+        );
     }
 }
@@ -0,0 +1,98 @@
+use crate::composition::{CompositionAnalysis, TargetStatus};
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+/// Markdown exporter for deck composition reports
+pub struct CompositionReportExporter;
+
+impl CompositionReportExporter {
+    /// Export composition analysis to a markdown file
+    pub fn export(
+        analysis: &CompositionAnalysis,
+        path: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(analysis);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(analysis: &CompositionAnalysis) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Deck Composition\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        // Deck info
+        if let Some(name) = &analysis.deck_name {
+            output.push_str(&format!("**Deck**: {name}\n"));
+        }
+        if let Some(format) = &analysis.deck_format {
+            output.push_str(&format!("**Format**: {format}\n"));
+        }
+        output.push_str(&format!("**Total Cards**: {}\n\n", analysis.total_cards));
+
+        // Card types
+        output.push_str("## Card Types\n\n");
+        output.push_str("| Type | Count | % of Deck |\n");
+        output.push_str("|------|-------|-----------|\n");
+        for type_count in analysis.type_counts.iter().filter(|t| t.count > 0) {
+            output.push_str(&format!(
+                "| {} | {} | {:.1}% |\n",
+                type_count.card_type.name(),
+                type_count.count,
+                type_count.percentage * 100.0
+            ));
+        }
+        output.push('\n');
+
+        // Template comparison
+        if !analysis.comparisons.is_empty() {
+            output.push_str(&format!("## {} Template\n\n", analysis.template_format));
+            output.push_str("| Category | Count | Target | Status |\n");
+            output.push_str("|----------|-------|--------|--------|\n");
+            for comparison in &analysis.comparisons {
+                let status = match comparison.status {
+                    TargetStatus::Below => "⚠️ Below",
+                    TargetStatus::Above => "⚠️ Above",
+                    TargetStatus::Within => "OK",
+                };
+                output.push_str(&format!(
+                    "| {} | {} | {}-{} | {} |\n",
+                    comparison.label, comparison.count, comparison.min, comparison.max, status
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Roles with card lists
+        output.push_str("## Functional Roles\n\n");
+        for role_count in &analysis.role_counts {
+            output.push_str(&format!(
+                "### {} ({})\n\n",
+                role_count.role.name(),
+                role_count.count
+            ));
+            if role_count.cards.is_empty() {
+                output.push_str("*None detected.*\n\n");
+                continue;
+            }
+            for card in &role_count.cards {
+                output.push_str(&format!("- {card}\n"));
+            }
+            output.push('\n');
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry composition analyzer*\n");
+
+        output
+    }
+}
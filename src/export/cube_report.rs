@@ -0,0 +1,199 @@
+use crate::cube::{ColorGroup, CubeAnalysis, CubeDiff, MAX_CURVE_CMC};
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+/// Markdown exporter for cube analyses and cube diffs
+pub struct CubeReportExporter;
+
+impl CubeReportExporter {
+    /// Export a cube analysis to a markdown file
+    pub fn export(analysis: &CubeAnalysis, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(analysis);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Export a cube diff to a markdown file
+    pub fn export_diff(diff: &CubeDiff, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate_diff(diff);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(analysis: &CubeAnalysis) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Cube Analysis\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        if let Some(name) = &analysis.cube_name {
+            output.push_str(&format!("**Cube**: {name}\n"));
+        }
+        output.push_str(&format!(
+            "**Cards**: {} ({} unique)\n\n",
+            analysis.total_cards, analysis.unique_cards
+        ));
+
+        // Color balance and curves
+        output.push_str("## Color Balance\n\n");
+        let cmc_labels: Vec<String> = (0..=MAX_CURVE_CMC)
+            .map(|cmc| {
+                if cmc == MAX_CURVE_CMC {
+                    format!("{cmc}+")
+                } else {
+                    cmc.to_string()
+                }
+            })
+            .collect();
+        output.push_str(&format!(
+            "| Group | Cards | Share | Creatures | Avg CMC | {} |\n",
+            cmc_labels.join(" | ")
+        ));
+        output.push_str(&format!(
+            "|-------|-------|-------|-----------|---------|{}\n",
+            "---|".repeat(cmc_labels.len())
+        ));
+        for stats in &analysis.colors {
+            let (average, curve) = if stats.group == ColorGroup::Land {
+                ("-".to_string(), vec!["-".to_string(); cmc_labels.len()])
+            } else {
+                (
+                    format!("{:.2}", stats.average_cmc),
+                    stats.curve.iter().map(|c| c.to_string()).collect(),
+                )
+            };
+            output.push_str(&format!(
+                "| {} | {} | {:.1}% | {} | {} | {} |\n",
+                stats.group.name(),
+                stats.count,
+                stats.share * 100.0,
+                stats.creatures,
+                average,
+                curve.join(" | ")
+            ));
+        }
+        output.push('\n');
+
+        // Archetype support
+        output.push_str("## Archetype Support\n\n");
+        output.push_str("| Pair | Colors | Playables | Gold | Themes |\n");
+        output.push_str("|------|--------|-----------|------|--------|\n");
+        for archetype in &analysis.archetypes {
+            let colors: String = archetype.colors.iter().map(|c| c.symbol()).collect();
+            let themes: Vec<String> = archetype
+                .themes
+                .iter()
+                .map(|t| format!("{} ({})", t.theme.display_name(), t.cards))
+                .collect();
+            output.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                archetype.name,
+                colors,
+                archetype.playables,
+                archetype.gold_cards,
+                if themes.is_empty() {
+                    "-".to_string()
+                } else {
+                    themes.join(", ")
+                }
+            ));
+        }
+        output.push('\n');
+
+        // Checks
+        output.push_str("## Checks\n\n");
+        if analysis.duplicates.is_empty() {
+            output.push_str("No duplicates.\n\n");
+        } else {
+            output.push_str("**Duplicates**:\n\n");
+            for duplicate in &analysis.duplicates {
+                output.push_str(&format!(
+                    "- {} ({} copies)\n",
+                    duplicate.name, duplicate.copies
+                ));
+            }
+            output.push('\n');
+        }
+        if let Some(legality) = &analysis.legality {
+            if legality.is_legal() {
+                output.push_str(&format!("All cards are legal in {}.\n\n", legality.format));
+            } else {
+                output.push_str(&format!("**Not legal in {}**:\n\n", legality.format));
+                for issue in &legality.issues {
+                    output.push_str(&format!("- {}: {}\n", issue.card, issue.reason));
+                }
+                output.push('\n');
+            }
+        }
+        if !analysis.unknown_cards.is_empty() {
+            output.push_str(&format!(
+                "**No card data**: {}\n\n",
+                analysis.unknown_cards.join(", ")
+            ));
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry cube analyzer*\n");
+
+        output
+    }
+
+    /// Generate markdown diff report as a string
+    pub fn generate_diff(diff: &CubeDiff) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Cube Diff\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+        output.push_str(&format!(
+            "**Size**: {} → {} cards\n\n",
+            diff.old_total, diff.new_total
+        ));
+
+        for (title, changes) in [("Added", &diff.added), ("Removed", &diff.removed)] {
+            output.push_str(&format!("## {title} ({})\n\n", changes.len()));
+            if changes.is_empty() {
+                output.push_str("None.\n\n");
+                continue;
+            }
+            for change in changes {
+                output.push_str(&format!("- {}x {}\n", change.quantity, change.name));
+            }
+            output.push('\n');
+        }
+
+        if !diff.colors.is_empty() {
+            output.push_str("## Color Balance Changes\n\n");
+            output.push_str("| Group | Before | After | Change |\n");
+            output.push_str("|-------|--------|-------|--------|\n");
+            for change in &diff.colors {
+                output.push_str(&format!(
+                    "| {} | {} | {} | {:+} |\n",
+                    change.group.name(),
+                    change.before,
+                    change.after,
+                    change.after as i64 - change.before as i64
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry cube analyzer*\n");
+
+        output
+    }
+}
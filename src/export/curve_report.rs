@@ -1,5 +1,5 @@
-use crate::curve::{CurveAnalysis, LandCountSource};
-use crate::deck::Color;
+use crate::curve::{BucketLoad, CurveAnalysis, LandCountSource};
+use crate::deck::{color_label, Color};
 use chrono::Local;
 use std::fs;
 use std::io::Write;
@@ -36,6 +36,18 @@ impl CurveReportExporter {
         }
         output.push_str(&format!("**Total Cards**: {}\n", analysis.total_cards));
         output.push_str(&format!("**Unique Cards**: {}\n\n", analysis.unique_cards));
+        output.push_str(&Self::sections(analysis));
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry mana curve analyzer*\n");
+
+        output
+    }
+
+    /// Report body without the title and deck info, for combining with other reports
+    pub fn sections(analysis: &CurveAnalysis) -> String {
+        let mut output = String::new();
 
         // Statistics
         output.push_str("## Statistics\n\n");
@@ -48,6 +60,12 @@ impl CurveReportExporter {
             analysis.stats.median_cmc
         ));
         output.push_str(&format!("- **Mode CMC**: {}\n", analysis.stats.mode_cmc));
+        if analysis.effective_costs.iter().any(|c| c.is_reduced()) {
+            output.push_str(&format!(
+                "- **Effective Avg CMC**: {:.2}\n",
+                analysis.stats.effective_average_cmc
+            ));
+        }
         output.push_str(&format!(
             "- **Non-land Cards**: {}\n",
             analysis.stats.total_nonland_cards
@@ -109,6 +127,58 @@ impl CurveReportExporter {
             output.push('\n');
         }
 
+        // Ideal curve comparison
+        if let Some(fit) = &analysis.curve_fit {
+            output.push_str(&format!("## Ideal Curve ({})\n\n", fit.archetype));
+            output.push_str(&format!("**Fit Score**: {:.0}/100\n\n", fit.score));
+            output.push_str("| CMC | Cards | Actual | Target | Deviation | Status |\n");
+            output.push_str("|-----|-------|--------|--------|-----------|--------|\n");
+
+            for deviation in &fit.deviations {
+                let status = match deviation.load {
+                    BucketLoad::Over => "⚠️ Over-loaded",
+                    BucketLoad::Under => "⚠️ Under-loaded",
+                    BucketLoad::OnTarget => "On target",
+                };
+                output.push_str(&format!(
+                    "| {} | {} | {:.1}% | {:.1}% | {:+.1}% | {} |\n",
+                    deviation.label(),
+                    deviation.actual_count,
+                    deviation.actual_share * 100.0,
+                    deviation.target_share * 100.0,
+                    deviation.deviation * 100.0,
+                    status
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Effective cost adjustments
+        let reduced: Vec<_> = analysis
+            .effective_costs
+            .iter()
+            .filter(|c| c.is_reduced())
+            .collect();
+        if !reduced.is_empty() {
+            output.push_str("## Cost Reductions\n\n");
+            if analysis.bucketed_by_effective {
+                output.push_str("*Curve buckets use effective mana value.*\n\n");
+            }
+            output.push_str("| Card | CMC | Effective | Cast Turn | Reason |\n");
+            output.push_str("|------|-----|-----------|-----------|--------|\n");
+            for cost in reduced {
+                output.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    cost.card_name,
+                    cost.cmc,
+                    cost.effective_cmc,
+                    cost.cast_turn,
+                    cost.reason.as_deref().unwrap_or("-")
+                ));
+            }
+            output.push('\n');
+        }
+
         // Card lists by CMC
         if !analysis.buckets.is_empty() {
             output.push_str("## Cards by CMC\n\n");
@@ -153,6 +223,11 @@ impl CurveReportExporter {
                 ));
             }
 
+            let colors: Vec<Color> = mana_base.color_percentages.keys().copied().collect();
+            if !colors.is_empty() {
+                output.push_str(&format!("**Colors**: {}\n\n", color_label(&colors)));
+            }
+
             // Dual lands table (if any detected)
             if !mana_base.duals.is_empty() {
                 let total_duals: u32 = mana_base.duals.iter().map(|d| d.count).sum();
@@ -216,10 +291,6 @@ impl CurveReportExporter {
             }
         }
 
-        // Footer
-        output.push_str("---\n\n");
-        output.push_str("*Generated by scry mana curve analyzer*\n");
-
         output
     }
 }
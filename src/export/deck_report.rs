@@ -0,0 +1,110 @@
+use crate::report::DeckReport;
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+use super::curve_report::CurveReportExporter;
+use super::synergy_report::SynergyReportExporter;
+
+/// Markdown exporter for the combined `scry analyze` report
+pub struct DeckReportExporter;
+
+impl DeckReportExporter {
+    /// Export the combined report to a markdown file
+    pub fn export(report: &DeckReport, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(report);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(report: &DeckReport) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Deck Analysis\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        // Deck info
+        if let Some(name) = &report.deck_name {
+            output.push_str(&format!("**Deck**: {name}\n"));
+        }
+        if let Some(format) = &report.deck_format {
+            output.push_str(&format!("**Format**: {format}\n"));
+        }
+        output.push_str(&format!("**Total Cards**: {}\n", report.total_cards));
+        output.push_str(&format!("**Unique Cards**: {}\n", report.unique_cards));
+        if let Some(price) = &report.price {
+            output.push_str(&format!("**Price**: ${:.2}\n", price.total_usd));
+        }
+        output.push('\n');
+
+        // Legality
+        if let Some(legality) = &report.legality {
+            output.push_str("## Legality\n\n");
+            if legality.is_legal() {
+                output.push_str(&format!("Legal in {}.\n\n", legality.format));
+            } else {
+                output.push_str(&format!("**Not legal in {}**\n\n", legality.format));
+                output.push_str("| Card | Issue |\n");
+                output.push_str("|------|-------|\n");
+                for issue in &legality.issues {
+                    output.push_str(&format!("| {} | {} |\n", issue.card, issue.reason));
+                }
+                output.push('\n');
+            }
+        }
+
+        // Mana curve and mana base
+        if let Some(curve) = &report.curve {
+            output.push_str("# Mana Curve\n\n");
+            output.push_str(&CurveReportExporter::sections(curve));
+        }
+
+        // Synergy
+        if let Some(matrix) = &report.synergy {
+            output.push_str("# Synergy\n\n");
+            output.push_str(&SynergyReportExporter::sections(matrix));
+        }
+
+        // Price
+        if let Some(price) = &report.price {
+            output.push_str("# Price\n\n");
+            output.push_str(&format!("**Total**: ${:.2}\n\n", price.total_usd));
+            if !price.cards.is_empty() {
+                output.push_str("| Card | Qty | Each | Total |\n");
+                output.push_str("|------|-----|------|-------|\n");
+                for card in price.cards.iter().take(15) {
+                    output.push_str(&format!(
+                        "| {} | {} | ${:.2} | ${:.2} |\n",
+                        card.card,
+                        card.quantity,
+                        card.unit_usd,
+                        card.total_usd()
+                    ));
+                }
+                output.push('\n');
+            }
+            if !price.unpriced.is_empty() {
+                output.push_str(&format!("No price for: {}\n\n", price.unpriced.join(", ")));
+            }
+        }
+
+        // LLM insights
+        if let Some(insights) = &report.llm_insights {
+            output.push_str("# LLM Insights\n\n");
+            output.push_str(insights.trim());
+            output.push_str("\n\n");
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry deck analyzer*\n");
+
+        output
+    }
+}
@@ -0,0 +1,871 @@
+use crate::api::images::image_url;
+use crate::curve::CurveAnalysis;
+use crate::deck::{color_label, Color};
+use crate::input::DeckList;
+use crate::report::{DeckReport, LegalityReport, PriceReport};
+use crate::synergy::SynergyMatrix;
+use chrono::Local;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+
+use super::synergy_graph::SynergyGraphExporter;
+
+/// Order and fill color of each color in the pip chart
+const PIP_COLORS: &[(Color, &str)] = &[
+    (Color::White, "#f3e6b3"),
+    (Color::Blue, "#2b6cb0"),
+    (Color::Black, "#2d2a26"),
+    (Color::Red, "#c53030"),
+    (Color::Green, "#2f855a"),
+    (Color::Colorless, "#a0aec0"),
+];
+
+const HISTOGRAM_WIDTH: f64 = 560.0;
+const HISTOGRAM_HEIGHT: f64 = 200.0;
+const PIE_RADIUS: f64 = 90.0;
+
+/// Standalone HTML report combining curve, mana base and synergy analysis.
+///
+/// Styles, scripts and charts are inlined so the file can be shared on its
+/// own. Card images come from `images`, a map of card names to `data:` URIs
+/// (see `fetch_image_data_uris`); cards missing from it link their image
+/// from Scryfall instead.
+pub struct HtmlReportExporter;
+
+impl HtmlReportExporter {
+    /// Export the report to an HTML file
+    pub fn export(
+        deck: &DeckList,
+        curve: &CurveAnalysis,
+        matrix: &SynergyMatrix,
+        images: &HashMap<String, String>,
+        path: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, Self::generate(deck, curve, matrix, images))?;
+        Ok(())
+    }
+
+    /// Export a combined `scry analyze` report to an HTML file
+    pub fn export_report(
+        deck: &DeckList,
+        report: &DeckReport,
+        images: &HashMap<String, String>,
+        path: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, Self::generate_report(deck, report, images))?;
+        Ok(())
+    }
+
+    /// Generate the report as a string
+    pub fn generate(
+        deck: &DeckList,
+        curve: &CurveAnalysis,
+        matrix: &SynergyMatrix,
+        images: &HashMap<String, String>,
+    ) -> String {
+        Self::render(deck, Some(curve), Some(matrix), None, None, images)
+    }
+
+    /// Generate a combined report, leaving out skipped sections
+    pub fn generate_report(
+        deck: &DeckList,
+        report: &DeckReport,
+        images: &HashMap<String, String>,
+    ) -> String {
+        Self::render(
+            deck,
+            report.curve.as_ref(),
+            report.synergy.as_ref(),
+            report.legality.as_ref(),
+            report.price.as_ref(),
+            images,
+        )
+    }
+
+    fn render(
+        deck: &DeckList,
+        curve: Option<&CurveAnalysis>,
+        matrix: Option<&SynergyMatrix>,
+        legality: Option<&LegalityReport>,
+        price: Option<&PriceReport>,
+        images: &HashMap<String, String>,
+    ) -> String {
+        let title = deck.name.as_deref().unwrap_or("Deck Analysis");
+
+        let mut output = String::new();
+        output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        output.push_str("<meta charset=\"utf-8\">\n");
+        output.push_str(&format!(
+            "<title>{} — Scry Report</title>\n",
+            escape_html(title)
+        ));
+        output.push_str(&format!("<style>{STYLE}</style>\n"));
+        output.push_str("</head>\n<body>\n");
+
+        output.push_str(&format!("<h1>{}</h1>\n", escape_html(title)));
+        output.push_str(&format!(
+            "<p class=\"meta\">Generated by Scry on {}{}</p>\n",
+            Local::now().format("%Y-%m-%d %H:%M"),
+            deck.format
+                .as_deref()
+                .map(|f| format!(" · {}", escape_html(f)))
+                .unwrap_or_default()
+        ));
+
+        output.push_str(&Self::overview(deck, curve, matrix, price));
+        if let Some(curve) = curve {
+            output.push_str("<div class=\"row\">\n");
+            output.push_str(&Self::curve_section(curve));
+            output.push_str(&Self::mana_section(curve));
+            output.push_str("</div>\n");
+        }
+        if let Some(legality) = legality {
+            output.push_str(&Self::legality_section(legality));
+        }
+        if let Some(matrix) = matrix {
+            output.push_str(&Self::synergy_section(matrix));
+            output.push_str(&Self::graph_section(matrix));
+        }
+        if let Some(price) = price {
+            output.push_str(&Self::price_section(price));
+        }
+        output.push_str(&Self::card_table(deck, matrix, images));
+
+        output.push_str(&format!("<script>{SCRIPT}</script>\n"));
+        output.push_str("</body>\n</html>\n");
+        output
+    }
+
+    fn overview(
+        deck: &DeckList,
+        curve: Option<&CurveAnalysis>,
+        matrix: Option<&SynergyMatrix>,
+        price: Option<&PriceReport>,
+    ) -> String {
+        let mut stats = vec![("Cards", deck.total_cards().to_string())];
+        if let Some(curve) = curve {
+            stats.push(("Average MV", format!("{:.2}", curve.stats.average_cmc)));
+            stats.push(("Creatures", curve.stats.total_creatures.to_string()));
+            if let Some(lands) = curve.target_lands {
+                stats.push(("Target Lands", lands.to_string()));
+            }
+        }
+        if let Some(matrix) = matrix {
+            stats.push(("Synergy", format!("{:.0}/100", matrix.stats.synergy_score)));
+            if let Some(archetype) = matrix.likely_archetype() {
+                stats.push(("Strategy", archetype.theme.display_name()));
+            }
+        }
+        if let Some(price) = price {
+            stats.push(("Price", format!("${:.2}", price.total_usd)));
+        }
+
+        let mut output = String::from("<section class=\"overview\">\n");
+        for (label, value) in stats {
+            output.push_str(&format!(
+                "<div class=\"stat\"><span>{}</span><strong>{}</strong></div>\n",
+                label,
+                escape_html(&value)
+            ));
+        }
+        output.push_str("</section>\n");
+        output
+    }
+
+    /// Stacked SVG histogram of creatures and non-creatures per mana value
+    fn curve_section(curve: &CurveAnalysis) -> String {
+        let mut output = String::from("<section class=\"panel\">\n<h2>Mana Curve</h2>\n");
+        if curve.buckets.is_empty() || curve.max_count == 0 {
+            output.push_str("<p>No non-land cards.</p>\n</section>\n");
+            return output;
+        }
+
+        let slot = HISTOGRAM_WIDTH / curve.buckets.len() as f64;
+        let bar = slot * 0.7;
+        let scale = (HISTOGRAM_HEIGHT - 20.0) / curve.max_count as f64;
+
+        output.push_str(&format!(
+            "<svg viewBox=\"0 0 {} {}\" class=\"histogram\" role=\"img\">\n",
+            HISTOGRAM_WIDTH,
+            HISTOGRAM_HEIGHT + 24.0
+        ));
+        for (i, bucket) in curve.buckets.iter().enumerate() {
+            let x = i as f64 * slot + (slot - bar) / 2.0;
+            let creature_height = bucket.creature_count as f64 * scale;
+            let other_height = bucket.non_creature_count as f64 * scale;
+            let base = HISTOGRAM_HEIGHT;
+
+            output.push_str(&format!(
+                "<rect class=\"creature\" x=\"{x:.1}\" y=\"{:.1}\" width=\"{bar:.1}\" height=\"{creature_height:.1}\"><title>{} creatures</title></rect>\n",
+                base - creature_height,
+                bucket.creature_count
+            ));
+            output.push_str(&format!(
+                "<rect class=\"noncreature\" x=\"{x:.1}\" y=\"{:.1}\" width=\"{bar:.1}\" height=\"{other_height:.1}\"><title>{} non-creatures</title></rect>\n",
+                base - creature_height - other_height,
+                bucket.non_creature_count
+            ));
+            output.push_str(&format!(
+                "<text x=\"{:.1}\" y=\"{:.1}\" class=\"count\">{}</text>\n",
+                x + bar / 2.0,
+                base - creature_height - other_height - 4.0,
+                bucket.total_count
+            ));
+            output.push_str(&format!(
+                "<text x=\"{:.1}\" y=\"{:.1}\" class=\"axis\">{}</text>\n",
+                x + bar / 2.0,
+                base + 18.0,
+                bucket.cmc
+            ));
+        }
+        output.push_str("</svg>\n");
+        output.push_str(
+            "<p class=\"legend\"><span class=\"swatch creature\"></span>Creatures \
+             <span class=\"swatch noncreature\"></span>Non-creatures</p>\n",
+        );
+
+        if let Some(fit) = &curve.curve_fit {
+            output.push_str(&format!(
+                "<p>Fits the {} curve at {:.0}/100.</p>\n",
+                escape_html(&fit.archetype.to_string()),
+                fit.score
+            ));
+        }
+
+        output.push_str("</section>\n");
+        output
+    }
+
+    /// SVG pie chart of colored pips plus the recommended mana base
+    fn mana_section(curve: &CurveAnalysis) -> String {
+        let pips = &curve.pip_breakdown;
+        let slices: Vec<(Color, &str, f64)> = PIP_COLORS
+            .iter()
+            .map(|(color, fill)| {
+                let count = match color {
+                    Color::White => pips.white,
+                    Color::Blue => pips.blue,
+                    Color::Black => pips.black,
+                    Color::Red => pips.red,
+                    Color::Green => pips.green,
+                    Color::Colorless => pips.colorless,
+                };
+                (*color, *fill, count)
+            })
+            .filter(|(_, _, count)| *count > 0.0)
+            .collect();
+
+        let mut output = String::from("<section class=\"panel\">\n<h2>Mana</h2>\n");
+        if slices.is_empty() {
+            output.push_str("<p>No colored pips.</p>\n");
+        } else {
+            output.push_str(&pie_chart(&slices, pips.total()));
+            output.push_str("<ul class=\"legend\">\n");
+            for (color, fill, count) in &slices {
+                output.push_str(&format!(
+                    "<li><span class=\"swatch\" style=\"background:{}\"></span>{} — {:.0} pips ({:.0}%)</li>\n",
+                    fill,
+                    color.name(),
+                    count,
+                    count / pips.total() * 100.0
+                ));
+            }
+            output.push_str("</ul>\n");
+        }
+
+        if let Some(mana_base) = &curve.mana_base {
+            output.push_str("<h3>Recommended Lands</h3>\n");
+            let colors: Vec<Color> = mana_base.color_percentages.keys().copied().collect();
+            if !colors.is_empty() {
+                output.push_str(&format!(
+                    "<p>Colors: {}</p>\n",
+                    escape_html(&color_label(&colors))
+                ));
+            }
+            output.push_str("<table>\n");
+            output.push_str("<tr><th>Land</th><th>Count</th></tr>\n");
+            for (color, _) in PIP_COLORS {
+                if let Some(count) = mana_base.basics.get(color).filter(|c| **c > 0) {
+                    output.push_str(&format!(
+                        "<tr><td>{}</td><td>{}</td></tr>\n",
+                        color.basic_land(),
+                        count
+                    ));
+                }
+            }
+            for dual in &mana_base.duals {
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(&dual.name),
+                    dual.count
+                ));
+            }
+            output.push_str("</table>\n");
+
+            if !mana_base.recommendations.is_empty() {
+                output.push_str("<ul>\n");
+                for recommendation in &mana_base.recommendations {
+                    output.push_str(&format!("<li>{}</li>\n", escape_html(recommendation)));
+                }
+                output.push_str("</ul>\n");
+            }
+        }
+
+        output.push_str("</section>\n");
+        output
+    }
+
+    fn synergy_section(matrix: &SynergyMatrix) -> String {
+        let mut output = String::from("<section>\n<h2>Synergy</h2>\n");
+
+        if let Some(archetype) = matrix.likely_archetype() {
+            output.push_str(&format!(
+                "<p><strong>{}</strong> ({:.0}% fit)</p>\n<ul>\n",
+                escape_html(&archetype.theme.display_name()),
+                archetype.score * 100.0
+            ));
+            for evidence in &archetype.evidence {
+                output.push_str(&format!("<li>{}</li>\n", escape_html(evidence)));
+            }
+            output.push_str("</ul>\n");
+        }
+
+        if !matrix.detected_themes.is_empty() {
+            output.push_str("<table>\n<tr><th>Theme</th><th>Cards</th><th>% of Deck</th><th>Enablers</th><th>Payoffs</th></tr>\n");
+            for theme in &matrix.detected_themes {
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{:.1}%</td><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(&theme.theme.display_name()),
+                    theme.card_count,
+                    theme.percentage * 100.0,
+                    escape_html(&theme.enablers.join(", ")),
+                    escape_html(&theme.payoffs.join(", "))
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+
+        if !matrix.combos.is_empty() {
+            output.push_str("<h3>Combos</h3>\n<ul>\n");
+            for combo in &matrix.combos {
+                let status = if combo.is_complete() {
+                    "Complete".to_string()
+                } else {
+                    format!("Missing {}", combo.missing.join(", "))
+                };
+                output.push_str(&format!(
+                    "<li><strong>{}</strong> — {} <em>({})</em></li>\n",
+                    escape_html(&combo.combo.label()),
+                    escape_html(&combo.combo.results_summary()),
+                    escape_html(&status)
+                ));
+            }
+            output.push_str("</ul>\n");
+        }
+
+        if matrix.clusters.len() > 1 {
+            output.push_str("<h3>Synergy Packages</h3>\n<table>\n<tr><th>Themes</th><th>Cards</th><th>Bridges</th></tr>\n");
+            for cluster in &matrix.clusters {
+                let themes: Vec<_> = cluster.themes.iter().map(|t| t.display_name()).collect();
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    if themes.is_empty() {
+                        "Mixed".to_string()
+                    } else {
+                        escape_html(&themes.join(", "))
+                    },
+                    escape_html(&cluster.cards.join(", ")),
+                    escape_html(&cluster.bridges.join(", "))
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+
+        let interactions = matrix.key_interactions(10);
+        if !interactions.is_empty() {
+            output.push_str("<h3>Key Interactions</h3>\n<ul>\n");
+            for edge in interactions {
+                output.push_str(&format!("<li>{}</li>\n", escape_html(&edge.reason)));
+            }
+            output.push_str("</ul>\n");
+        }
+
+        if !matrix.observations.is_empty() {
+            output.push_str("<h3>Observations</h3>\n<ul>\n");
+            for observation in &matrix.observations {
+                output.push_str(&format!("<li>{}</li>\n", escape_html(observation)));
+            }
+            output.push_str("</ul>\n");
+        }
+
+        output.push_str("</section>\n");
+        output
+    }
+
+    fn legality_section(legality: &LegalityReport) -> String {
+        let mut output = format!(
+            "<section>\n<h2>Legality</h2>\n<p>{}</p>\n",
+            if legality.is_legal() {
+                format!("Legal in {}.", escape_html(&legality.format))
+            } else {
+                format!(
+                    "<strong>Not legal in {}</strong>: {} issue(s).",
+                    escape_html(&legality.format),
+                    legality.issues.len()
+                )
+            }
+        );
+        if !legality.is_legal() {
+            output.push_str("<table>\n<tr><th>Card</th><th>Issue</th></tr>\n");
+            for issue in &legality.issues {
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(&issue.card),
+                    escape_html(&issue.reason)
+                ));
+            }
+            output.push_str("</table>\n");
+        }
+        output.push_str("</section>\n");
+        output
+    }
+
+    fn price_section(price: &PriceReport) -> String {
+        let mut output = format!(
+            "<section>\n<h2>Price</h2>\n<p>Total: <strong>${:.2}</strong></p>\n",
+            price.total_usd
+        );
+        if !price.cards.is_empty() {
+            output.push_str(
+                "<table class=\"sortable\">\n<thead><tr><th>Card</th><th>Qty</th><th>Each</th><th>Total</th></tr></thead>\n<tbody>\n",
+            );
+            for card in price.cards.iter().take(15) {
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td data-sort=\"{:.2}\">${:.2}</td><td data-sort=\"{:.2}\">${:.2}</td></tr>\n",
+                    escape_html(&card.card),
+                    card.quantity,
+                    card.unit_usd,
+                    card.unit_usd,
+                    card.total_usd(),
+                    card.total_usd()
+                ));
+            }
+            output.push_str("</tbody>\n</table>\n");
+        }
+        if !price.unpriced.is_empty() {
+            output.push_str(&format!(
+                "<p class=\"legend\">No price for: {}</p>\n",
+                escape_html(&price.unpriced.join(", "))
+            ));
+        }
+        output.push_str("</section>\n");
+        output
+    }
+
+    /// Container and data for the force-directed graph drawn by `SCRIPT`
+    fn graph_section(matrix: &SynergyMatrix) -> String {
+        let nodes = SynergyGraphExporter::nodes(matrix);
+        if nodes.is_empty() {
+            return String::new();
+        }
+
+        let data = json!({
+            "nodes": nodes.values().map(|n| json!({
+                "id": n.name,
+                "color": n.color,
+                "degree": n.degree,
+                "theme": n.theme.map(|t| t.display_name()),
+            })).collect::<Vec<_>>(),
+            "edges": matrix.edges.iter().map(|e| json!({
+                "source": e.card_a,
+                "target": e.card_b,
+                "relation": SynergyGraphExporter::relation_name(&e.relation),
+                "strength": e.strength,
+                "reason": e.reason,
+            })).collect::<Vec<_>>(),
+        });
+
+        format!(
+            "<section>\n<h2>Synergy Graph</h2>\n\
+             <p class=\"legend\">Drag cards to rearrange. Solid: enables · Thick: payoff · \
+             Dashed: supports · Red: combo</p>\n\
+             <svg id=\"graph\" viewBox=\"0 0 900 600\"></svg>\n\
+             <script type=\"application/json\" id=\"graph-data\">{}</script>\n</section>\n",
+            data.to_string().replace("</", "<\\/")
+        )
+    }
+
+    /// Sortable table of every mainboard card with its image and synergy profile
+    fn card_table(
+        deck: &DeckList,
+        matrix: Option<&SynergyMatrix>,
+        images: &HashMap<String, String>,
+    ) -> String {
+        let mut output = String::from(
+            "<section>\n<h2>Cards</h2>\n<table class=\"sortable\">\n<thead><tr>\
+             <th>Card</th><th>Qty</th><th>Type</th><th>Cost</th><th>MV</th>\
+             <th>Themes</th><th>Role</th><th>Synergy</th></tr></thead>\n<tbody>\n",
+        );
+
+        for entry in deck.mainboard() {
+            let Some(card) = &entry.card else {
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td></td><td></td><td data-sort=\"0\"></td><td></td><td></td><td data-sort=\"0\"></td></tr>\n",
+                    escape_html(&entry.card_name),
+                    entry.quantity
+                ));
+                continue;
+            };
+
+            let image = images
+                .get(&card.name)
+                .map(String::as_str)
+                .or_else(|| image_url(card))
+                .map(|src| {
+                    format!(
+                        "<img src=\"{}\" alt=\"\" loading=\"lazy\">",
+                        escape_html(src)
+                    )
+                })
+                .unwrap_or_default();
+
+            let profile = matrix.and_then(|m| m.card_profiles.get(&card.name));
+            let themes = profile
+                .map(|p| {
+                    p.themes
+                        .iter()
+                        .map(|t| t.display_name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            let role = profile
+                .and_then(|p| p.role.as_ref())
+                .map(|r| format!("{r:?}"))
+                .unwrap_or_default();
+            let score = profile.map(|p| p.synergy_score).unwrap_or(0.0);
+
+            output.push_str(&format!(
+                "<tr><td class=\"card\">{}<a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td>\
+                 <td data-sort=\"{}\">{}</td><td>{}</td><td>{}</td><td data-sort=\"{:.3}\">{:.2}</td></tr>\n",
+                image,
+                escape_html(&card.scryfall_uri),
+                escape_html(&card.name),
+                entry.quantity,
+                escape_html(&card.type_line),
+                escape_html(card.mana_cost.as_deref().unwrap_or("")),
+                card.cmc,
+                card.cmc,
+                escape_html(&themes),
+                role,
+                score,
+                score
+            ));
+        }
+
+        output.push_str("</tbody>\n</table>\n</section>\n");
+        output
+    }
+}
+
+/// SVG pie chart with one slice per (color, fill, count)
+fn pie_chart(slices: &[(Color, &str, f64)], total: f64) -> String {
+    let size = PIE_RADIUS * 2.0 + 10.0;
+    let center = size / 2.0;
+    let mut output = format!("<svg viewBox=\"0 0 {size} {size}\" class=\"pie\" role=\"img\">\n");
+
+    if slices.len() == 1 {
+        output.push_str(&format!(
+            "<circle cx=\"{center}\" cy=\"{center}\" r=\"{PIE_RADIUS}\" fill=\"{}\"/>\n",
+            slices[0].1
+        ));
+    } else {
+        let point = |angle: f64| {
+            (
+                center + PIE_RADIUS * angle.sin(),
+                center - PIE_RADIUS * angle.cos(),
+            )
+        };
+        let mut angle = 0.0;
+        for (color, fill, count) in slices {
+            let sweep = count / total * std::f64::consts::TAU;
+            let (x1, y1) = point(angle);
+            let (x2, y2) = point(angle + sweep);
+            let large_arc = if sweep > std::f64::consts::PI { 1 } else { 0 };
+            output.push_str(&format!(
+                "<path d=\"M{center},{center} L{x1:.2},{y1:.2} A{PIE_RADIUS},{PIE_RADIUS} 0 {large_arc} 1 {x2:.2},{y2:.2} Z\" fill=\"{fill}\"><title>{}: {:.0}</title></path>\n",
+                color.name(),
+                count
+            ));
+            angle += sweep;
+        }
+    }
+
+    output.push_str("</svg>\n");
+    output
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; margin: 2rem auto; max-width: 1100px; color: #1a202c; background: #f7fafc; padding: 0 1rem; }
+h1 { margin-bottom: 0; }
+h2 { border-bottom: 2px solid #e2e8f0; padding-bottom: .3rem; }
+.meta, .legend { color: #718096; font-size: .9rem; }
+section { background: #fff; border-radius: 8px; padding: 1rem 1.5rem; margin: 1rem 0; box-shadow: 0 1px 3px rgba(0,0,0,.08); }
+.overview { display: flex; flex-wrap: wrap; gap: 1rem; }
+.stat { flex: 1; min-width: 120px; }
+.stat span { display: block; color: #718096; font-size: .8rem; text-transform: uppercase; }
+.stat strong { font-size: 1.4rem; }
+.row { display: flex; flex-wrap: wrap; gap: 1rem; }
+.row .panel { flex: 1; min-width: 320px; margin: 0; }
+.histogram rect.creature, .swatch.creature { fill: #3182ce; background: #3182ce; }
+.histogram rect.noncreature, .swatch.noncreature { fill: #90cdf4; background: #90cdf4; }
+.histogram text { font-size: 12px; text-anchor: middle; fill: #4a5568; }
+.pie { width: 200px; height: 200px; float: left; margin-right: 1rem; }
+ul.legend { list-style: none; padding: 0; }
+.swatch { display: inline-block; width: 12px; height: 12px; border-radius: 2px; margin: 0 .4rem 0 .8rem; vertical-align: middle; }
+ul.legend .swatch { margin-left: 0; border: 1px solid #cbd5e0; }
+table { border-collapse: collapse; width: 100%; margin: .5rem 0; clear: both; }
+th, td { text-align: left; padding: .35rem .5rem; border-bottom: 1px solid #edf2f7; font-size: .9rem; vertical-align: middle; }
+table.sortable th { cursor: pointer; user-select: none; }
+table.sortable th.asc::after { content: " ▲"; }
+table.sortable th.desc::after { content: " ▼"; }
+td.card { position: relative; }
+td.card img { width: 34px; border-radius: 3px; margin-right: .5rem; vertical-align: middle; }
+td.card img:hover { position: absolute; width: 240px; z-index: 10; left: 40px; top: -20px; box-shadow: 0 4px 12px rgba(0,0,0,.4); }
+a { color: #2b6cb0; text-decoration: none; }
+#graph { width: 100%; height: 600px; background: #fdfdfd; border: 1px solid #e2e8f0; border-radius: 6px; }
+#graph line { stroke: #a0aec0; stroke-opacity: .7; }
+#graph line.payoff_for { stroke-width: 3; }
+#graph line.supports { stroke-dasharray: 4 3; }
+#graph line.combos { stroke: #c0392b; stroke-width: 3; }
+#graph circle { stroke: #fff; stroke-width: 1.5; cursor: grab; }
+#graph text { font-size: 10px; fill: #2d3748; pointer-events: none; }
+"#;
+
+const SCRIPT: &str = r#"
+(function () {
+  // Sortable tables: click a header to sort, click again to reverse
+  document.querySelectorAll("table.sortable").forEach(function (table) {
+    var headers = table.querySelectorAll("th");
+    headers.forEach(function (th, index) {
+      th.addEventListener("click", function () {
+        var ascending = !th.classList.contains("asc");
+        headers.forEach(function (h) { h.classList.remove("asc", "desc"); });
+        th.classList.add(ascending ? "asc" : "desc");
+        var body = table.tBodies[0];
+        var rows = Array.prototype.slice.call(body.rows);
+        var key = function (row) {
+          var cell = row.cells[index];
+          var value = cell.getAttribute("data-sort");
+          if (value === null) { value = cell.textContent.trim(); }
+          var number = parseFloat(value);
+          return isNaN(number) || !/^-?[\d.]+$/.test(value) ? value.toLowerCase() : number;
+        };
+        rows.sort(function (a, b) {
+          var x = key(a), y = key(b);
+          var order = x < y ? -1 : x > y ? 1 : 0;
+          return ascending ? order : -order;
+        });
+        rows.forEach(function (row) { body.appendChild(row); });
+      });
+    });
+  });
+
+  // Force-directed synergy graph
+  var dataNode = document.getElementById("graph-data");
+  var svg = document.getElementById("graph");
+  if (!dataNode || !svg) { return; }
+  var data = JSON.parse(dataNode.textContent);
+  var ns = "http://www.w3.org/2000/svg";
+  var width = 900, height = 600;
+  var byId = {};
+  data.nodes.forEach(function (n, i) {
+    var angle = (i / data.nodes.length) * 2 * Math.PI;
+    n.x = width / 2 + 200 * Math.cos(angle);
+    n.y = height / 2 + 200 * Math.sin(angle);
+    n.vx = 0; n.vy = 0;
+    n.r = 5 + Math.min(n.degree, 12) * 1.2;
+    byId[n.id] = n;
+  });
+  var edges = data.edges.filter(function (e) { return byId[e.source] && byId[e.target]; });
+
+  function step() {
+    var nodes = data.nodes;
+    for (var i = 0; i < nodes.length; i++) {
+      for (var j = i + 1; j < nodes.length; j++) {
+        var a = nodes[i], b = nodes[j];
+        var dx = a.x - b.x, dy = a.y - b.y;
+        var dist2 = Math.max(dx * dx + dy * dy, 25);
+        var force = 1800 / dist2;
+        var dist = Math.sqrt(dist2);
+        a.vx += force * dx / dist; a.vy += force * dy / dist;
+        b.vx -= force * dx / dist; b.vy -= force * dy / dist;
+      }
+    }
+    edges.forEach(function (e) {
+      var a = byId[e.source], b = byId[e.target];
+      var dx = b.x - a.x, dy = b.y - a.y;
+      var dist = Math.max(Math.sqrt(dx * dx + dy * dy), 1);
+      var force = (dist - 90) * 0.02 * (0.5 + e.strength);
+      a.vx += force * dx / dist; a.vy += force * dy / dist;
+      b.vx -= force * dx / dist; b.vy -= force * dy / dist;
+    });
+    nodes.forEach(function (n) {
+      n.vx += (width / 2 - n.x) * 0.004;
+      n.vy += (height / 2 - n.y) * 0.004;
+      if (!n.fixed) { n.x += n.vx; n.y += n.vy; }
+      n.vx *= 0.6; n.vy *= 0.6;
+      n.x = Math.max(n.r, Math.min(width - n.r, n.x));
+      n.y = Math.max(n.r, Math.min(height - n.r, n.y));
+    });
+  }
+
+  var lines = edges.map(function (e) {
+    var line = document.createElementNS(ns, "line");
+    line.setAttribute("class", e.relation);
+    var title = document.createElementNS(ns, "title");
+    title.textContent = e.reason;
+    line.appendChild(title);
+    svg.appendChild(line);
+    return line;
+  });
+  var groups = data.nodes.map(function (n) {
+    var g = document.createElementNS(ns, "g");
+    var circle = document.createElementNS(ns, "circle");
+    circle.setAttribute("r", n.r);
+    circle.setAttribute("fill", n.color);
+    var title = document.createElementNS(ns, "title");
+    title.textContent = n.id + (n.theme ? " (" + n.theme + ")" : "") + " — " + n.degree + " synergies";
+    circle.appendChild(title);
+    var label = document.createElementNS(ns, "text");
+    label.setAttribute("dx", n.r + 3);
+    label.setAttribute("dy", 3);
+    label.textContent = n.id;
+    g.appendChild(circle);
+    g.appendChild(label);
+    svg.appendChild(g);
+    circle.addEventListener("mousedown", function (event) {
+      event.preventDefault();
+      dragging = n;
+      n.fixed = true;
+    });
+    return g;
+  });
+
+  function render() {
+    edges.forEach(function (e, i) {
+      var a = byId[e.source], b = byId[e.target];
+      lines[i].setAttribute("x1", a.x); lines[i].setAttribute("y1", a.y);
+      lines[i].setAttribute("x2", b.x); lines[i].setAttribute("y2", b.y);
+    });
+    data.nodes.forEach(function (n, i) {
+      groups[i].setAttribute("transform", "translate(" + n.x + "," + n.y + ")");
+    });
+  }
+
+  var dragging = null;
+  svg.addEventListener("mousemove", function (event) {
+    if (!dragging) { return; }
+    var point = svg.createSVGPoint();
+    point.x = event.clientX; point.y = event.clientY;
+    var local = point.matrixTransform(svg.getScreenCTM().inverse());
+    dragging.x = local.x; dragging.y = local.y;
+    ticks = Math.max(ticks, 60);
+  });
+  window.addEventListener("mouseup", function () {
+    if (dragging) { dragging.fixed = false; dragging = null; }
+  });
+
+  var ticks = 300;
+  function frame() {
+    if (ticks > 0) { step(); render(); ticks--; }
+    window.requestAnimationFrame(frame);
+  }
+  frame();
+})();
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::{CmcBucket, CurveAnalysis};
+    use crate::input::DeckSection;
+    use crate::input::DeckSource;
+    use crate::synergy::{SynergyEdge, SynergyRelation, Theme};
+    use crate::testing::{card, entry};
+
+    #[test]
+    fn test_report_is_self_contained() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        deck.entries.push(entry(
+            1,
+            card("Goblin Bombardment")
+                .type_line("Enchantment")
+                .image("https://cards.scryfall.io/small/front/goblin.jpg")
+                .build(),
+            DeckSection::Mainboard,
+        ));
+        let images = HashMap::from([(
+            "Goblin Bombardment".to_string(),
+            "data:image/jpeg;base64,AAAA".to_string(),
+        )]);
+        let mut curve = CurveAnalysis::new();
+        let mut bucket = CmcBucket::new(2);
+        bucket.total_count = 3;
+        bucket.creature_count = 2;
+        bucket.non_creature_count = 1;
+        curve.buckets.push(bucket);
+        curve.max_count = 3;
+        curve.pip_breakdown.red = 4.0;
+        curve.pip_breakdown.green = 2.0;
+
+        let mut matrix = SynergyMatrix::new();
+        matrix.edges.push(SynergyEdge {
+            card_a: "Goblin Bombardment".to_string(),
+            card_b: "<script>".to_string(),
+            relation: SynergyRelation::Enables,
+            themes: vec![Theme::Sacrifice],
+            strength: 0.9,
+            reason: "Sacrifice outlet </script>".to_string(),
+        });
+
+        let html = HtmlReportExporter::generate(&deck, &curve, &matrix, &images);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<svg viewBox=\"0 0 560 224\" class=\"histogram\""));
+        assert_eq!(html.matches("<path d=").count(), 2);
+        assert!(html.contains("id=\"graph-data\""));
+        assert!(!html.contains("Sacrifice outlet </script>"));
+        assert!(html.contains("<img src=\"data:image/jpeg;base64,AAAA\""));
+        assert!(html.contains(">Goblin Bombardment</a>"));
+        assert!(!html.contains("<link") && !html.contains("<script src"));
+        assert!(!html.contains("src=\"http"));
+    }
+
+    #[test]
+    fn test_unfetched_images_link_to_scryfall() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        deck.entries.push(entry(
+            1,
+            card("Goblin Bombardment")
+                .image("https://cards.scryfall.io/small/front/goblin.jpg")
+                .build(),
+            DeckSection::Mainboard,
+        ));
+
+        let html = HtmlReportExporter::generate(
+            &deck,
+            &CurveAnalysis::new(),
+            &SynergyMatrix::new(),
+            &HashMap::new(),
+        );
+        assert!(html.contains("<img src=\"https://cards.scryfall.io/small/front/goblin.jpg\""));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::deck::color_label;
+use crate::lands::LandSuite;
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+/// Markdown exporter for optimized land suites
+pub struct LandSuiteReportExporter;
+
+impl LandSuiteReportExporter {
+    /// Export a land suite to a markdown file
+    pub fn export(suite: &LandSuite, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(suite);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(suite: &LandSuite) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Mana Base Optimizer\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        output.push_str(&format!("**Colors**: {}\n", color_label(&suite.colors)));
+        output.push_str(&format!("**Format**: {}\n", suite.format.name()));
+        if let Some(legality) = &suite.legality {
+            output.push_str(&format!("**Legal in**: {legality}\n"));
+        }
+        output.push_str(&format!("**Lands**: {}\n", suite.total_lands));
+        output.push_str(&format!("**Candidate lands**: {}\n", suite.candidates));
+        match suite.budget {
+            Some(budget) => output.push_str(&format!(
+                "**Cost**: ${:.2} of ${:.2} budget\n\n",
+                suite.cost_usd, budget
+            )),
+            None => output.push_str(&format!("**Cost**: ${:.2}\n\n", suite.cost_usd)),
+        }
+
+        // Nonbasic lands
+        let nonbasic_count: u32 = suite.nonbasics.iter().map(|l| l.copies).sum();
+        output.push_str(&format!("## Nonbasic Lands ({nonbasic_count})\n\n"));
+        if suite.nonbasics.is_empty() {
+            output.push_str("None\n\n");
+        } else {
+            output.push_str("| Count | Land | Colors | Kind | Enters | Owned | Price |\n");
+            output.push_str("|-------|------|--------|------|--------|-------|-------|\n");
+            for land in &suite.nonbasics {
+                let symbols: Vec<&str> = land.colors.iter().map(|c| c.symbol()).collect();
+                output.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |\n",
+                    land.copies,
+                    land.name,
+                    symbols.join("/"),
+                    land.kind.name(),
+                    land.entry.name(),
+                    land.owned,
+                    land.unit_usd
+                        .map(|usd| format!("${usd:.2}"))
+                        .unwrap_or_else(|| "-".to_string())
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Basic lands
+        let basic_count: u32 = suite.basics.iter().map(|b| b.count).sum();
+        output.push_str(&format!("## Basic Lands ({basic_count})\n\n"));
+        for basic in &suite.basics {
+            output.push_str(&format!(
+                "- {}x {}\n",
+                basic.count,
+                basic.color.basic_land()
+            ));
+        }
+        output.push('\n');
+
+        // Sources
+        output.push_str("## Colored Sources\n\n");
+        output.push_str("| Color | Basics | Nonbasics | Total | Target | Status |\n");
+        output.push_str("|-------|--------|-----------|-------|--------|--------|\n");
+        for sources in &suite.sources {
+            output.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                sources.color.name(),
+                sources.basics,
+                sources.nonbasics,
+                sources.total,
+                sources.target,
+                if sources.meets_target() {
+                    "On target"
+                } else {
+                    "⚠️ Below"
+                }
+            ));
+        }
+        output.push('\n');
+        if suite.tapped > 0 {
+            output.push_str(&format!("**Tapped lands**: {}\n\n", suite.tapped));
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry mana base optimizer*\n");
+
+        output
+    }
+}
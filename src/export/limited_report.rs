@@ -0,0 +1,173 @@
+use crate::composition::TargetStatus;
+use crate::limited::{grade, LimitedReport, RatedCard};
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+/// Markdown exporter for Limited deck evaluations and sealed pool builds
+pub struct LimitedReportExporter;
+
+impl LimitedReportExporter {
+    /// Export a Limited report to a markdown file
+    pub fn export(report: &LimitedReport, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(report);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(report: &LimitedReport) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Limited Analysis\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        // Deck info
+        if let Some(name) = &report.deck_name {
+            output.push_str(&format!("**Deck**: {name}\n"));
+        }
+        if let Some(set) = &report.set {
+            output.push_str(&format!("**Set**: {}\n", set.to_uppercase()));
+        }
+        output.push_str(&format!(
+            "**Ratings**: {}\n\n",
+            report.metric.as_deref().unwrap_or("none loaded")
+        ));
+
+        if let Some(evaluation) = &report.evaluation {
+            output.push_str(&format!("**Maindeck**: {} cards\n", evaluation.total_cards));
+            if let Some(average) = evaluation.average_rating {
+                output.push_str(&format!(
+                    "**Average rating**: {} ({:.0}th percentile)\n",
+                    grade(average),
+                    average * 100.0
+                ));
+            }
+            output.push('\n');
+
+            // Norms
+            output.push_str("## Limited Norms\n\n");
+            output.push_str("| Category | Count | Target | Status |\n");
+            output.push_str("|----------|-------|--------|--------|\n");
+            for comparison in &evaluation.comparisons {
+                let status = match comparison.status {
+                    TargetStatus::Below => "⚠️ Below",
+                    TargetStatus::Above => "⚠️ Above",
+                    TargetStatus::Within => "OK",
+                };
+                output.push_str(&format!(
+                    "| {} | {} | {}-{} | {} |\n",
+                    comparison.label, comparison.count, comparison.min, comparison.max, status
+                ));
+            }
+            output.push_str(&format!(
+                "\n**Curve fit**: {:.0}/100\n\n",
+                evaluation.curve_fit.score
+            ));
+
+            // Ratings
+            if !evaluation.best.is_empty() {
+                output.push_str("## Best Cards\n\n");
+                push_rated_table(&mut output, &evaluation.best);
+                output.push_str("## Weakest Cards\n\n");
+                push_rated_table(&mut output, &evaluation.weakest);
+            }
+
+            if !evaluation.upgrades.is_empty() {
+                output.push_str("## Sideboard Upgrades\n\n");
+                output.push_str("| Add | Cut | Gain |\n");
+                output.push_str("|-----|-----|------|\n");
+                for upgrade in &evaluation.upgrades {
+                    output.push_str(&format!(
+                        "| {} | {} | +{:.0} percentile |\n",
+                        upgrade.add,
+                        upgrade.cut,
+                        upgrade.gain * 100.0
+                    ));
+                }
+                output.push('\n');
+            }
+
+            if !evaluation.unrated.is_empty() && evaluation.rated_cards > 0 {
+                output.push_str(&format!(
+                    "**Unrated**: {}\n\n",
+                    evaluation.unrated.join(", ")
+                ));
+            }
+        }
+
+        // Pool builds
+        if !report.builds.is_empty() {
+            output.push_str("## Suggested Builds\n\n");
+            for (rank, build) in report.builds.iter().enumerate() {
+                let colors: String = build.colors.iter().map(|c| c.symbol()).collect();
+                let splash = build
+                    .splash
+                    .map(|c| format!(" splashing {}", c.symbol()))
+                    .unwrap_or_default();
+                output.push_str(&format!(
+                    "### {}. {} ({colors}){splash}\n\n",
+                    rank + 1,
+                    build.name
+                ));
+                output.push_str(&format!(
+                    "**Score**: {:.0}/100 | **Quality**: {:.0}% | **Curve fit**: {:.0}/100 | **Synergy density**: {:.0}%\n\n",
+                    build.score,
+                    build.quality * 100.0,
+                    build.curve_fit,
+                    build.synergy_density * 100.0
+                ));
+                output.push_str(&format!(
+                    "**Playables**: {} | **Creatures**: {} | **Removal**: {}\n\n",
+                    build.playables, build.creatures, build.removal
+                ));
+                if !build.lands.is_empty() {
+                    let lands: Vec<String> = build
+                        .lands
+                        .iter()
+                        .map(|l| format!("{} {}", l.count, l.color.basic_land()))
+                        .collect();
+                    output.push_str(&format!("**Lands**: {}\n\n", lands.join(", ")));
+                }
+                if !build.splash_cards.is_empty() {
+                    output.push_str(&format!(
+                        "**Splash**: {}\n\n",
+                        build.splash_cards.join(", ")
+                    ));
+                }
+                for card in &build.cards {
+                    output.push_str(&format!("- {}x {}\n", card.quantity, card.name));
+                }
+                output.push('\n');
+            }
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry limited analyzer*\n");
+
+        output
+    }
+}
+
+/// Append a table of cards with their grades and percentiles
+fn push_rated_table(output: &mut String, cards: &[RatedCard]) {
+    output.push_str("| Card | Grade | Percentile | Rating |\n");
+    output.push_str("|------|-------|------------|--------|\n");
+    for card in cards {
+        let rating = card.rating.unwrap_or(0.0);
+        output.push_str(&format!(
+            "| {} | {} | {:.0}% | {} |\n",
+            card.name,
+            grade(rating),
+            rating * 100.0,
+            card.value.map(|v| format!("{v:.1}")).unwrap_or_default()
+        ));
+    }
+    output.push('\n');
+}
@@ -2,7 +2,7 @@ use std::fs;
 use std::io::Write;
 
 use crate::calculator::get_intensity_recommendations;
-use crate::deck::{guild_name, Color, Deck, ManaBase};
+use crate::deck::{color_combination_name, Color, Deck, ManaBase};
 
 pub struct MarkdownExporter;
 
@@ -35,7 +35,13 @@ impl MarkdownExporter {
         output.push_str(&format!("- **Target Lands**: {}\n", deck.target_lands));
 
         let color_names: Vec<&str> = deck.colors.iter().map(|c| c.name()).collect();
-        output.push_str(&format!("- **Colors**: {}\n", color_names.join(", ")));
+        match color_combination_name(&deck.colors) {
+            Some(combination) => output.push_str(&format!(
+                "- **Colors**: {} ({combination})\n",
+                color_names.join(", ")
+            )),
+            None => output.push_str(&format!("- **Colors**: {}\n", color_names.join(", "))),
+        }
         output.push('\n');
 
         // Mana Symbol Distribution
@@ -88,13 +94,10 @@ impl MarkdownExporter {
             output.push_str(&format!("### Dual Lands ({total_duals} total)\n\n"));
 
             for dual in &mana_base.duals {
-                let name = if dual.colors.len() == 2 {
-                    guild_name(&dual.colors)
-                        .map(|g| g.to_string())
-                        .unwrap_or_else(|| dual.name.clone())
-                } else {
-                    dual.name.clone()
-                };
+                let name = dual
+                    .combination_name()
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| dual.name.clone());
 
                 let colors_str: Vec<_> = dual.colors.iter().map(|c| c.symbol()).collect();
                 output.push_str(&format!(
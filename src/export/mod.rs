@@ -1,9 +1,27 @@
+pub mod composition_report;
+pub mod cube_report;
 pub mod curve_report;
+pub mod deck_report;
+pub mod html_report;
 pub mod json;
+pub mod lands_report;
+pub mod limited_report;
 pub mod markdown;
+pub mod recommend_report;
+pub mod sections_report;
+pub mod synergy_graph;
 pub mod synergy_report;
 
+pub use composition_report::*;
+pub use cube_report::*;
 pub use curve_report::*;
+pub use deck_report::*;
+pub use html_report::*;
 pub use json::*;
+pub use lands_report::*;
+pub use limited_report::*;
 pub use markdown::*;
+pub use recommend_report::*;
+pub use sections_report::*;
+pub use synergy_graph::*;
 pub use synergy_report::*;
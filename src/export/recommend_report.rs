@@ -0,0 +1,91 @@
+use crate::recommend::Recommendations;
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+/// Markdown exporter for cut and add recommendations
+pub struct RecommendationReportExporter;
+
+impl RecommendationReportExporter {
+    /// Export recommendations to a markdown file
+    pub fn export(
+        recommendations: &Recommendations,
+        path: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(recommendations);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(recommendations: &Recommendations) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Recommendations\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        // Deck info
+        if let Some(name) = &recommendations.deck_name {
+            output.push_str(&format!("**Deck**: {name}\n"));
+        }
+        if let Some(theme) = &recommendations.primary_theme {
+            output.push_str(&format!("**Primary Theme**: {}\n", theme.display_name()));
+        }
+        output.push('\n');
+
+        // Cuts
+        output.push_str("## Suggested Cuts\n\n");
+        if recommendations.cuts.is_empty() {
+            output.push_str("No cards to cut.\n\n");
+        } else {
+            output.push_str("| Card | Keep Score | Synergy | Curve | Role | Reasons |\n");
+            output.push_str("|------|------------|---------|-------|------|---------|\n");
+            for cut in &recommendations.cuts {
+                output.push_str(&format!(
+                    "| {} | {:.0} | {:.0} | {:.0} | {:.0} | {} |\n",
+                    cut.card,
+                    cut.score * 100.0,
+                    cut.synergy * 100.0,
+                    cut.curve * 100.0,
+                    cut.role * 100.0,
+                    cut.reasons.join("; ")
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Additions
+        output.push_str("## Suggested Additions\n\n");
+        if recommendations.adds.is_empty() {
+            output.push_str(&format!(
+                "No additions found in a pool of {} cards.\n\n",
+                recommendations.pool_size
+            ));
+        } else {
+            output.push_str("| Card | Cost | New Synergies | With Primary Theme | Partners |\n");
+            output.push_str("|------|------|---------------|--------------------|----------|\n");
+            for add in &recommendations.adds {
+                output.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    add.card,
+                    add.mana_cost.as_deref().unwrap_or(""),
+                    add.new_edges,
+                    add.primary_edges,
+                    add.partners.join(", ")
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry recommendation engine*\n");
+
+        output
+    }
+}
@@ -0,0 +1,112 @@
+use crate::sections::SectionAnalysis;
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+
+/// Markdown exporter for sideboard and maybeboard analysis
+pub struct SectionReportExporter;
+
+impl SectionReportExporter {
+    /// Export section analysis to a markdown file
+    pub fn export(
+        analysis: &SectionAnalysis,
+        path: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let content = Self::generate(analysis);
+        let mut file = fs::File::create(path)?;
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Generate markdown report as a string
+    pub fn generate(analysis: &SectionAnalysis) -> String {
+        let mut output = String::new();
+
+        // Header
+        output.push_str("# Scry Sideboard & Maybeboard\n\n");
+        output.push_str(&format!(
+            "Generated: {}\n\n",
+            Local::now().format("%Y-%m-%d %H:%M")
+        ));
+
+        // Deck info
+        if let Some(name) = &analysis.deck_name {
+            output.push_str(&format!("**Deck**: {name}\n"));
+        }
+        output.push_str(&format!("**Format**: {}\n", analysis.format));
+        output.push_str(&format!(
+            "**Mainboard**: {} cards\n\n",
+            analysis.mainboard_cards
+        ));
+
+        // Sideboard
+        output.push_str("## Sideboard\n\n");
+        match &analysis.sideboard {
+            None => output.push_str("No sideboard.\n\n"),
+            Some(sideboard) => {
+                match sideboard.max_size {
+                    Some(max) => output.push_str(&format!(
+                        "**Size**: {}/{max} cards{}\n\n",
+                        sideboard.total_cards,
+                        if sideboard.is_oversized() {
+                            " (over the limit)"
+                        } else {
+                            ""
+                        }
+                    )),
+                    None => {
+                        output.push_str(&format!("**Size**: {} cards\n\n", sideboard.total_cards))
+                    }
+                }
+                output.push_str("| Role | Count | Cards |\n");
+                output.push_str("|------|-------|-------|\n");
+                for role_count in &sideboard.roles {
+                    output.push_str(&format!(
+                        "| {} | {} | {} |\n",
+                        role_count.role.name(),
+                        role_count.count,
+                        role_count.cards.join(", ")
+                    ));
+                }
+                output.push('\n');
+            }
+        }
+
+        // Maybeboard
+        output.push_str("## Maybeboard Swaps\n\n");
+        if analysis.maybeboard.is_empty() {
+            output.push_str("No maybeboard cards.\n\n");
+        } else {
+            output.push_str(
+                "| Card | Swap Out | Synergies | Score | Avg CMC | Curve Fit | Theme Changes |\n",
+            );
+            output.push_str(
+                "|------|----------|-----------|-------|---------|-----------|---------------|\n",
+            );
+            for swap in &analysis.maybeboard {
+                let changes: Vec<String> = swap
+                    .theme_changes
+                    .iter()
+                    .map(|c| format!("{} {} → {}", c.theme.display_name(), c.before, c.after))
+                    .collect();
+                output.push_str(&format!(
+                    "| {} | {} | {} | {:+.0} | {:+.2} | {:+.0} | {} |\n",
+                    swap.card,
+                    swap.swap_out.as_deref().unwrap_or("-"),
+                    swap.new_edges,
+                    swap.synergy_score_change,
+                    swap.average_cmc_change,
+                    swap.curve_fit_change,
+                    changes.join(", ")
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by scry section analyzer*\n");
+
+        output
+    }
+}
@@ -0,0 +1,363 @@
+use crate::synergy::{SynergyMatrix, SynergyRelation, Theme};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Node fill colors, assigned to the deck's themes in order of prominence
+const THEME_COLORS: &[&str] = &[
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+/// Fill color for cards without a theme among the deck's detected themes
+const UNTHEMED_COLOR: &str = "#d3d3d3";
+
+/// File formats the synergy graph can be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    GraphMl,
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Pick a format from a file extension (.dot/.gv, .graphml, .mmd/.mermaid)
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        let ext = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase);
+
+        match ext.as_deref() {
+            Some("dot") | Some("gv") => Ok(Self::Dot),
+            Some("graphml") => Ok(Self::GraphMl),
+            Some("mmd") | Some("mermaid") => Ok(Self::Mermaid),
+            _ => Err(format!(
+                "Unknown graph format for {path} (use .dot, .gv, .graphml, .mmd or .mermaid)"
+            )),
+        }
+    }
+}
+
+/// A card in the synergy graph
+pub struct GraphNode<'a> {
+    pub name: &'a str,
+    /// The card's most prominent deck theme
+    pub theme: Option<&'a Theme>,
+    pub color: &'static str,
+    /// Number of synergy edges touching the card
+    pub degree: usize,
+}
+
+/// Exporter for the card-to-card synergy graph
+pub struct SynergyGraphExporter;
+
+impl SynergyGraphExporter {
+    /// Write the synergy graph to a file, choosing the format from its extension
+    pub fn export(matrix: &SynergyMatrix, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let format = GraphFormat::from_path(path)?;
+        fs::write(path, Self::generate(matrix, format))?;
+        Ok(())
+    }
+
+    /// Generate the synergy graph in the given format
+    pub fn generate(matrix: &SynergyMatrix, format: GraphFormat) -> String {
+        let nodes = Self::nodes(matrix);
+        match format {
+            GraphFormat::Dot => Self::to_dot(matrix, &nodes),
+            GraphFormat::GraphMl => Self::to_graphml(matrix, &nodes),
+            GraphFormat::Mermaid => Self::to_mermaid(matrix, &nodes),
+        }
+    }
+
+    /// Cards with at least one synergy edge, keyed by name.
+    ///
+    /// Each card is colored by the first of its themes that the deck leans on,
+    /// following the order of `detected_themes`.
+    pub fn nodes(matrix: &SynergyMatrix) -> BTreeMap<&str, GraphNode<'_>> {
+        let theme_colors: HashMap<&Theme, &'static str> = matrix
+            .detected_themes
+            .iter()
+            .zip(THEME_COLORS.iter().cycle())
+            .map(|(analysis, color)| (&analysis.theme, *color))
+            .collect();
+
+        let mut nodes: BTreeMap<&str, GraphNode> = BTreeMap::new();
+        for edge in &matrix.edges {
+            for name in [edge.card_a.as_str(), edge.card_b.as_str()] {
+                nodes
+                    .entry(name)
+                    .or_insert_with(|| {
+                        let theme = matrix.card_profiles.get(name).and_then(|profile| {
+                            matrix
+                                .detected_themes
+                                .iter()
+                                .map(|a| &a.theme)
+                                .find(|t| profile.themes.contains(t))
+                        });
+                        GraphNode {
+                            name,
+                            theme,
+                            color: theme
+                                .and_then(|t| theme_colors.get(t).copied())
+                                .unwrap_or(UNTHEMED_COLOR),
+                            degree: 0,
+                        }
+                    })
+                    .degree += 1;
+            }
+        }
+        nodes
+    }
+
+    fn node_ids<'a>(nodes: &BTreeMap<&'a str, GraphNode>) -> HashMap<&'a str, String> {
+        nodes
+            .keys()
+            .enumerate()
+            .map(|(i, name)| (*name, format!("n{i}")))
+            .collect()
+    }
+
+    pub fn relation_name(relation: &SynergyRelation) -> &'static str {
+        match relation {
+            SynergyRelation::Enables => "enables",
+            SynergyRelation::PayoffFor => "payoff_for",
+            SynergyRelation::Supports => "supports",
+            SynergyRelation::Combos => "combos",
+        }
+    }
+
+    /// Graphviz DOT: node width grows with edge count, edges styled by relation
+    fn to_dot(matrix: &SynergyMatrix, nodes: &BTreeMap<&str, GraphNode>) -> String {
+        let ids = Self::node_ids(nodes);
+        let mut output = String::new();
+
+        output.push_str("digraph synergies {\n");
+        output.push_str("  graph [overlap=false, splines=true];\n");
+        output.push_str("  node [shape=ellipse, style=filled, fontname=\"Helvetica\"];\n");
+
+        for node in nodes.values() {
+            let width = 0.75 + (node.degree as f64 * 0.1).min(1.5);
+            let tooltip = node.theme.map(|t| t.display_name()).unwrap_or_default();
+            output.push_str(&format!(
+                "  {} [label=\"{}\", fillcolor=\"{}\", width={:.2}, tooltip=\"{}\"];\n",
+                ids[node.name],
+                escape_dot(node.name),
+                node.color,
+                width,
+                escape_dot(&tooltip)
+            ));
+        }
+
+        for edge in &matrix.edges {
+            let style = match edge.relation {
+                SynergyRelation::Enables => "style=solid",
+                SynergyRelation::PayoffFor => "style=bold",
+                SynergyRelation::Supports => "style=dashed, dir=none",
+                SynergyRelation::Combos => "style=bold, color=\"#c0392b\", dir=both",
+            };
+            output.push_str(&format!(
+                "  {} -> {} [{}, penwidth={:.1}, tooltip=\"{}\"];\n",
+                ids[edge.card_a.as_str()],
+                ids[edge.card_b.as_str()],
+                style,
+                1.0 + edge.strength * 2.0,
+                escape_dot(&edge.reason)
+            ));
+        }
+
+        output.push_str("}\n");
+        output
+    }
+
+    /// GraphML with theme, color and degree on nodes and relation, strength
+    /// and reason on edges
+    fn to_graphml(matrix: &SynergyMatrix, nodes: &BTreeMap<&str, GraphNode>) -> String {
+        let ids = Self::node_ids(nodes);
+        let mut output = String::new();
+
+        output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (id, target, name, kind) in [
+            ("label", "node", "label", "string"),
+            ("theme", "node", "theme", "string"),
+            ("color", "node", "color", "string"),
+            ("degree", "node", "degree", "int"),
+            ("relation", "edge", "relation", "string"),
+            ("strength", "edge", "strength", "double"),
+            ("reason", "edge", "reason", "string"),
+        ] {
+            output.push_str(&format!(
+                "  <key id=\"{id}\" for=\"{target}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>\n"
+            ));
+        }
+        output.push_str("  <graph id=\"synergies\" edgedefault=\"directed\">\n");
+
+        for node in nodes.values() {
+            output.push_str(&format!("    <node id=\"{}\">\n", ids[node.name]));
+            output.push_str(&format!(
+                "      <data key=\"label\">{}</data>\n",
+                escape_xml(node.name)
+            ));
+            if let Some(theme) = node.theme {
+                output.push_str(&format!(
+                    "      <data key=\"theme\">{}</data>\n",
+                    escape_xml(&theme.display_name())
+                ));
+            }
+            output.push_str(&format!(
+                "      <data key=\"color\">{}</data>\n",
+                node.color
+            ));
+            output.push_str(&format!(
+                "      <data key=\"degree\">{}</data>\n",
+                node.degree
+            ));
+            output.push_str("    </node>\n");
+        }
+
+        for (i, edge) in matrix.edges.iter().enumerate() {
+            output.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">\n",
+                i,
+                ids[edge.card_a.as_str()],
+                ids[edge.card_b.as_str()]
+            ));
+            output.push_str(&format!(
+                "      <data key=\"relation\">{}</data>\n",
+                Self::relation_name(&edge.relation)
+            ));
+            output.push_str(&format!(
+                "      <data key=\"strength\">{:.2}</data>\n",
+                edge.strength
+            ));
+            output.push_str(&format!(
+                "      <data key=\"reason\">{}</data>\n",
+                escape_xml(&edge.reason)
+            ));
+            output.push_str("    </edge>\n");
+        }
+
+        output.push_str("  </graph>\n");
+        output.push_str("</graphml>\n");
+        output
+    }
+
+    /// Mermaid flowchart. Mermaid can't size nodes, so heavily connected cards
+    /// get a thicker border instead.
+    fn to_mermaid(matrix: &SynergyMatrix, nodes: &BTreeMap<&str, GraphNode>) -> String {
+        let ids = Self::node_ids(nodes);
+        let mut output = String::new();
+
+        output.push_str("graph LR\n");
+        for node in nodes.values() {
+            output.push_str(&format!(
+                "  {}[\"{}\"]\n",
+                ids[node.name],
+                escape_mermaid(node.name)
+            ));
+        }
+
+        for edge in &matrix.edges {
+            let arrow = match edge.relation {
+                SynergyRelation::Enables => "-->",
+                SynergyRelation::PayoffFor => "==>",
+                SynergyRelation::Supports => "-.-",
+                SynergyRelation::Combos => "<==>",
+            };
+            output.push_str(&format!(
+                "  {} {} {}\n",
+                ids[edge.card_a.as_str()],
+                arrow,
+                ids[edge.card_b.as_str()]
+            ));
+        }
+
+        for node in nodes.values() {
+            let border = 1 + node.degree.min(8) / 2;
+            output.push_str(&format!(
+                "  style {} fill:{},stroke-width:{}px\n",
+                ids[node.name], node.color, border
+            ));
+        }
+
+        output
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synergy::{CardSynergyProfile, SynergyEdge, ThemeAnalysis};
+
+    fn matrix() -> SynergyMatrix {
+        let mut matrix = SynergyMatrix::new();
+        let mut profile = CardSynergyProfile::new("Blood Artist".to_string());
+        profile.themes.push(Theme::Sacrifice);
+        matrix
+            .card_profiles
+            .insert(profile.card_name.clone(), profile);
+        matrix.detected_themes.push(ThemeAnalysis {
+            theme: Theme::Sacrifice,
+            card_count: 2,
+            percentage: 0.2,
+            enablers: vec![],
+            payoffs: vec![],
+            support: vec![],
+        });
+        matrix.edges.push(SynergyEdge {
+            card_a: "Viscera Seer".to_string(),
+            card_b: "Blood Artist".to_string(),
+            relation: SynergyRelation::Enables,
+            themes: vec![Theme::Sacrifice],
+            strength: 0.8,
+            reason: "Sacrifice \"fodder\" & drain".to_string(),
+        });
+        matrix
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(GraphFormat::from_path("out.dot"), Ok(GraphFormat::Dot));
+        assert_eq!(
+            GraphFormat::from_path("out.GraphML"),
+            Ok(GraphFormat::GraphMl)
+        );
+        assert_eq!(GraphFormat::from_path("out.mmd"), Ok(GraphFormat::Mermaid));
+        assert!(GraphFormat::from_path("out.png").is_err());
+    }
+
+    #[test]
+    fn test_graph_formats() {
+        let matrix = matrix();
+
+        let dot = SynergyGraphExporter::generate(&matrix, GraphFormat::Dot);
+        assert!(dot.contains("n0 [label=\"Blood Artist\", fillcolor=\"#4e79a7\""));
+        assert!(dot.contains("n1 -> n0 [style=solid"));
+        assert!(dot.contains("Sacrifice \\\"fodder\\\" & drain"));
+
+        let graphml = SynergyGraphExporter::generate(&matrix, GraphFormat::GraphMl);
+        assert!(graphml.contains("<data key=\"relation\">enables</data>"));
+        assert!(graphml.contains("Sacrifice &quot;fodder&quot; &amp; drain"));
+
+        let mermaid = SynergyGraphExporter::generate(&matrix, GraphFormat::Mermaid);
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("n1 --> n0"));
+        assert!(mermaid.contains(&format!("style n1 fill:{UNTHEMED_COLOR}")));
+    }
+}
@@ -35,6 +35,43 @@ impl SynergyReportExporter {
         }
         output.push_str(&format!("**Total Cards**: {}\n", matrix.total_cards));
         output.push_str(&format!("**Unique Cards**: {}\n\n", matrix.unique_cards));
+        output.push_str(&Self::sections(matrix));
+
+        // Footer
+        output.push_str("---\n\n");
+        output.push_str("*Generated by mtg-cli synergy analyzer*\n");
+
+        output
+    }
+
+    /// Report body without the title and deck info, for combining with other reports
+    pub fn sections(matrix: &SynergyMatrix) -> String {
+        let mut output = String::new();
+
+        // Strategy
+        if let Some(archetype) = matrix.likely_archetype() {
+            output.push_str("## Strategy\n\n");
+            output.push_str(&format!(
+                "**{}** ({:.0}% fit)\n\n",
+                archetype.theme.display_name(),
+                archetype.score * 100.0
+            ));
+            for evidence in &archetype.evidence {
+                output.push_str(&format!("- {evidence}\n"));
+            }
+            output.push('\n');
+
+            output.push_str("| Archetype | Fit |\n");
+            output.push_str("|-----------|-----|\n");
+            for score in &matrix.archetypes {
+                output.push_str(&format!(
+                    "| {} | {:.0}% |\n",
+                    score.theme.display_name(),
+                    score.score * 100.0
+                ));
+            }
+            output.push('\n');
+        }
 
         // Theme Analysis
         output.push_str("## Detected Themes\n\n");
@@ -87,6 +124,14 @@ impl SynergyReportExporter {
 
         // Statistics
         output.push_str("## Statistics\n\n");
+        output.push_str(&format!(
+            "- **Synergy Score**: {:.0}/100\n",
+            matrix.stats.synergy_score
+        ));
+        output.push_str(&format!(
+            "- **Theme Coherence**: {:.1}%\n",
+            matrix.stats.theme_coherence * 100.0
+        ));
         output.push_str(&format!(
             "- **Theme Coverage**: {:.1}%\n",
             matrix.stats.theme_coverage * 100.0
@@ -103,13 +148,78 @@ impl SynergyReportExporter {
 
         // Hub Cards
         if !matrix.stats.hub_cards.is_empty() {
-            output.push_str("### Hub Cards (Most Synergies)\n\n");
+            output.push_str("### Hub Cards (Most Central)\n\n");
             for card in &matrix.stats.hub_cards {
                 output.push_str(&format!("- {card}\n"));
             }
             output.push('\n');
         }
 
+        // Synergy Packages
+        if matrix.clusters.len() > 1 {
+            output.push_str("### Synergy Packages\n\n");
+            output.push_str("| # | Themes | Cards | Bridges |\n");
+            output.push_str("|---|--------|-------|---------|\n");
+            for (i, cluster) in matrix.clusters.iter().enumerate() {
+                let themes: Vec<_> = cluster.themes.iter().map(|t| t.display_name()).collect();
+                output.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    i + 1,
+                    if themes.is_empty() {
+                        "Mixed".to_string()
+                    } else {
+                        themes.join(", ")
+                    },
+                    cluster.cards.join(", "),
+                    cluster.bridges.join(", ")
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Key Interactions
+        let interactions = matrix.key_interactions(10);
+        if !interactions.is_empty() {
+            output.push_str("### Key Interactions\n\n");
+            for edge in interactions {
+                output.push_str(&format!("- {}\n", edge.reason));
+            }
+            output.push('\n');
+        }
+
+        // Combos
+        if !matrix.combos.is_empty() {
+            let (complete, partial): (Vec<_>, Vec<_>) =
+                matrix.combos.iter().partition(|m| m.is_complete());
+
+            output.push_str("## Combos\n\n");
+            if !complete.is_empty() {
+                output.push_str("### Complete\n\n");
+                for combo_match in &complete {
+                    output.push_str(&format!(
+                        "- **{}**: {}\n",
+                        combo_match.combo.label(),
+                        combo_match.combo.results_summary()
+                    ));
+                }
+                output.push('\n');
+            }
+            if !partial.is_empty() {
+                output.push_str("### One Piece Away\n\n");
+                output.push_str("| Have | Missing | Result |\n");
+                output.push_str("|------|---------|--------|\n");
+                for combo_match in &partial {
+                    output.push_str(&format!(
+                        "| {} | {} | {} |\n",
+                        combo_match.present.join(", "),
+                        combo_match.missing.join(", "),
+                        combo_match.combo.results_summary()
+                    ));
+                }
+                output.push('\n');
+            }
+        }
+
         // Orphan Cards
         if !matrix.stats.orphan_cards.is_empty() {
             output.push_str("### Cards with No Synergies\n\n");
@@ -143,10 +253,6 @@ impl SynergyReportExporter {
             output.push('\n');
         }
 
-        // Footer
-        output.push_str("---\n\n");
-        output.push_str("*Generated by mtg-cli synergy analyzer*\n");
-
         output
     }
 }
@@ -73,6 +73,13 @@ impl DeckList {
             .filter(|e| e.section == DeckSection::Sideboard)
     }
 
+    /// Get maybeboard entries
+    pub fn maybeboard(&self) -> impl Iterator<Item = &DeckEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.section == DeckSection::Maybeboard)
+    }
+
     /// Get commander entries
     pub fn commanders(&self) -> impl Iterator<Item = &DeckEntry> {
         self.entries
@@ -85,6 +92,12 @@ impl DeckList {
         self.entries.iter().map(|e| e.quantity).sum()
     }
 
+    /// Get the mainboard card count (including commander), leaving out the
+    /// sideboard and maybeboard
+    pub fn mainboard_cards(&self) -> u32 {
+        self.mainboard().map(|e| e.quantity).sum()
+    }
+
     /// Get the count of unique cards
     pub fn unique_cards(&self) -> usize {
         self.entries.len()
@@ -95,6 +108,31 @@ impl DeckList {
         self.entries.iter().map(|e| e.card_name.clone()).collect()
     }
 
+    /// Copy card data from a previously hydrated decklist onto matching entries.
+    ///
+    /// Returns the names that still need to be fetched.
+    pub fn reuse_cards_from(&mut self, previous: &DeckList) -> Vec<String> {
+        let mut missing = Vec::new();
+
+        for entry in &mut self.entries {
+            let known = previous
+                .entries
+                .iter()
+                .find(|e| e.card.is_some() && e.card_name.eq_ignore_ascii_case(&entry.card_name))
+                .and_then(|e| e.card.clone());
+
+            match known {
+                Some(card) => entry.card = Some(card),
+                None if !missing.contains(&entry.card_name) => {
+                    missing.push(entry.card_name.clone())
+                }
+                None => {}
+            }
+        }
+
+        missing
+    }
+
     /// Add an entry to the decklist
     pub fn add_entry(&mut self, quantity: u32, card_name: String, section: DeckSection) {
         self.entries.push(DeckEntry {
@@ -105,6 +143,38 @@ impl DeckList {
         });
     }
 
+    /// Render the decklist as plain text readable by `TextDecklistParser`
+    pub fn to_text(&self) -> String {
+        let mut output = String::new();
+
+        for (section, header) in [
+            (DeckSection::Commander, "// Commander"),
+            (DeckSection::Mainboard, "// Mainboard"),
+            (DeckSection::Sideboard, "// Sideboard"),
+            (DeckSection::Maybeboard, "// Maybeboard"),
+        ] {
+            let entries: Vec<_> = self
+                .entries
+                .iter()
+                .filter(|e| e.section == section)
+                .collect();
+            if entries.is_empty() {
+                continue;
+            }
+
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            output.push_str(header);
+            output.push('\n');
+            for entry in entries {
+                output.push_str(&format!("{} {}\n", entry.quantity, entry.card_name));
+            }
+        }
+
+        output
+    }
+
     /// Count total lands in the mainboard (from hydrated card data)
     pub fn count_lands(&self) -> u32 {
         self.mainboard()
@@ -1,7 +1,9 @@
 pub mod decklist;
 pub mod moxfield;
 pub mod text_parser;
+pub mod watch;
 
 pub use decklist::*;
 pub use moxfield::*;
 pub use text_parser::*;
+pub use watch::*;
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Detects changes to a decklist file by polling its modification time
+pub struct FileWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+}
+
+impl FileWatcher {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let last_modified = Self::modified(&path);
+        Self {
+            path,
+            last_modified,
+        }
+    }
+
+    fn modified(path: &PathBuf) -> Option<SystemTime> {
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    /// Whether the file was modified since the last call
+    pub fn changed(&mut self) -> bool {
+        let modified = Self::modified(&self.path);
+        if modified.is_some() && modified != self.last_modified {
+            self.last_modified = modified;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_detects_modification() {
+        let path = std::env::temp_dir().join(format!("scry_watch_{}.txt", std::process::id()));
+        std::fs::write(&path, "1 Opt\n").unwrap();
+
+        let mut watcher = FileWatcher::new(&path);
+        assert!(!watcher.changed());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(later).unwrap();
+
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+        std::fs::remove_file(&path).ok();
+    }
+}
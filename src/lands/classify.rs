@@ -0,0 +1,165 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::api::Card;
+use crate::deck::{sorted_colors, Color};
+
+use super::types::{LandEntry, LandKind, LandProfile};
+
+lazy_static! {
+    /// A mana ability's "Add ..." clause, up to the end of the sentence
+    static ref ADD_CLAUSE: Regex = Regex::new(r"Add ([^.]*)").unwrap();
+    static ref COLOR_SYMBOL: Regex = Regex::new(r"\{([WUBRG])\}").unwrap();
+    /// What a fetch land searches for, e.g. "an Island or Swamp card"
+    static ref SEARCH_TARGET: Regex =
+        Regex::new(r"[Ss]earch your library for (?:a|an|up to \w+) ([^.,]*?) cards?").unwrap();
+}
+
+/// Classify a nonbasic land by the colors it makes and how it enters.
+///
+/// Returns `None` for nonlands (including modal cards whose front face isn't
+/// a land), basics and lands making fewer than two colors. Lands that make
+/// "one mana of any color" count as all five colors unless the color depends
+/// on other lands ("could produce").
+pub fn classify_land(card: &Card) -> Option<LandProfile> {
+    let front_type = card.type_line.split("//").next().unwrap_or_default();
+    if !front_type.contains("Land") || front_type.contains("Basic") {
+        return None;
+    }
+
+    let text = front_text(card);
+    let mut colors: Vec<Color> = Color::all_colors()
+        .into_iter()
+        .filter(|c| front_type.contains(c.basic_land()))
+        .collect();
+    for clause in ADD_CLAUSE.captures_iter(&text) {
+        for symbol in COLOR_SYMBOL.captures_iter(&clause[1]) {
+            colors.extend(Color::from_symbol(&symbol[1]));
+        }
+    }
+    if text.contains("mana of any color") && !text.contains("could produce") {
+        colors = Color::all_colors();
+    }
+
+    let fetch_target = SEARCH_TARGET
+        .captures(&text)
+        .filter(|_| text.contains("Sacrifice"))
+        .map(|target| target[1].to_string())
+        .filter(|target| {
+            target.contains("land")
+                || Color::all_colors()
+                    .iter()
+                    .any(|c| target.contains(c.basic_land()))
+        });
+    if let Some(target) = &fetch_target {
+        if target.contains("basic land") {
+            colors = Color::all_colors();
+        } else {
+            colors.extend(
+                Color::all_colors()
+                    .into_iter()
+                    .filter(|c| target.contains(c.basic_land())),
+            );
+        }
+    }
+
+    let colors = sorted_colors(&colors);
+    let kind = match colors.len() {
+        0 | 1 => return None,
+        _ if fetch_target.is_some() => LandKind::Fetch,
+        2 => LandKind::Dual,
+        3 => LandKind::TriLand,
+        _ => LandKind::AnyColor,
+    };
+
+    Some(LandProfile {
+        name: card.name.clone(),
+        colors,
+        kind,
+        entry: land_entry(&text),
+        unit_usd: card
+            .prices
+            .as_ref()
+            .and_then(|p| p.usd.as_deref())
+            .and_then(|usd| usd.parse::<f64>().ok()),
+    })
+}
+
+/// Oracle text of the card's front face
+fn front_text(card: &Card) -> String {
+    card.oracle_text
+        .clone()
+        .or_else(|| {
+            card.card_faces
+                .as_ref()
+                .and_then(|faces| faces.first())
+                .and_then(|face| face.oracle_text.clone())
+        })
+        .unwrap_or_default()
+}
+
+fn land_entry(text: &str) -> LandEntry {
+    let tapped = text.contains("enters tapped")
+        || text.contains("enters the battlefield tapped")
+        || text.contains("onto the battlefield tapped");
+    if !tapped {
+        LandEntry::Untapped
+    } else if text.contains("unless") || text.contains("If you don't") {
+        LandEntry::Conditional
+    } else {
+        LandEntry::Tapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    #[test]
+    fn test_classify_lands() {
+        let shock = classify_land(&card("Watery Grave")
+            .type_line("Land — Island Swamp")
+            .oracle("({T}: Add {U} or {B}.)\nAs Watery Grave enters, you may pay 2 life. If you don't, it enters tapped.")
+            .build())
+        .unwrap();
+        assert_eq!(shock.colors, vec![Color::Blue, Color::Black]);
+        assert_eq!(shock.kind, LandKind::Dual);
+        assert_eq!(shock.entry, LandEntry::Conditional);
+
+        let tri = classify_land(
+            &card("Crumbling Necropolis")
+                .type_line("Land")
+                .oracle("Crumbling Necropolis enters tapped.\n{T}: Add {U}, {B}, or {R}.")
+                .build(),
+        )
+        .unwrap();
+        assert_eq!(tri.kind, LandKind::TriLand);
+        assert_eq!(tri.entry, LandEntry::Tapped);
+
+        let fetch = classify_land(&card("Bloodstained Mire")
+            .type_line("Land")
+            .oracle("{T}, Pay 1 life, Sacrifice Bloodstained Mire: Search your library for a Swamp or Mountain card, put it onto the battlefield, then shuffle.")
+            .build())
+        .unwrap();
+        assert_eq!(fetch.kind, LandKind::Fetch);
+        assert_eq!(fetch.colors, vec![Color::Black, Color::Red]);
+        assert_eq!(fetch.entry, LandEntry::Untapped);
+
+        let check = classify_land(&card("Drowned Catacomb")
+            .type_line("Land")
+            .oracle("Drowned Catacomb enters tapped unless you control an Island or a Swamp.\n{T}: Add {U} or {B}.")
+            .build())
+        .unwrap();
+        assert_eq!(check.entry, LandEntry::Conditional);
+
+        assert!(classify_land(&card("Island").type_line("Basic Land — Island").build()).is_none());
+        assert!(classify_land(
+            &card("Reflecting Pool")
+                .type_line("Land")
+                .oracle("{T}: Add one mana of any type that a land you control could produce.")
+                .build()
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod classify;
+pub mod optimizer;
+pub mod types;
+
+pub use optimizer::*;
+pub use types::*;
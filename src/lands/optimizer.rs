@@ -0,0 +1,465 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::api::Card;
+use crate::calculator::get_calculator;
+use crate::deck::{sorted_colors, Algorithm, Color, Deck, DualLand, Format};
+use crate::limited::BasicLandCount;
+use crate::report::legality::is_singleton_format;
+
+use super::classify::classify_land;
+use super::types::{ColorSources, LandEntry, LandKind, LandProfile, LandSuite, SuggestedLand};
+
+/// Share of the lands a splash color aims to make
+const SPLASH_SOURCE_SHARE: f64 = 0.25;
+
+/// Smallest share of the lands a main color aims to make
+const MAIN_SOURCE_SHARE: f64 = 0.55;
+
+/// Colors below this share of the pips are treated as splashes
+const SPLASH_PIP_SHARE: f64 = 0.15;
+
+/// Sources past the target that still improve the mana base
+const SURPLUS_SOURCES: u32 = 2;
+
+/// A nonbasic land that can go in the deck, with the deck colors it makes
+struct Candidate {
+    profile: LandProfile,
+    colors: Vec<Color>,
+    owned: u32,
+}
+
+/// Sources, basics and score of one land configuration
+struct Evaluation {
+    score: f64,
+    basics: HashMap<Color, u32>,
+    sources: HashMap<Color, u32>,
+}
+
+/// Picks nonbasic lands from a card pool to reach colored-source targets
+/// while keeping tapped lands and cost down
+pub struct ManaBaseOptimizer {
+    format: Format,
+    /// Scryfall format key the lands must be legal in
+    legality: Option<String>,
+    budget: Option<f64>,
+    /// Owned copies by lowercase card name
+    collection: HashMap<String, u32>,
+    algorithm: Algorithm,
+}
+
+impl ManaBaseOptimizer {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            legality: None,
+            budget: None,
+            collection: HashMap::new(),
+            algorithm: Algorithm::Simple,
+        }
+    }
+
+    pub fn with_legality(mut self, legality: Option<String>) -> Self {
+        self.legality = legality;
+        self
+    }
+
+    /// Most to spend on lands that aren't in the collection
+    pub fn with_budget(mut self, budget: Option<f64>) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Owned cards as (name, quantity); owned copies cost nothing
+    pub fn with_collection(mut self, collection: Vec<(String, u32)>) -> Self {
+        for (name, quantity) in collection {
+            *self.collection.entry(name.to_lowercase()).or_insert(0) += quantity;
+        }
+        self
+    }
+
+    /// Calculator that splits the remaining slots into basics
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Build a land suite of `lands` lands for `colors`, weighting each color
+    /// by its pips in `weights`.
+    ///
+    /// Lands are added greedily, one copy at a time, picking whichever most
+    /// improves the score: source deficit against the targets, then extra
+    /// sources, less a penalty for lands that enter tapped.
+    pub fn optimize(
+        &self,
+        colors: &[Color],
+        weights: &HashMap<Color, u32>,
+        lands: u32,
+        pool: &[Card],
+    ) -> LandSuite {
+        let colors = sorted_colors(colors);
+        let targets = source_targets(&colors, weights, lands);
+        let candidates = self.candidates(&colors, pool);
+        let max_copies = self.max_copies();
+
+        let mut copies = vec![0u32; candidates.len()];
+        let mut spent = 0.0;
+        let mut current = self.evaluate(&colors, weights, lands, &targets, &candidates, &copies);
+
+        while copies.iter().sum::<u32>() < lands {
+            let mut best: Option<(usize, f64, Evaluation)> = None;
+            for (i, candidate) in candidates.iter().enumerate() {
+                if copies[i] >= max_copies {
+                    continue;
+                }
+                if let Some(budget) = self.budget {
+                    if spent + copy_cost(candidate, copies[i]) > budget + f64::EPSILON {
+                        continue;
+                    }
+                }
+
+                copies[i] += 1;
+                let evaluation =
+                    self.evaluate(&colors, weights, lands, &targets, &candidates, &copies);
+                copies[i] -= 1;
+
+                let gain = evaluation.score - current.score;
+                if gain <= 1e-9 {
+                    continue;
+                }
+                let better = match &best {
+                    None => true,
+                    Some((j, best_gain, _)) => {
+                        gain > best_gain + 1e-9
+                            || ((gain - best_gain).abs() <= 1e-9
+                                && prefer(candidate, copies[i], &candidates[*j], copies[*j]))
+                    }
+                };
+                if better {
+                    best = Some((i, gain, evaluation));
+                }
+            }
+
+            let Some((i, _, evaluation)) = best else {
+                break;
+            };
+            spent += copy_cost(&candidates[i], copies[i]);
+            copies[i] += 1;
+            current = evaluation;
+        }
+
+        self.suite(&colors, lands, &targets, &candidates, &copies, current)
+    }
+
+    /// Legal, affordable lands in the pool that make two or more deck colors
+    fn candidates(&self, colors: &[Color], pool: &[Card]) -> Vec<Candidate> {
+        let mut seen = HashSet::new();
+        let mut candidates = Vec::new();
+        for card in pool {
+            if !seen.insert(card.name.clone()) || !self.is_legal(card) {
+                continue;
+            }
+            let Some(profile) = classify_land(card) else {
+                continue;
+            };
+            let land_colors: Vec<Color> = profile
+                .colors
+                .iter()
+                .copied()
+                .filter(|c| colors.contains(c))
+                .collect();
+            if land_colors.len() < 2 {
+                continue;
+            }
+
+            let owned = self
+                .collection
+                .get(&card.name.to_lowercase())
+                .copied()
+                .unwrap_or(0);
+            if self.budget.is_some() && owned == 0 && profile.unit_usd.is_none() {
+                continue;
+            }
+            candidates.push(Candidate {
+                profile,
+                colors: land_colors,
+                owned,
+            });
+        }
+        candidates
+    }
+
+    fn is_legal(&self, card: &Card) -> bool {
+        let commander_only = card
+            .all_oracle_text()
+            .iter()
+            .any(|text| text.contains("commander's color identity"));
+        if commander_only && self.format != Format::Commander {
+            return false;
+        }
+        match &self.legality {
+            Some(key) => matches!(
+                card.legalities.get(key).map(String::as_str),
+                Some("legal") | Some("restricted")
+            ),
+            None => true,
+        }
+    }
+
+    fn max_copies(&self) -> u32 {
+        let singleton = self.format == Format::Commander
+            || self.legality.as_deref().is_some_and(is_singleton_format);
+        if singleton {
+            1
+        } else {
+            4
+        }
+    }
+
+    fn evaluate(
+        &self,
+        colors: &[Color],
+        weights: &HashMap<Color, u32>,
+        lands: u32,
+        targets: &HashMap<Color, u32>,
+        candidates: &[Candidate],
+        copies: &[u32],
+    ) -> Evaluation {
+        let mut deck = Deck::new(self.format);
+        deck.target_lands = lands;
+        deck.colors = colors.to_vec();
+        deck.mana_symbols = weights.clone();
+        deck.dual_lands = candidates
+            .iter()
+            .zip(copies)
+            .filter(|(_, n)| **n > 0)
+            .map(|(c, n)| DualLand::new(c.profile.name.clone(), c.colors.clone(), *n))
+            .collect();
+        let basics = get_calculator(self.algorithm).calculate(&deck).basics;
+
+        let mut sources: HashMap<Color, u32> = HashMap::new();
+        for color in colors {
+            let basic = basics.get(color).copied().unwrap_or(0);
+            let nonbasic: u32 = candidates
+                .iter()
+                .zip(copies)
+                .filter(|(c, _)| makes(c, *color, basic))
+                .map(|(_, n)| *n)
+                .sum();
+            sources.insert(*color, basic + nonbasic);
+        }
+
+        let mut score = 0.0;
+        for color in colors {
+            let total = sources[color];
+            let target = targets[color];
+            score -= target.saturating_sub(total) as f64;
+            score += 0.1 * total.min(target + SURPLUS_SOURCES) as f64;
+        }
+        for (candidate, n) in candidates.iter().zip(copies) {
+            score -= *n as f64
+                * match candidate.profile.entry {
+                    LandEntry::Tapped => 0.5,
+                    LandEntry::Conditional => 0.05,
+                    LandEntry::Untapped => 0.0,
+                };
+        }
+
+        Evaluation {
+            score,
+            basics,
+            sources,
+        }
+    }
+
+    fn suite(
+        &self,
+        colors: &[Color],
+        lands: u32,
+        targets: &HashMap<Color, u32>,
+        candidates: &[Candidate],
+        copies: &[u32],
+        evaluation: Evaluation,
+    ) -> LandSuite {
+        let mut nonbasics: Vec<SuggestedLand> = candidates
+            .iter()
+            .zip(copies)
+            .filter(|(_, n)| **n > 0)
+            .map(|(c, n)| SuggestedLand {
+                name: c.profile.name.clone(),
+                colors: c.colors.clone(),
+                kind: c.profile.kind,
+                entry: c.profile.entry,
+                copies: *n,
+                owned: c.owned.min(*n),
+                unit_usd: c.profile.unit_usd,
+            })
+            .collect();
+        nonbasics.sort_by(|a, b| {
+            b.colors
+                .len()
+                .cmp(&a.colors.len())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        let basics: Vec<BasicLandCount> = colors
+            .iter()
+            .filter_map(|color| {
+                let count = evaluation.basics.get(color).copied().unwrap_or(0);
+                (count > 0).then_some(BasicLandCount {
+                    color: *color,
+                    count,
+                })
+            })
+            .collect();
+
+        let sources = colors
+            .iter()
+            .map(|color| {
+                let basic = evaluation.basics.get(color).copied().unwrap_or(0);
+                let total = evaluation.sources[color];
+                ColorSources {
+                    color: *color,
+                    basics: basic,
+                    nonbasics: total - basic,
+                    total,
+                    target: targets[color],
+                }
+            })
+            .collect();
+
+        LandSuite {
+            colors: colors.to_vec(),
+            format: self.format,
+            legality: self.legality.clone(),
+            total_lands: lands,
+            tapped: nonbasics
+                .iter()
+                .filter(|l| l.entry == LandEntry::Tapped)
+                .map(|l| l.copies)
+                .sum(),
+            cost_usd: nonbasics
+                .iter()
+                .map(SuggestedLand::cost_usd)
+                .fold(0.0, |total, cost| total + cost),
+            nonbasics,
+            basics,
+            sources,
+            budget: self.budget,
+            candidates: candidates.len() as u32,
+        }
+    }
+}
+
+/// Sources each color should reach: a quarter of the lands for a splash,
+/// otherwise its pip share but at least 55%
+fn source_targets(
+    colors: &[Color],
+    weights: &HashMap<Color, u32>,
+    lands: u32,
+) -> HashMap<Color, u32> {
+    let total: u32 = colors
+        .iter()
+        .map(|c| weights.get(c).copied().unwrap_or(0))
+        .sum();
+    colors
+        .iter()
+        .map(|color| {
+            let share = if total > 0 {
+                weights.get(color).copied().unwrap_or(0) as f64 / total as f64
+            } else {
+                1.0 / colors.len() as f64
+            };
+            let share = if share < SPLASH_PIP_SHARE {
+                SPLASH_SOURCE_SHARE
+            } else {
+                share.max(MAIN_SOURCE_SHARE)
+            };
+            (*color, (lands as f64 * share).ceil() as u32)
+        })
+        .collect()
+}
+
+/// Whether a land counts as a source of `color`. Fetch lands only count when
+/// there are basics of that color left to find.
+fn makes(candidate: &Candidate, color: Color, basics: u32) -> bool {
+    candidate.colors.contains(&color) && (candidate.profile.kind != LandKind::Fetch || basics > 0)
+}
+
+/// Cost of adding another copy when `copies` are already chosen
+fn copy_cost(candidate: &Candidate, copies: u32) -> f64 {
+    if copies < candidate.owned {
+        0.0
+    } else {
+        candidate.profile.unit_usd.unwrap_or(0.0)
+    }
+}
+
+/// Tie-break between equally good lands: owned copies, then cheaper, then by name
+fn prefer(a: &Candidate, a_copies: u32, b: &Candidate, b_copies: u32) -> bool {
+    let a_key = (a_copies >= a.owned, copy_cost(a, a_copies));
+    let b_key = (b_copies >= b.owned, copy_cost(b, b_copies));
+    a_key
+        .0
+        .cmp(&b_key.0)
+        .then_with(|| a_key.1.total_cmp(&b_key.1))
+        .then_with(|| a.profile.name.cmp(&b.profile.name))
+        .is_lt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    #[test]
+    fn test_optimize_prefers_untapped_within_budget() {
+        let pool = vec![
+            card("Fancy Dual")
+                .type_line("Land")
+                .oracle("{T}: Add {U} or {B}.")
+                .usd("20.00")
+                .legality("modern", "legal")
+                .build(),
+            card("Slow Dual")
+                .type_line("Land")
+                .oracle("Slow Dual enters tapped.\n{T}: Add {U} or {B}.")
+                .usd("0.25")
+                .legality("modern", "legal")
+                .build(),
+            card("Mono Land")
+                .type_line("Land")
+                .oracle("{T}: Add {U}.")
+                .usd("0.10")
+                .legality("modern", "legal")
+                .build(),
+        ];
+        let weights = HashMap::from([(Color::Blue, 20), (Color::Black, 20)]);
+        let colors = [Color::Blue, Color::Black];
+
+        let optimizer = ManaBaseOptimizer::new(Format::Modern).with_legality(Some("modern".into()));
+        let suite = optimizer.optimize(&colors, &weights, 24, &pool);
+        assert_eq!(suite.candidates, 2);
+        assert_eq!(suite.nonbasics[0].name, "Fancy Dual");
+        assert_eq!(suite.nonbasics[0].copies, 4);
+        assert!(suite.sources.iter().all(|s| s.meets_target()));
+        assert_eq!(
+            suite.nonbasics.iter().map(|l| l.copies).sum::<u32>()
+                + suite.basics.iter().map(|b| b.count).sum::<u32>(),
+            24
+        );
+
+        // Two owned copies are free; the budget covers one more
+        let suite = ManaBaseOptimizer::new(Format::Modern)
+            .with_budget(Some(21.0))
+            .with_collection(vec![("fancy dual".to_string(), 2)])
+            .optimize(&colors, &weights, 24, &pool);
+        let fancy = suite
+            .nonbasics
+            .iter()
+            .find(|l| l.name == "Fancy Dual")
+            .unwrap();
+        assert_eq!(fancy.copies, 3);
+        assert_eq!(fancy.owned, 2);
+        assert!(suite.cost_usd <= 21.0);
+    }
+}
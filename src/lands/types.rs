@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use crate::deck::{Color, Format};
+use crate::limited::BasicLandCount;
+
+/// What kind of multi-color land a card is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LandKind {
+    Dual,
+    TriLand,
+    /// Sacrifices to search for a land of one of several types
+    Fetch,
+    /// Taps for any color
+    AnyColor,
+}
+
+impl LandKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LandKind::Dual => "Dual",
+            LandKind::TriLand => "Tri-land",
+            LandKind::Fetch => "Fetch",
+            LandKind::AnyColor => "Any color",
+        }
+    }
+}
+
+/// Whether a land is ready to tap the turn it's played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LandEntry {
+    Untapped,
+    /// Enters tapped unless a condition is met or a cost is paid
+    Conditional,
+    Tapped,
+}
+
+impl LandEntry {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LandEntry::Untapped => "Untapped",
+            LandEntry::Conditional => "Conditional",
+            LandEntry::Tapped => "Tapped",
+        }
+    }
+}
+
+/// A nonbasic land's colors, speed and price
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandProfile {
+    pub name: String,
+    /// Colors the land makes or can fetch, in WUBRG order
+    pub colors: Vec<Color>,
+    pub kind: LandKind,
+    pub entry: LandEntry,
+    pub unit_usd: Option<f64>,
+}
+
+/// A nonbasic land picked for the mana base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestedLand {
+    pub name: String,
+    /// The deck colors it provides
+    pub colors: Vec<Color>,
+    pub kind: LandKind,
+    pub entry: LandEntry,
+    pub copies: u32,
+    /// Copies already in the collection
+    pub owned: u32,
+    pub unit_usd: Option<f64>,
+}
+
+impl SuggestedLand {
+    /// Cost of the copies that aren't owned yet
+    pub fn cost_usd(&self) -> f64 {
+        self.copies.saturating_sub(self.owned) as f64 * self.unit_usd.unwrap_or(0.0)
+    }
+}
+
+/// Sources for one color in the suggested mana base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorSources {
+    pub color: Color,
+    pub basics: u32,
+    pub nonbasics: u32,
+    pub total: u32,
+    /// Sources the optimizer aimed for
+    pub target: u32,
+}
+
+impl ColorSources {
+    pub fn meets_target(&self) -> bool {
+        self.total >= self.target
+    }
+}
+
+/// A complete land suite: nonbasic lands, basics and the sources they give
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandSuite {
+    pub colors: Vec<Color>,
+    pub format: Format,
+    /// Scryfall format the lands were checked against
+    pub legality: Option<String>,
+    pub total_lands: u32,
+    pub nonbasics: Vec<SuggestedLand>,
+    pub basics: Vec<BasicLandCount>,
+    pub sources: Vec<ColorSources>,
+    /// Lands that always enter tapped
+    pub tapped: u32,
+    /// Cost of the nonbasic copies not in the collection
+    pub cost_usd: f64,
+    pub budget: Option<f64>,
+    /// Nonbasic lands that were eligible
+    pub candidates: u32,
+}
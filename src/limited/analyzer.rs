@@ -0,0 +1,246 @@
+use crate::combo::ComboDatabase;
+use crate::composition::{card_types, CardRole, CardType, CompositionAnalyzer};
+use crate::curve::{CurveAnalyzer, CurveArchetype, CurveFit};
+use crate::deck::{Color, Format};
+use crate::input::DeckList;
+use std::sync::Arc;
+
+use super::pool::{card_colors, PoolBuilder};
+use super::ratings::CardRatings;
+use super::types::{LimitedEvaluation, LimitedReport, RatedCard, Upgrade};
+
+/// Cards listed as the best and weakest in an evaluation
+const LISTED_CARDS: usize = 5;
+
+pub struct LimitedAnalyzer {
+    ratings: Option<CardRatings>,
+    set: Option<String>,
+    splash: bool,
+    combos: Arc<ComboDatabase>,
+}
+
+impl LimitedAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            ratings: None,
+            set: None,
+            splash: false,
+            combos: Arc::default(),
+        }
+    }
+
+    /// Rate cards with ratings loaded for `set`
+    pub fn with_ratings(ratings: CardRatings, set: Option<String>) -> Self {
+        Self {
+            ratings: Some(ratings),
+            set,
+            splash: false,
+            combos: Arc::default(),
+        }
+    }
+
+    /// Also suggest pool builds that splash a third color
+    pub fn with_splash(mut self, splash: bool) -> Self {
+        self.splash = splash;
+        self
+    }
+
+    /// Look for combos from `combos` when building from a pool
+    pub fn with_combos(mut self, combos: Arc<ComboDatabase>) -> Self {
+        self.combos = combos;
+        self
+    }
+
+    /// Number of cards in the loaded ratings
+    pub fn rating_count(&self) -> Option<usize> {
+        self.ratings.as_ref().map(|r| r.len())
+    }
+
+    /// Evaluate a built deck, or suggest builds when the decklist is a sealed pool
+    pub fn analyze(&self, deck_list: &DeckList, pool: bool) -> LimitedReport {
+        LimitedReport {
+            deck_name: deck_list.name.clone(),
+            set: self.set.clone(),
+            metric: self.ratings.as_ref().map(|r| r.metric.clone()),
+            evaluation: (!pool).then(|| self.evaluate(deck_list)),
+            builds: if pool {
+                PoolBuilder::new()
+                    .with_splash(self.splash)
+                    .with_combos(self.combos.clone())
+                    .build(deck_list, self.ratings.as_ref())
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    /// Rate a deck's cards and compare its creature, removal and land counts
+    /// and curve against Limited norms
+    pub fn evaluate(&self, deck_list: &DeckList) -> LimitedEvaluation {
+        let composition = CompositionAnalyzer::new().analyze(deck_list, Format::Limited);
+        let curve = CurveAnalyzer::new().analyze(deck_list);
+        let curve_fit = CurveFit::evaluate(&curve.buckets, CurveArchetype::Limited);
+
+        let rating = |name: &str| self.ratings.as_ref().and_then(|r| r.percentile(name));
+        let mut rated: Vec<RatedCard> = Vec::new();
+        let mut unrated: Vec<String> = Vec::new();
+        let mut deck_colors: Vec<Color> = Vec::new();
+        for entry in deck_list.mainboard() {
+            let Some(card) = &entry.card else {
+                continue;
+            };
+            if card_types(card).contains(&CardType::Land) {
+                continue;
+            }
+            for color in card_colors(card) {
+                if !deck_colors.contains(&color) {
+                    deck_colors.push(color);
+                }
+            }
+            match rating(&card.name) {
+                Some(percentile) => rated.push(RatedCard {
+                    name: card.name.clone(),
+                    quantity: entry.quantity,
+                    rating: Some(percentile),
+                    value: self.ratings.as_ref().and_then(|r| r.raw(&card.name)),
+                }),
+                None => unrated.push(card.name.clone()),
+            }
+        }
+
+        let rated_copies: u32 = rated.iter().map(|c| c.quantity).sum();
+        let average_rating = (rated_copies > 0).then(|| {
+            rated
+                .iter()
+                .filter_map(|c| c.rating.map(|r| r * c.quantity as f64))
+                .sum::<f64>()
+                / rated_copies as f64
+        });
+
+        rated.sort_by(|a, b| {
+            b.rating
+                .unwrap_or(0.0)
+                .total_cmp(&a.rating.unwrap_or(0.0))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        let best: Vec<RatedCard> = rated.iter().take(LISTED_CARDS).cloned().collect();
+        let weakest: Vec<RatedCard> = rated.iter().rev().take(LISTED_CARDS).cloned().collect();
+
+        // Sideboard cards in the deck's colors that beat the weakest maindeck cards
+        let mut sideboard: Vec<(String, f64)> = deck_list
+            .sideboard()
+            .filter_map(|e| e.card.as_ref())
+            .filter(|c| !card_types(c).contains(&CardType::Land))
+            .filter(|c| {
+                card_colors(c)
+                    .iter()
+                    .all(|color| deck_colors.contains(color))
+            })
+            .filter_map(|c| rating(&c.name).map(|r| (c.name.clone(), r)))
+            .collect();
+        sideboard.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        sideboard.dedup_by(|a, b| a.0 == b.0);
+        let upgrades = sideboard
+            .into_iter()
+            .zip(rated.iter().rev())
+            .filter_map(|((add, add_rating), cut)| {
+                let gain = add_rating - cut.rating?;
+                (gain > 0.0).then(|| Upgrade {
+                    add,
+                    cut: cut.name.clone(),
+                    gain,
+                })
+            })
+            .collect();
+
+        LimitedEvaluation {
+            total_cards: deck_list.mainboard_cards(),
+            average_rating,
+            rated_cards: rated.len() as u32,
+            unrated,
+            best,
+            weakest,
+            creatures: composition.type_count(CardType::Creature),
+            removal: composition.role_count(CardRole::Removal)
+                + composition.role_count(CardRole::Wipe),
+            lands: composition.type_count(CardType::Land),
+            comparisons: composition.comparisons,
+            curve_fit,
+            upgrades,
+        }
+    }
+}
+
+impl Default for LimitedAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{DeckSection, DeckSource};
+    use crate::testing::{card, entry};
+
+    #[test]
+    fn test_evaluates_deck_against_limited_norms() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        deck.entries.push(entry(
+            12,
+            card("Goblin")
+                .cmc(2.0)
+                .type_line("Creature — Goblin")
+                .build(),
+            DeckSection::Mainboard,
+        ));
+        deck.entries.push(entry(
+            4,
+            card("Shock")
+                .cmc(1.0)
+                .type_line("Instant")
+                .oracle("Shock deals 2 damage to any target.")
+                .build(),
+            DeckSection::Mainboard,
+        ));
+        deck.entries.push(entry(
+            7,
+            card("Ogre").cmc(4.0).type_line("Creature — Ogre").build(),
+            DeckSection::Mainboard,
+        ));
+        let mut mountain = card("Mountain")
+            .cmc(0.0)
+            .type_line("Basic Land — Mountain")
+            .build();
+        mountain.colors = Some(vec![]);
+        deck.entries
+            .push(entry(17, mountain, DeckSection::Mainboard));
+        deck.entries.push(entry(
+            1,
+            card("Dragon")
+                .cmc(5.0)
+                .type_line("Creature — Dragon")
+                .oracle("Flying")
+                .build(),
+            DeckSection::Sideboard,
+        ));
+
+        let ratings = CardRatings::from_csv(
+            "Name,GIH WR\nDragon,64%\nShock,58%\nGoblin,54%\nOgre,50%\nFiller,45%\n",
+        )
+        .unwrap();
+        let evaluation =
+            LimitedAnalyzer::with_ratings(ratings, Some("tst".to_string())).evaluate(&deck);
+
+        assert_eq!(evaluation.total_cards, 40);
+        assert_eq!(evaluation.creatures, 19);
+        assert_eq!(evaluation.removal, 4);
+        assert_eq!(evaluation.lands, 17);
+        assert_eq!(evaluation.rated_cards, 3);
+        assert_eq!(evaluation.best[0].name, "Shock");
+        assert_eq!(evaluation.weakest[0].name, "Ogre");
+        assert_eq!(evaluation.upgrades.len(), 1);
+        assert_eq!(evaluation.upgrades[0].add, "Dragon");
+        assert_eq!(evaluation.upgrades[0].cut, "Ogre");
+    }
+}
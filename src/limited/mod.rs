@@ -0,0 +1,8 @@
+pub mod analyzer;
+pub mod pool;
+pub mod ratings;
+pub mod types;
+
+pub use analyzer::*;
+pub use ratings::*;
+pub use types::*;
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::Card;
+use crate::combo::ComboDatabase;
+use crate::composition::{card_roles, card_types, CardRole, CardType};
+use crate::curve::{calculate_mana_base, CurveAnalyzer, CurveArchetype, CurveFit};
+use crate::deck::{guild_name, Algorithm, Color, Format};
+use crate::input::{DeckEntry, DeckList, DeckSection, DeckSource};
+use crate::synergy::{get_detector, SynergyDetector};
+
+use super::ratings::CardRatings;
+use super::types::{BasicLandCount, PoolBuild, RatedCard};
+
+/// Nonland cards in a 40-card Limited deck
+pub const NONLAND_SLOTS: usize = 23;
+
+/// Most off-color cards a splash may add
+const MAX_SPLASH_CARDS: usize = 3;
+
+/// Most pips of the splash color a splashed card may have
+const MAX_SPLASH_PIPS: usize = 1;
+
+/// Fewest lands that should produce the splash color
+const MIN_SPLASH_SOURCES: u32 = 3;
+
+/// Score lost per splashed card, for the risk of drawing it without its color
+const SPLASH_PENALTY: f64 = 2.0;
+
+/// Weights of card quality, curve fit and synergy density in a build's score
+const QUALITY_WEIGHT: f64 = 0.6;
+const CURVE_WEIGHT: f64 = 0.25;
+const SYNERGY_WEIGHT: f64 = 0.15;
+
+/// Synergy density that earns the full synergy share of the score
+const FULL_SYNERGY_DENSITY: f64 = 0.2;
+
+/// Colors needed to cast a card, from its colors or its faces' colors
+pub fn card_colors(card: &Card) -> Vec<Color> {
+    let symbols: Vec<&String> = match &card.colors {
+        Some(colors) => colors.iter().collect(),
+        None => card
+            .card_faces
+            .iter()
+            .flatten()
+            .flat_map(|f| f.colors.iter().flatten())
+            .collect(),
+    };
+    let mut colors: Vec<Color> = symbols
+        .into_iter()
+        .filter_map(|s| Color::from_symbol(s))
+        .collect();
+    colors.dedup();
+    colors
+}
+
+/// Quality of a card from 0 to 1: its rating percentile, or an estimate
+/// from rarity and role when it has no rating
+pub fn card_quality(card: &Card, ratings: Option<&CardRatings>) -> f64 {
+    ratings
+        .and_then(|r| r.percentile(&card.name))
+        .unwrap_or_else(|| heuristic_quality(card))
+}
+
+/// Rough quality for an unrated card: rarer cards and removal tend to be
+/// stronger picks, very expensive cards weaker ones
+fn heuristic_quality(card: &Card) -> f64 {
+    let mut quality: f64 = match card.rarity.as_str() {
+        "mythic" => 0.65,
+        "rare" => 0.6,
+        "uncommon" => 0.45,
+        _ => 0.35,
+    };
+    let roles = card_roles(card);
+    if roles.contains(&CardRole::Removal) || roles.contains(&CardRole::Wipe) {
+        quality += 0.2;
+    }
+    if roles.contains(&CardRole::Draw) {
+        quality += 0.05;
+    }
+    if card.cmc >= 6.0 {
+        quality -= 0.1;
+    }
+    quality.clamp(0.0, 1.0)
+}
+
+/// Pips of one color in a card's mana cost
+fn color_pips(card: &Card, color: Color) -> usize {
+    let symbol = format!("{{{}}}", color.symbol());
+    card.mana_cost
+        .as_deref()
+        .map_or(0, |cost| cost.matches(&symbol).count())
+}
+
+/// Builds 23-card Limited decks from a sealed or cube pool
+pub struct PoolBuilder {
+    splash: bool,
+    detector: Box<dyn SynergyDetector>,
+}
+
+impl PoolBuilder {
+    pub fn new() -> Self {
+        Self {
+            splash: false,
+            detector: get_detector(Arc::default()),
+        }
+    }
+
+    /// Look for combos from `combos` when scoring synergy
+    pub fn with_combos(mut self, combos: Arc<ComboDatabase>) -> Self {
+        self.detector = get_detector(combos);
+        self
+    }
+
+    /// Also try splashing a third color onto each pair
+    pub fn with_splash(mut self, splash: bool) -> Self {
+        self.splash = splash;
+        self
+    }
+
+    /// Candidate builds for every color pair, and splashes if enabled,
+    /// best first.
+    ///
+    /// Each build takes the highest-quality castable nonland cards, then is
+    /// scored on their average quality over the 23 slots (so shallow pairs
+    /// score lower), its curve fit to the Limited template and its synergy
+    /// density. A splash adds up to three single-pip cards that beat the
+    /// pair's weakest card, at a small cost per card.
+    pub fn build(&self, pool: &DeckList, ratings: Option<&CardRatings>) -> Vec<PoolBuild> {
+        let cards: Vec<(&Card, u32, f64)> = pool
+            .cards()
+            .filter_map(|e| e.card.as_ref().map(|c| (c, e.quantity)))
+            .filter(|(c, _)| !card_types(c).contains(&CardType::Land))
+            .map(|(c, quantity)| (c, quantity, card_quality(c, ratings)))
+            .collect();
+        let castable = |colors: &[Color]| -> Vec<(&Card, f64)> {
+            let mut castable: Vec<(&Card, f64)> = cards
+                .iter()
+                .filter(|(c, _, _)| card_colors(c).iter().all(|color| colors.contains(color)))
+                .flat_map(|(c, quantity, quality)| {
+                    std::iter::repeat_n((*c, *quality), *quantity as usize)
+                })
+                .collect();
+            castable.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+            castable
+        };
+
+        let colors = Color::all_colors();
+        let mut builds = Vec::new();
+        for (i, first) in colors.iter().enumerate() {
+            for second in &colors[i + 1..] {
+                let pair = vec![*first, *second];
+                let base = castable(&pair);
+                builds.push(self.evaluate(&pair, None, &base, ratings));
+
+                if !self.splash {
+                    continue;
+                }
+                // A splashed card has to beat the card it pushes out of the 23,
+                // or the pair's weakest card when the pair can't fill them
+                let cutoff = base
+                    .get(NONLAND_SLOTS - 1)
+                    .or(base.last())
+                    .map_or(0.0, |(_, q)| *q);
+                for splash in colors.iter().filter(|c| !pair.contains(c)) {
+                    let mut splashed_colors = pair.clone();
+                    splashed_colors.push(*splash);
+                    let splash_cards: Vec<(&Card, f64)> = castable(&splashed_colors)
+                        .into_iter()
+                        .filter(|(c, q)| {
+                            *q > cutoff
+                                && card_colors(c).contains(splash)
+                                && color_pips(c, *splash) <= MAX_SPLASH_PIPS
+                        })
+                        .take(MAX_SPLASH_CARDS)
+                        .collect();
+                    if splash_cards.is_empty() {
+                        continue;
+                    }
+                    let mut splashed = base.clone();
+                    splashed.extend(splash_cards);
+                    splashed
+                        .sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+                    builds.push(self.evaluate(&pair, Some(*splash), &splashed, ratings));
+                }
+            }
+        }
+
+        builds.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        builds
+    }
+
+    /// Score the best 23 of `castable` (sorted by quality) as one build
+    fn evaluate(
+        &self,
+        pair: &[Color],
+        splash: Option<Color>,
+        castable: &[(&Card, f64)],
+        ratings: Option<&CardRatings>,
+    ) -> PoolBuild {
+        let chosen = &castable[..castable.len().min(NONLAND_SLOTS)];
+        let quality = chosen.iter().map(|(_, q)| q).sum::<f64>() / NONLAND_SLOTS as f64;
+
+        let mut deck = DeckList::new(DeckSource::Manual);
+        for (card, _) in chosen {
+            match deck.entries.iter_mut().find(|e| e.card_name == card.name) {
+                Some(entry) => entry.quantity += 1,
+                None => deck.entries.push(DeckEntry {
+                    quantity: 1,
+                    card_name: card.name.clone(),
+                    card: Some((*card).clone()),
+                    section: DeckSection::Mainboard,
+                }),
+            }
+        }
+
+        let curve = CurveAnalyzer::new().analyze(&deck);
+        let curve_fit = CurveFit::evaluate(&curve.buckets, CurveArchetype::Limited).score;
+        let synergy_density = self.detector.analyze(&deck).stats.synergy_density;
+        let lands = land_counts(
+            calculate_mana_base(
+                &curve,
+                &deck,
+                Format::Limited.default_lands(),
+                Format::Limited,
+                Algorithm::CmcWeighted,
+            )
+            .basics,
+            splash,
+        );
+
+        let splash_cards: Vec<String> = match splash {
+            Some(splash) => chosen
+                .iter()
+                .filter(|(c, _)| card_colors(c).contains(&splash))
+                .map(|(c, _)| c.name.clone())
+                .collect(),
+            None => Vec::new(),
+        };
+        let score = 100.0
+            * (QUALITY_WEIGHT * quality
+                + CURVE_WEIGHT * curve_fit / 100.0
+                + SYNERGY_WEIGHT * (synergy_density / FULL_SYNERGY_DENSITY).min(1.0))
+            - SPLASH_PENALTY * splash_cards.len() as f64;
+
+        let has_role =
+            |card: &Card, roles: &[CardRole]| card_roles(card).iter().any(|r| roles.contains(r));
+        PoolBuild {
+            name: guild_name(pair).unwrap_or_default().to_string(),
+            colors: pair.to_vec(),
+            splash,
+            splash_cards,
+            cards: deck
+                .entries
+                .iter()
+                .map(|e| RatedCard {
+                    name: e.card_name.clone(),
+                    quantity: e.quantity,
+                    rating: ratings.and_then(|r| r.percentile(&e.card_name)),
+                    value: ratings.and_then(|r| r.raw(&e.card_name)),
+                })
+                .collect(),
+            playables: castable.len() as u32,
+            quality,
+            curve_fit,
+            synergy_density,
+            lands,
+            score,
+            creatures: chosen
+                .iter()
+                .filter(|(c, _)| card_types(c).contains(&CardType::Creature))
+                .count() as u32,
+            removal: chosen
+                .iter()
+                .filter(|(c, _)| has_role(c, &[CardRole::Removal, CardRole::Wipe]))
+                .count() as u32,
+        }
+    }
+}
+
+impl Default for PoolBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Basic land counts in WUBRG order, giving a splash color at least
+/// `MIN_SPLASH_SOURCES` lands taken from the most plentiful basic
+fn land_counts(basics: HashMap<Color, u32>, splash: Option<Color>) -> Vec<BasicLandCount> {
+    let mut lands: Vec<BasicLandCount> = Color::all_colors()
+        .into_iter()
+        .filter_map(|color| {
+            let count = basics.get(&color).copied().unwrap_or(0);
+            (count > 0 || splash == Some(color)).then_some(BasicLandCount { color, count })
+        })
+        .collect();
+
+    if let Some(splash) = splash {
+        while lands
+            .iter()
+            .any(|l| l.color == splash && l.count < MIN_SPLASH_SOURCES)
+        {
+            let Some(donor) = lands
+                .iter_mut()
+                .filter(|l| l.color != splash)
+                .max_by_key(|l| l.count)
+                .filter(|l| l.count > MIN_SPLASH_SOURCES)
+            else {
+                break;
+            };
+            donor.count -= 1;
+            if let Some(land) = lands.iter_mut().find(|l| l.color == splash) {
+                land.count += 1;
+            }
+        }
+    }
+    lands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{card, entry};
+
+    fn mock_pool() -> (DeckList, CardRatings) {
+        let mut pool = DeckList::new(DeckSource::Manual);
+        for (name, mana_cost, colors, quantity) in [
+            ("Red Bomb", "{1}{R}", vec!["R"], 6),
+            ("Green Bomb", "{1}{G}", vec!["G"], 6),
+            ("Gruul Signpost", "{R}{G}", vec!["R", "G"], 2),
+            ("White Filler", "{1}{W}", vec!["W"], 10),
+            ("Blue Filler", "{1}{U}", vec!["U"], 10),
+            ("Gold Stone", "{2}", vec![], 1),
+            ("White Splash", "{1}{W}", vec!["W"], 1),
+            ("White Heavy", "{W}{W}", vec!["W"], 1),
+        ] {
+            let card = card(name)
+                .mana_cost(mana_cost)
+                .type_line("Creature — Test")
+                .colors(&colors)
+                .build();
+            pool.entries
+                .push(entry(quantity, card, DeckSection::Mainboard));
+        }
+        let ratings = CardRatings::from_csv(
+            "Name,GIH WR\nRed Bomb,62%\nGreen Bomb,61%\nGruul Signpost,58%\n\
+             White Filler,50%\nBlue Filler,49%\nGold Stone,55%\n\
+             White Splash,64%\nWhite Heavy,65%\n",
+        )
+        .unwrap();
+        (pool, ratings)
+    }
+
+    #[test]
+    fn test_best_pair_uses_highest_rated_cards() {
+        let (pool, ratings) = mock_pool();
+        let builds = PoolBuilder::new().build(&pool, Some(&ratings));
+
+        assert_eq!(builds.len(), 10);
+        let best = &builds[0];
+        assert_eq!(best.colors, vec![Color::Red, Color::Green]);
+        assert_eq!(best.name, "Gruul");
+        assert_eq!(best.splash, None);
+        // Both colors plus gold and colorless cards are castable
+        assert_eq!(best.playables, 15);
+        assert_eq!(best.creatures, 15);
+        assert_eq!(best.cards[0].name, "Red Bomb");
+        let lands: u32 = best.lands.iter().map(|l| l.count).sum();
+        assert_eq!(lands, 17);
+        assert!(best
+            .lands
+            .iter()
+            .all(|l| l.color == Color::Red || l.color == Color::Green));
+        assert!(builds.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_splash_adds_single_pip_cards_with_sources() {
+        let (pool, ratings) = mock_pool();
+        let builds = PoolBuilder::new()
+            .with_splash(true)
+            .build(&pool, Some(&ratings));
+
+        let splash = builds
+            .iter()
+            .find(|b| b.name == "Gruul" && b.splash == Some(Color::White))
+            .unwrap();
+        // The double-pip card can't be splashed
+        assert_eq!(splash.splash_cards, vec!["White Splash".to_string()]);
+        let plains = splash
+            .lands
+            .iter()
+            .find(|l| l.color == Color::White)
+            .unwrap();
+        assert!(plains.count >= MIN_SPLASH_SOURCES);
+        let lands: u32 = splash.lands.iter().map(|l| l.count).sum();
+        assert_eq!(lands, 17);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::DeckList;
+
+/// Rating columns to use, best signal first (17lands exports plus a plain
+/// `Rating` column for hand-made files)
+const RATING_COLUMNS: [&str; 6] = ["GIH WR", "OH WR", "GP WR", "GD WR", "IWD", "Rating"];
+
+/// Per-card ratings for one set, loaded from a 17lands-style CSV.
+///
+/// Raw values (win rates, grades) aren't comparable between files, so each
+/// card is also given its percentile within the file, from 0 (worst) to 1.
+#[derive(Debug, Clone, Default)]
+pub struct CardRatings {
+    /// Column the ratings came from
+    pub metric: String,
+    raw: HashMap<String, f64>,
+    percentiles: HashMap<String, f64>,
+}
+
+impl CardRatings {
+    /// Directory searched for `<SET>.csv` rating files
+    pub fn default_dir() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("scry")
+            .join("ratings")
+    }
+
+    /// Load ratings from a CSV file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_csv(&contents)
+            .map_err(|e| format!("Failed to parse ratings {}: {}", path.display(), e))
+    }
+
+    /// Load `<SET>.csv` from the ratings directory
+    pub fn load_set(set: &str) -> Result<Self, String> {
+        Self::load(&Self::default_dir().join(format!("{}.csv", set.to_lowercase())))
+    }
+
+    /// Parse a CSV with a `Name` column and one of the known rating columns.
+    /// Percentages ("58.2%") and percentage points ("4.5pp") are accepted;
+    /// cards with an empty rating are skipped.
+    pub fn from_csv(contents: &str) -> Result<Self, String> {
+        let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+        let header = split_csv_line(lines.next().ok_or("empty file")?);
+        let column = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+
+        let name_column = column("Name").ok_or("missing Name column")?;
+        let (metric, rating_column) = RATING_COLUMNS
+            .iter()
+            .find_map(|m| column(m).map(|i| (m.to_string(), i)))
+            .ok_or_else(|| format!("missing a rating column ({})", RATING_COLUMNS.join(", ")))?;
+
+        let mut raw = HashMap::new();
+        for line in lines {
+            let fields = split_csv_line(line);
+            let (Some(name), Some(value)) = (fields.get(name_column), fields.get(rating_column))
+            else {
+                continue;
+            };
+            let value = value.trim().trim_end_matches('%').trim_end_matches("pp");
+            if let Ok(rating) = value.trim().parse::<f64>() {
+                raw.insert(name.trim().to_lowercase(), rating);
+            }
+        }
+        if raw.is_empty() {
+            return Err(format!("no ratings in the {metric} column"));
+        }
+
+        let mut sorted: Vec<f64> = raw.values().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let percentiles = raw
+            .iter()
+            .map(|(name, rating)| {
+                let below = sorted.partition_point(|r| r < rating);
+                let percentile = if sorted.len() > 1 {
+                    below as f64 / (sorted.len() - 1) as f64
+                } else {
+                    1.0
+                };
+                (name.clone(), percentile)
+            })
+            .collect();
+
+        Ok(Self {
+            metric,
+            raw,
+            percentiles,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Raw rating, matching double-faced cards by their front face too
+    pub fn raw(&self, name: &str) -> Option<f64> {
+        lookup(&self.raw, name)
+    }
+
+    /// Percentile of a card's rating within the set, 0-1
+    pub fn percentile(&self, name: &str) -> Option<f64> {
+        lookup(&self.percentiles, name)
+    }
+}
+
+fn lookup(map: &HashMap<String, f64>, name: &str) -> Option<f64> {
+    let name = name.to_lowercase();
+    map.get(&name).copied().or_else(|| {
+        let front = name.split(" // ").next()?;
+        map.get(front).copied()
+    })
+}
+
+/// Letter grade for a rating percentile
+pub fn grade(percentile: f64) -> &'static str {
+    match percentile {
+        p if p >= 0.9 => "A",
+        p if p >= 0.75 => "B",
+        p if p >= 0.5 => "C",
+        p if p >= 0.25 => "D",
+        _ => "F",
+    }
+}
+
+/// The set most of a deck's cards were printed in
+pub fn detect_set(deck_list: &DeckList) -> Option<String> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for entry in deck_list.cards() {
+        if let Some(card) = &entry.card {
+            *counts.entry(card.set.as_str()).or_insert(0) += entry.quantity;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(set, _)| set.to_lowercase())
+}
+
+/// Split one CSV line, honoring double-quoted fields and `""` escapes
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_17lands_export() {
+        let csv = "\"Name\",\"Color\",\"Rarity\",\"# GIH\",\"GIH WR\"\n\
+                   \"Lightning Strike\",\"R\",\"C\",\"1200\",\"58.1%\"\n\
+                   \"Fable of the Mirror-Breaker // Reflection of Kiki-Jikki\",\"R\",\"R\",\"300\",\"66.0%\"\n\
+                   \"Filler, the Card\",\"W\",\"C\",\"900\",\"51.5%\"\n\
+                   \"Unplayed\",\"U\",\"C\",\"0\",\"\"\n";
+        let ratings = CardRatings::from_csv(csv).unwrap();
+
+        assert_eq!(ratings.metric, "GIH WR");
+        assert_eq!(ratings.len(), 3);
+        assert_eq!(ratings.raw("lightning strike"), Some(58.1));
+        assert_eq!(
+            ratings.percentile("Fable of the Mirror-Breaker // Reflection of Kiki-Jikki"),
+            Some(1.0)
+        );
+        assert_eq!(ratings.percentile("Filler, the Card"), Some(0.0));
+        assert_eq!(ratings.percentile("Unplayed"), None);
+        assert!(CardRatings::from_csv("Name,Color\nA,W\n").is_err());
+    }
+}
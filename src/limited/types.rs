@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::composition::TargetComparison;
+use crate::curve::CurveFit;
+use crate::deck::Color;
+
+/// A card with its rating percentile within the set, if rated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatedCard {
+    pub name: String,
+    pub quantity: u32,
+    /// Percentile of the card's rating within its set, 0-1
+    pub rating: Option<f64>,
+    /// Rating as given in the ratings file, e.g. a GIH win rate
+    pub value: Option<f64>,
+}
+
+/// A sideboard card rated higher than a maindeck card it could replace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Upgrade {
+    pub add: String,
+    pub cut: String,
+    /// Difference in rating percentile
+    pub gain: f64,
+}
+
+/// A 40-card deck measured against Limited norms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitedEvaluation {
+    pub total_cards: u32,
+    /// Average rating percentile of the rated nonland cards
+    pub average_rating: Option<f64>,
+    pub rated_cards: u32,
+    /// Nonland cards missing from the ratings file
+    pub unrated: Vec<String>,
+    /// Highest-rated nonland cards
+    pub best: Vec<RatedCard>,
+    /// Lowest-rated nonland cards
+    pub weakest: Vec<RatedCard>,
+    pub creatures: u32,
+    pub removal: u32,
+    pub lands: u32,
+    /// Creature, removal, draw and land counts against the Limited template
+    pub comparisons: Vec<TargetComparison>,
+    pub curve_fit: CurveFit,
+    /// Sideboard cards in the deck's colors rated above maindeck cards
+    pub upgrades: Vec<Upgrade>,
+}
+
+/// Basic lands of one color in a suggested build
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasicLandCount {
+    pub color: Color,
+    pub count: u32,
+}
+
+/// A 23-card build from a pool in one color pair, optionally splashing a third
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolBuild {
+    pub colors: Vec<Color>,
+    /// Guild name for the pair
+    pub name: String,
+    pub splash: Option<Color>,
+    /// Cards played off the splash
+    pub splash_cards: Vec<String>,
+    pub cards: Vec<RatedCard>,
+    /// Nonland cards in the pool castable with these colors
+    pub playables: u32,
+    /// Average card quality over the deck's nonland slots, 0-1
+    pub quality: f64,
+    /// Fit to the Limited curve template, 0-100
+    pub curve_fit: f64,
+    /// Ratio of synergy edges to possible edges among the chosen cards
+    pub synergy_density: f64,
+    /// Basic lands for a 17-land mana base
+    pub lands: Vec<BasicLandCount>,
+    /// Combined quality, curve and synergy score, 0-100
+    pub score: f64,
+    pub creatures: u32,
+    pub removal: u32,
+}
+
+/// Limited analysis of a deck or a sealed pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitedReport {
+    pub deck_name: Option<String>,
+    /// Set the ratings were loaded for
+    pub set: Option<String>,
+    /// Rating column used, e.g. "GIH WR"
+    pub metric: Option<String>,
+    pub evaluation: Option<LimitedEvaluation>,
+    pub builds: Vec<PoolBuild>,
+}
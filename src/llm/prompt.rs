@@ -1,4 +1,5 @@
 use crate::input::{DeckEntry, DeckList, DeckSection};
+use crate::synergy::oracle::NormalizedOracle;
 use crate::synergy::SynergyMatrix;
 
 /// System prompt for the MTG deck analyst
@@ -300,8 +301,11 @@ fn format_card_condensed(entry: &DeckEntry) -> String {
         .map(|pt| format!(" | {pt}"))
         .unwrap_or_default();
 
-    // Oracle text - combine all faces, replace newlines
-    let oracle = card.all_oracle_text().join(" // ").replace('\n', " ");
+    // Oracle text without reminder text - combine all faces, replace newlines
+    let oracle = NormalizedOracle::new(card)
+        .face_texts()
+        .join(" // ")
+        .replace('\n', " ");
 
     if oracle.is_empty() {
         format!("{prefix} {{{cost}}} | {type_line}{pt}")
@@ -1,23 +1,51 @@
 mod api;
 mod calculator;
 mod cli;
+mod combo;
+mod composition;
+mod cube;
 mod curve;
 mod deck;
 mod export;
 mod input;
+mod lands;
+mod limited;
 mod llm;
+mod recommend;
+mod report;
+mod sections;
 mod synergy;
+mod tui;
+
+#[cfg(test)]
+mod testing;
 
 use clap::Parser;
+use cli::synergy_display::{display_error, display_warning};
 use cli::{
-    handle_card_command, handle_curve_command, handle_mana_command, handle_synergy_command,
-    print_help, Cli, Commands,
+    handle_analyze_command, handle_card_command, handle_combos_command, handle_composition_command,
+    handle_cube_command, handle_curve_command, handle_dashboard_command, handle_lands_command,
+    handle_limited_command, handle_mana_command, handle_recommend_command, handle_repl_command,
+    handle_rules_command, handle_sections_command, handle_synergy_command, print_help, Cli,
+    Commands,
 };
+use std::path::Path;
 
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
 
+    // A rules file passed with --rules must load for commands that detect
+    // themes; one picked up from the config directory only warns
+    if let Err(e) = synergy::rules::init_custom_rules(cli.rules.as_deref().map(Path::new)) {
+        let needs_rules = cli.command.as_ref().is_some_and(Commands::uses_theme_rules);
+        if cli.rules.is_some() && needs_rules {
+            display_error(&e);
+            return;
+        }
+        display_warning(&format!("Using the built-in theme rules only. {e}"));
+    }
+
     match cli.command {
         Some(Commands::Mana {
             format,
@@ -43,10 +71,12 @@ async fn main() {
             provider,
             export,
             json,
+            graph,
             verbose,
             api,
             no_fallback,
             excludes_lands,
+            watch,
         }) => {
             handle_synergy_command(
                 input,
@@ -54,28 +84,235 @@ async fn main() {
                 provider,
                 export,
                 json,
+                graph,
                 verbose,
                 api,
                 no_fallback,
                 excludes_lands,
+                watch,
             )
             .await;
         }
         Some(Commands::Curve {
             input,
             by_type,
+            effective,
             lands,
             algorithm,
+            archetype,
             export,
             json,
+            html,
             api,
             no_fallback,
             excludes_lands,
+            watch,
         }) => {
             handle_curve_command(
                 input,
                 by_type,
+                effective,
+                lands,
+                algorithm,
+                archetype,
+                export,
+                json,
+                html,
+                api,
+                no_fallback,
+                excludes_lands,
+                watch,
+            )
+            .await;
+        }
+        Some(Commands::Analyze {
+            input,
+            format,
+            lands,
+            algorithm,
+            no_curve,
+            no_mana,
+            no_synergy,
+            no_legality,
+            no_price,
+            llm,
+            provider,
+            verbose,
+            export,
+            json,
+            html,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_analyze_command(
+                input,
+                format,
+                lands,
+                algorithm,
+                no_curve,
+                no_mana,
+                no_synergy,
+                no_legality,
+                no_price,
+                llm,
+                provider,
+                verbose,
+                export,
+                json,
+                html,
+                api,
+                no_fallback,
+                excludes_lands,
+            )
+            .await;
+        }
+        Some(Commands::Composition {
+            input,
+            format,
+            verbose,
+            export,
+            json,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_composition_command(
+                input,
+                format,
+                verbose,
+                export,
+                json,
+                api,
+                no_fallback,
+                excludes_lands,
+            )
+            .await;
+        }
+        Some(Commands::Sections {
+            input,
+            format,
+            verbose,
+            export,
+            json,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_sections_command(
+                input,
+                format,
+                verbose,
+                export,
+                json,
+                api,
+                no_fallback,
+                excludes_lands,
+            )
+            .await;
+        }
+        Some(Commands::Limited {
+            input,
+            pool,
+            splash,
+            ratings,
+            set,
+            builds,
+            export,
+            json,
+            api,
+            no_fallback,
+        }) => {
+            handle_limited_command(
+                input,
+                pool,
+                splash,
+                ratings,
+                set,
+                builds,
+                export,
+                json,
+                api,
+                no_fallback,
+            )
+            .await;
+        }
+        Some(Commands::Recommend {
+            input,
+            cuts,
+            adds,
+            pool,
+            format,
+            export,
+            json,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_recommend_command(
+                input,
+                cuts,
+                adds,
+                pool,
+                format,
+                export,
+                json,
+                api,
+                no_fallback,
+                excludes_lands,
+            )
+            .await;
+        }
+        Some(Commands::Repl {
+            input,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_repl_command(input, api.to_provider(), no_fallback, excludes_lands).await;
+        }
+        Some(Commands::Dashboard {
+            input,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_dashboard_command(input, api, no_fallback, excludes_lands).await;
+        }
+        Some(Commands::Rules { action }) => {
+            handle_rules_command(action).await;
+        }
+        Some(Commands::Combos { action }) => {
+            handle_combos_command(action);
+        }
+        Some(Commands::Cube { action }) => {
+            handle_cube_command(action).await;
+        }
+        Some(Commands::Lands {
+            input,
+            colors,
+            format,
+            lands,
+            legality,
+            budget,
+            collection,
+            pool,
+            algorithm,
+            export,
+            json,
+            api,
+            no_fallback,
+            excludes_lands,
+        }) => {
+            handle_lands_command(
+                input,
+                colors,
+                format,
                 lands,
+                legality,
+                budget,
+                collection,
+                pool,
                 algorithm,
                 export,
                 json,
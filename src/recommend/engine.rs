@@ -0,0 +1,428 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::api::Card;
+use crate::combo::ComboDatabase;
+use crate::composition::templates::CompositionTemplate;
+use crate::composition::{card_roles, card_types, CardRole, CardType};
+use crate::curve::{detect_format_from_deck, BucketLoad, CurveAnalyzer, CurveArchetype, CurveFit};
+use crate::deck::Format;
+use crate::input::{DeckEntry, DeckList, DeckSection};
+use crate::report::legality::legality_format;
+use crate::synergy::keywords::extract_creature_types;
+use crate::synergy::themes::detect_card_themes;
+use crate::synergy::{get_detector, AnalysisCache, RuleBasedDetector, SynergyMatrix, Theme};
+
+use super::types::{AddSuggestion, CutSuggestion, Recommendations};
+
+/// Share of the keep score that comes from synergy, curve and role
+const SYNERGY_WEIGHT: f64 = 0.5;
+const CURVE_WEIGHT: f64 = 0.25;
+const ROLE_WEIGHT: f64 = 0.25;
+
+/// Weight of new edges outside the primary theme when ranking additions
+const OFF_THEME_EDGE_WEIGHT: f64 = 0.25;
+
+/// Candidates re-analyzed with the deck when looking for additions, picked by
+/// how strongly they match the primary theme
+const MAX_EVALUATED_CANDIDATES: usize = 150;
+
+/// Scores cards for cutting and finds additions from a card pool
+pub struct RecommendationEngine {
+    cuts: usize,
+    adds: usize,
+    combos: Arc<ComboDatabase>,
+}
+
+impl RecommendationEngine {
+    pub fn new() -> Self {
+        Self::with_counts(5, 5)
+    }
+
+    pub fn with_counts(cuts: usize, adds: usize) -> Self {
+        Self {
+            cuts,
+            adds,
+            combos: Arc::default(),
+        }
+    }
+
+    /// Look for combos from `combos` when scoring synergy
+    pub fn with_combos(mut self, combos: Arc<ComboDatabase>) -> Self {
+        self.combos = combos;
+        self
+    }
+
+    /// Analyze a hydrated deck and propose cuts, and additions from `pool`.
+    /// `format` picks the role template; it is detected from the deck if `None`.
+    pub fn recommend(
+        &self,
+        deck_list: &DeckList,
+        format: Option<Format>,
+        pool: &[Card],
+    ) -> Recommendations {
+        let format = format.unwrap_or_else(|| detect_format_from_deck(deck_list));
+        let matrix = get_detector(self.combos.clone()).analyze(deck_list);
+
+        let curve = CurveAnalyzer::new().analyze(deck_list);
+        let archetype = CurveArchetype::select(format, Some(&matrix), curve.stats.average_cmc);
+        let fit = CurveFit::evaluate(&curve.buckets, archetype);
+
+        Recommendations {
+            deck_name: deck_list.name.clone(),
+            primary_theme: matrix.primary_theme.clone(),
+            cuts: self.recommend_cuts(deck_list, &matrix, Some(&fit), format),
+            adds: self.recommend_adds(deck_list, &matrix, pool),
+            pool_size: pool.len(),
+        }
+    }
+
+    /// Score every non-land mainboard card and return the weakest.
+    ///
+    /// A card's keep score blends its synergy contribution (relative to the
+    /// deck's best-connected card), whether its CMC slot is over-loaded for the
+    /// ideal curve, and whether the roles it fills are already over the format
+    /// template. Commanders and lands are never proposed.
+    pub fn recommend_cuts(
+        &self,
+        deck_list: &DeckList,
+        matrix: &SynergyMatrix,
+        fit: Option<&CurveFit>,
+        format: Format,
+    ) -> Vec<CutSuggestion> {
+        let template = CompositionTemplate::for_format(format);
+        let mut role_counts: HashMap<CardRole, u32> = HashMap::new();
+        for entry in deck_list.mainboard() {
+            if let Some(card) = &entry.card {
+                for role in card_roles(card) {
+                    *role_counts.entry(role).or_insert(0) += entry.quantity;
+                }
+            }
+        }
+
+        let best_synergy = matrix
+            .card_profiles
+            .values()
+            .map(|p| p.synergy_score)
+            .fold(0.0, f64::max);
+
+        let mut cuts: Vec<CutSuggestion> = Vec::new();
+        for entry in deck_list.mainboard() {
+            let Some(card) = &entry.card else {
+                continue;
+            };
+            if entry.section == DeckSection::Commander
+                || card_types(card).contains(&CardType::Land)
+                || cuts.iter().any(|c| c.card == card.name)
+            {
+                continue;
+            }
+
+            let mut reasons = Vec::new();
+
+            let synergy_score = matrix
+                .card_profiles
+                .get(&card.name)
+                .map_or(0.0, |p| p.synergy_score);
+            let synergy = if best_synergy > 0.0 {
+                synergy_score / best_synergy
+            } else {
+                0.0
+            };
+            if let Some(orphan) = matrix
+                .stats
+                .orphan_cards
+                .iter()
+                .find(|o| o.name == card.name)
+            {
+                reasons.push(format!("No synergies ({})", orphan.reason));
+            } else if synergy < 0.25 {
+                reasons.push(format!(
+                    "Weak synergy ({:.0}% of the best-connected card)",
+                    synergy * 100.0
+                ));
+            }
+
+            let curve = match fit.and_then(|f| f.deviation_for(card.cmc as u32)) {
+                Some(deviation) if deviation.load == BucketLoad::Over => {
+                    let excess = deviation.actual_count as f64 - deviation.target_count;
+                    reasons.push(format!(
+                        "{}-drop slot is {:.0} over the {} curve",
+                        deviation.label(),
+                        excess.max(1.0),
+                        fit.map_or("ideal", |f| f.archetype.name())
+                    ));
+                    1.0 - (excess / deviation.actual_count.max(1) as f64).clamp(0.0, 1.0)
+                }
+                _ => 1.0,
+            };
+
+            let targeted: Vec<(CardRole, u32, u32)> = card_roles(card)
+                .into_iter()
+                .filter_map(|role| {
+                    template
+                        .role_targets
+                        .iter()
+                        .find(|(r, _, _)| *r == role)
+                        .copied()
+                })
+                .collect();
+            let count = |role: CardRole| role_counts.get(&role).copied().unwrap_or(0);
+            let role = if targeted.is_empty() {
+                0.5
+            } else if targeted.iter().any(|(r, min, _)| count(*r) <= *min) {
+                1.0
+            } else if targeted.iter().all(|(r, _, max)| count(*r) > *max) {
+                for (r, min, max) in &targeted {
+                    reasons.push(format!(
+                        "One of {} {} cards (target {min}-{max})",
+                        count(*r),
+                        r.name()
+                    ));
+                }
+                0.25
+            } else {
+                0.75
+            };
+
+            cuts.push(CutSuggestion {
+                card: card.name.clone(),
+                score: SYNERGY_WEIGHT * synergy + CURVE_WEIGHT * curve + ROLE_WEIGHT * role,
+                synergy,
+                curve,
+                role,
+                reasons,
+            });
+        }
+
+        cuts.sort_by(|a, b| {
+            a.score
+                .total_cmp(&b.score)
+                .then_with(|| a.card.cmp(&b.card))
+        });
+        cuts.truncate(self.cuts);
+        cuts
+    }
+
+    /// Find the pool cards that would add the most synergy to the primary theme.
+    ///
+    /// Candidates must be in the deck's color identity, legal in its format
+    /// and match the primary theme. The strongest matches are each scored
+    /// against the deck, and ranked by the strength of the new edges they
+    /// create, counting edges to primary-theme cards in full.
+    pub fn recommend_adds(
+        &self,
+        deck_list: &DeckList,
+        matrix: &SynergyMatrix,
+        pool: &[Card],
+    ) -> Vec<AddSuggestion> {
+        let Some(primary) = matrix.detected_themes.first() else {
+            return Vec::new();
+        };
+        let primary_cards: HashSet<&String> = primary.all_cards().into_iter().collect();
+        let identity = color_identity(deck_list);
+        let format = legality_format(deck_list);
+        let in_deck: HashSet<&str> = deck_list
+            .entries
+            .iter()
+            .map(|e| e.card_name.as_str())
+            .collect();
+
+        let mut candidates: Vec<(&Card, f64)> = pool
+            .iter()
+            .filter(|card| !in_deck.contains(card.name.as_str()))
+            .filter(|card| !card_types(card).contains(&CardType::Land))
+            .filter(|card| card.color_identity.iter().all(|c| identity.contains(c)))
+            .filter(|card| {
+                format.as_ref().is_none_or(|f| {
+                    card.legalities
+                        .get(f)
+                        .is_none_or(|status| status == "legal" || status == "restricted")
+                })
+            })
+            .filter_map(|card| theme_match(card, &primary.theme).map(|c| (card, c)))
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+        candidates.truncate(MAX_EVALUATED_CANDIDATES);
+
+        // Deck cards are analyzed once and shared by every candidate
+        let detector = RuleBasedDetector::new().with_combos(self.combos.clone());
+        let mut cache = AnalysisCache::default();
+        let mut adds: Vec<AddSuggestion> = candidates
+            .into_iter()
+            .filter_map(|(card, _)| {
+                let candidate_edges = detector.candidate_edges(deck_list, card, &mut cache);
+
+                // Edges are sorted strongest first
+                let edges: Vec<(&String, f64, &String)> = candidate_edges
+                    .iter()
+                    .map(|e| {
+                        let partner = if e.card_a == card.name {
+                            &e.card_b
+                        } else {
+                            &e.card_a
+                        };
+                        (partner, e.strength, &e.reason)
+                    })
+                    .collect();
+                if edges.is_empty() {
+                    return None;
+                }
+
+                let primary_edges = edges
+                    .iter()
+                    .filter(|(p, _, _)| primary_cards.contains(p))
+                    .count();
+                let score = edges
+                    .iter()
+                    .map(|(p, strength, _)| {
+                        if primary_cards.contains(p) {
+                            *strength
+                        } else {
+                            strength * OFF_THEME_EDGE_WEIGHT
+                        }
+                    })
+                    .sum();
+
+                Some(AddSuggestion {
+                    card: card.name.clone(),
+                    type_line: card.type_line.clone(),
+                    mana_cost: card.mana_cost.clone(),
+                    new_edges: edges.len() as u32,
+                    primary_edges: primary_edges as u32,
+                    score,
+                    partners: edges.iter().take(3).map(|(p, _, _)| (*p).clone()).collect(),
+                    reason: edges[0].2.clone(),
+                })
+            })
+            .collect();
+
+        adds.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.card.cmp(&b.card))
+        });
+        adds.truncate(self.adds);
+        adds
+    }
+}
+
+impl Default for RecommendationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The deck's color identity: its commanders', or every mainboard card's
+fn color_identity(deck_list: &DeckList) -> HashSet<String> {
+    let commanders: Vec<&DeckEntry> = deck_list.commanders().collect();
+    let entries: Vec<&DeckEntry> = if commanders.is_empty() {
+        deck_list.mainboard().collect()
+    } else {
+        commanders
+    };
+
+    entries
+        .into_iter()
+        .filter_map(|e| e.card.as_ref())
+        .flat_map(|c| c.color_identity.iter().cloned())
+        .collect()
+}
+
+/// How confidently a card matches a theme, if at all
+fn theme_match(card: &Card, theme: &Theme) -> Option<f64> {
+    if let Theme::Tribal(tribe) = theme {
+        let oracle = card.all_oracle_text().join("\n");
+        return (extract_creature_types(card).contains(tribe) || oracle.contains(tribe.as_str()))
+            .then_some(1.0);
+    }
+    detect_card_themes(card)
+        .into_iter()
+        .find(|(t, _, _)| t == theme)
+        .map(|(_, confidence, _)| confidence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::DeckSource;
+    use crate::testing::{card, entry};
+
+    fn token_deck() -> DeckList {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        let cards = [
+            ("Raise the Alarm", "Create two 1/1 white Soldier creature tokens.", "Instant", 2.0),
+            ("Spectral Procession", "Create three 1/1 white Spirit creature tokens with flying.", "Sorcery", 3.0),
+            ("Lingering Souls", "Create two 1/1 white Spirit creature tokens with flying.", "Sorcery", 2.0),
+            ("Intangible Virtue", "Creature tokens you control get +1/+1 and have vigilance.", "Enchantment", 1.0),
+            ("Anointed Procession", "If an effect would create one or more tokens under your control, it creates twice that many of those tokens instead.", "Enchantment", 4.0),
+            ("Gray Ogre", "", "Creature — Ogre", 3.0),
+        ];
+        for (name, text, type_line, cmc) in cards {
+            deck.entries.push(entry(
+                4,
+                card(name)
+                    .cmc(cmc)
+                    .type_line(type_line)
+                    .oracle(text)
+                    .build(),
+                DeckSection::Mainboard,
+            ));
+        }
+        deck
+    }
+
+    #[test]
+    fn test_cuts_the_card_without_synergy() {
+        let deck = token_deck();
+        let matrix = get_detector(Arc::new(ComboDatabase::default())).analyze(&deck);
+        let cuts = RecommendationEngine::with_counts(1, 0).recommend_cuts(
+            &deck,
+            &matrix,
+            None,
+            Format::Modern,
+        );
+
+        assert_eq!(cuts.len(), 1);
+        assert_eq!(cuts[0].card, "Gray Ogre");
+        assert!(cuts[0].reasons[0].starts_with("No synergies"));
+    }
+
+    #[test]
+    fn test_adds_on_theme_cards_in_color() {
+        let deck = token_deck();
+        let matrix = get_detector(Arc::new(ComboDatabase::default())).analyze(&deck);
+
+        let mut off_color = card("Goblin Offensive")
+            .cmc(3.0)
+            .type_line("Sorcery")
+            .oracle("Create X 1/1 red Goblin creature tokens.")
+            .build();
+        off_color.color_identity = vec!["R".to_string()];
+        let pool = vec![
+            card("Captain's Claws")
+                .cmc(1.0)
+                .type_line("Artifact — Equipment")
+                .oracle("Whenever equipped creature attacks, create a 1/1 white Kor Warrior creature token tapped and attacking.")
+                .build(),
+            card("Glorious Anthem")
+                .cmc(3.0)
+                .type_line("Enchantment")
+                .oracle("Creatures you control get +1/+1.")
+                .build(),
+            card("Raise the Alarm")
+                .cmc(2.0)
+                .type_line("Instant")
+                .oracle("Create two 1/1 white Soldier creature tokens.")
+                .build(),
+            off_color,
+        ];
+
+        let adds = RecommendationEngine::with_counts(0, 5).recommend_adds(&deck, &matrix, &pool);
+        let names: Vec<&str> = adds.iter().map(|a| a.card.as_str()).collect();
+        assert!(names.contains(&"Captain's Claws"));
+        assert!(!names.contains(&"Raise the Alarm"));
+        assert!(!names.contains(&"Goblin Offensive"));
+        assert!(adds.iter().all(|a| a.primary_edges > 0));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod engine;
+pub mod pool;
+pub mod types;
+
+pub use engine::*;
+pub use pool::*;
+pub use types::*;
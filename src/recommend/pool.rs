@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::api::cache::CardCache;
+use crate::api::Card;
+
+/// Cards to draw additions from: everything in the local card cache, plus the
+/// cards in a Scryfall bulk data file if one is given. Each name appears once.
+pub fn load_card_pool(bulk_file: Option<&Path>) -> Result<Vec<Card>, String> {
+    let mut cards = CardCache::new().cards();
+    if let Some(path) = bulk_file {
+        cards.extend(load_bulk_cards(path)?);
+    }
+
+    let mut seen = HashSet::new();
+    cards.retain(|card| seen.insert(card.name.clone()));
+    Ok(cards)
+}
+
+/// Read a Scryfall bulk data file (a JSON array of cards), skipping entries
+/// that aren't regular cards
+fn load_bulk_cards(path: &Path) -> Result<Vec<Card>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let values: Vec<serde_json::Value> = serde_json::from_str(&contents)
+        .map_err(|e| format!("Invalid Scryfall bulk data in {}: {e}", path.display()))?;
+
+    Ok(values
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect())
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::synergy::Theme;
+
+/// A card proposed for cutting, with what it contributes to the deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CutSuggestion {
+    pub card: String,
+    /// Weighted keep score, 0-1 (lower = weaker)
+    pub score: f64,
+    /// Synergy contribution relative to the deck's best card, 0-1
+    pub synergy: f64,
+    /// 1 unless the card's CMC slot is over-loaded for the ideal curve
+    pub curve: f64,
+    /// How much the deck needs the roles the card fills, 0-1
+    pub role: f64,
+    pub reasons: Vec<String>,
+}
+
+/// A card proposed for adding, with the synergies it would create
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddSuggestion {
+    pub card: String,
+    pub type_line: String,
+    pub mana_cost: Option<String>,
+    /// Synergy edges the card would add to the deck
+    pub new_edges: u32,
+    /// New edges to cards in the primary theme
+    pub primary_edges: u32,
+    /// Combined strength of the new edges, weighted toward the primary theme
+    pub score: f64,
+    /// Strongest new partners in the deck
+    pub partners: Vec<String>,
+    /// Reason for the strongest new edge
+    pub reason: String,
+}
+
+/// Cuts and additions proposed for a deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recommendations {
+    pub deck_name: Option<String>,
+    pub primary_theme: Option<Theme>,
+    pub cuts: Vec<CutSuggestion>,
+    pub adds: Vec<AddSuggestion>,
+    /// Number of cards additions were chosen from
+    pub pool_size: usize,
+}
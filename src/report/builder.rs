@@ -0,0 +1,113 @@
+use crate::combo::ComboDatabase;
+use crate::curve::{
+    calculate_mana_base, detect_format_from_deck, determine_land_count, CurveAnalyzer,
+    CurveArchetype, CurveFit,
+};
+use crate::deck::Algorithm;
+use crate::input::DeckList;
+use crate::synergy::get_detector;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::sync::Arc;
+
+use super::legality::{check_legality, legality_format};
+use super::price::price_deck;
+use super::types::DeckReport;
+
+lazy_static! {
+    /// Terminal color codes, raw or escaped as literal text
+    static ref ANSI_ESCAPE: Regex = Regex::new(r"(?:\x1b|\\x1b)\[[0-9;]*m").unwrap();
+}
+
+/// Which sections of a `DeckReport` to compute
+#[derive(Debug, Clone)]
+pub struct ReportOptions {
+    pub curve: bool,
+    /// Mana base recommendation; needs the curve
+    pub mana: bool,
+    pub synergy: bool,
+    pub legality: bool,
+    pub price: bool,
+    /// Format to check legality against, overriding the detected one
+    pub legality_format: Option<String>,
+    pub lands: Option<u32>,
+    pub algorithm: Algorithm,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            curve: true,
+            mana: true,
+            synergy: true,
+            legality: true,
+            price: true,
+            legality_format: None,
+            lands: None,
+            algorithm: Algorithm::Simple,
+        }
+    }
+}
+
+/// Analyze a hydrated deck once for every requested section, looking for
+/// combos from `combos`
+pub fn build_report(
+    deck_list: &DeckList,
+    options: &ReportOptions,
+    combos: Arc<ComboDatabase>,
+) -> DeckReport {
+    let format = detect_format_from_deck(deck_list);
+
+    // The synergy matrix also picks the curve archetype, so run it once for both
+    let matrix =
+        (options.synergy || options.curve).then(|| get_detector(combos).analyze(deck_list));
+
+    let curve = options.curve.then(|| {
+        let mut analysis = CurveAnalyzer::new().analyze(deck_list);
+        let archetype = CurveArchetype::select(format, matrix.as_ref(), analysis.stats.average_cmc);
+        analysis.curve_fit = Some(CurveFit::evaluate(&analysis.buckets, archetype));
+
+        if options.mana {
+            let (target_lands, land_source) =
+                determine_land_count(deck_list, options.lands, deck_list.excludes_lands);
+            analysis.mana_base = Some(calculate_mana_base(
+                &analysis,
+                deck_list,
+                target_lands,
+                format,
+                options.algorithm,
+            ));
+            analysis.target_lands = Some(target_lands);
+            analysis.land_source = Some(land_source);
+        }
+        analysis
+    });
+
+    let legality = options
+        .legality
+        .then(|| {
+            options
+                .legality_format
+                .clone()
+                .or_else(|| legality_format(deck_list))
+        })
+        .flatten()
+        .map(|format| check_legality(deck_list, &format));
+
+    DeckReport {
+        deck_name: deck_list.name.clone(),
+        deck_format: deck_list.format.clone(),
+        total_cards: deck_list.total_cards(),
+        unique_cards: deck_list.unique_cards() as u32,
+        curve,
+        synergy: matrix.filter(|_| options.synergy),
+        legality,
+        price: options.price.then(|| price_deck(deck_list)),
+        llm_insights: None,
+    }
+}
+
+/// Remove terminal color codes so console output can be saved in a report
+pub fn strip_ansi(text: &str) -> String {
+    ANSI_ESCAPE.replace_all(text, "").to_string()
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use crate::api::Card;
+use crate::curve::detect_format_from_deck;
+use crate::deck::Format;
+use crate::input::{DeckList, DeckSection};
+
+use super::types::{LegalityIssue, LegalityReport};
+
+/// Scryfall format keys that can be checked
+pub const LEGALITY_FORMATS: &[&str] = &[
+    "standard",
+    "pioneer",
+    "modern",
+    "legacy",
+    "vintage",
+    "pauper",
+    "commander",
+    "oathbreaker",
+    "paupercommander",
+    "duel",
+    "predh",
+    "brawl",
+    "standardbrawl",
+    "historic",
+    "timeless",
+    "explorer",
+    "alchemy",
+    "gladiator",
+    "premodern",
+    "oldschool",
+    "penny",
+];
+
+/// Formats that allow one copy of each card
+const SINGLETON_FORMATS: &[&str] = &[
+    "commander",
+    "oathbreaker",
+    "paupercommander",
+    "duel",
+    "predh",
+    "brawl",
+    "standardbrawl",
+    "gladiator",
+];
+
+/// The Scryfall format key a format name refers to, ignoring case, spaces
+/// and punctuation ("Pauper Commander" is `paupercommander`, "EDH" is
+/// `commander`). Errors with the list of valid formats for unknown names.
+pub fn parse_legality_format(name: &str) -> Result<String, String> {
+    let key: String = name
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect();
+    if key == "edh" {
+        return Ok("commander".to_string());
+    }
+    if LEGALITY_FORMATS.contains(&key.as_str()) {
+        return Ok(key);
+    }
+    Err(format!(
+        "Unknown format '{name}'. Choose one of: {}",
+        LEGALITY_FORMATS.join(", ")
+    ))
+}
+
+/// The Scryfall format key to check a deck against.
+///
+/// Uses the deck's declared format when it names one, and otherwise only
+/// assumes Commander, since a 60-card list could be for any constructed format.
+pub fn legality_format(deck: &DeckList) -> Option<String> {
+    if let Some(Ok(key)) = deck.format.as_deref().map(parse_legality_format) {
+        return Some(key);
+    }
+
+    (detect_format_from_deck(deck) == Format::Commander).then(|| "commander".to_string())
+}
+
+/// Whether a Scryfall format allows only one copy of each card
+pub fn is_singleton_format(format: &str) -> bool {
+    SINGLETON_FORMATS.contains(&format)
+}
+
+/// Whether a card's own text lifts the copy limit ("A deck can have any number
+/// of cards named ...")
+fn ignores_copy_limit(card: &Card) -> bool {
+    card.type_line.contains("Basic")
+        || card
+            .all_oracle_text()
+            .iter()
+            .any(|text| text.contains("deck can have"))
+}
+
+/// Check every card's legality and copy count in a format.
+///
+/// Maybeboard cards are ignored; sideboard copies count toward the limit.
+pub fn check_legality(deck: &DeckList, format: &str) -> LegalityReport {
+    let mut copies: BTreeMap<&str, (u32, &Card)> = BTreeMap::new();
+    for entry in deck
+        .cards()
+        .filter(|e| e.section != DeckSection::Maybeboard)
+    {
+        if let Some(card) = &entry.card {
+            copies.entry(card.name.as_str()).or_insert((0, card)).0 += entry.quantity;
+        }
+    }
+
+    let singleton = is_singleton_format(format);
+    let mut issues = Vec::new();
+    for (name, (count, card)) in copies {
+        let status = card.legalities.get(format).map(String::as_str);
+        let reason = match status {
+            Some("banned") => Some(format!("Banned in {format}")),
+            Some("not_legal") => Some(format!("Not legal in {format}")),
+            Some("restricted") if count > 1 => Some(format!(
+                "Restricted in {format}, but the deck has {count} copies"
+            )),
+            _ => None,
+        };
+        let limit = if singleton { 1 } else { 4 };
+        let reason = reason.or_else(|| {
+            (count > limit && !ignores_copy_limit(card))
+                .then(|| format!("{count} copies, but {format} allows {limit}"))
+        });
+
+        if let Some(reason) = reason {
+            issues.push(LegalityIssue {
+                card: name.to_string(),
+                reason,
+            });
+        }
+    }
+
+    LegalityReport {
+        format: format.to_string(),
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::DeckSource;
+    use crate::testing::{card, entry};
+
+    #[test]
+    fn test_check_legality() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        deck.format = Some("Modern".to_string());
+        deck.entries = vec![
+            entry(
+                4,
+                card("Lightning Bolt")
+                    .type_line("Instant")
+                    .legality("modern", "legal")
+                    .build(),
+                DeckSection::Mainboard,
+            ),
+            entry(
+                1,
+                card("Lightning Bolt")
+                    .type_line("Instant")
+                    .legality("modern", "legal")
+                    .build(),
+                DeckSection::Sideboard,
+            ),
+            entry(
+                2,
+                card("Mental Misstep")
+                    .type_line("Instant")
+                    .legality("modern", "banned")
+                    .build(),
+                DeckSection::Mainboard,
+            ),
+            entry(
+                20,
+                card("Mountain")
+                    .type_line("Basic Land — Mountain")
+                    .legality("modern", "legal")
+                    .build(),
+                DeckSection::Mainboard,
+            ),
+            entry(
+                1,
+                card("Black Lotus")
+                    .type_line("Artifact")
+                    .legality("modern", "not_legal")
+                    .build(),
+                DeckSection::Maybeboard,
+            ),
+        ];
+
+        assert_eq!(legality_format(&deck).as_deref(), Some("modern"));
+        let report = check_legality(&deck, "modern");
+        let issues: Vec<_> = report.issues.iter().map(|i| i.card.as_str()).collect();
+        assert_eq!(issues, vec!["Lightning Bolt", "Mental Misstep"]);
+        assert!(report.issues[0].reason.contains("5 copies"));
+    }
+
+    #[test]
+    fn test_parse_legality_format() {
+        assert_eq!(parse_legality_format("Modern").as_deref(), Ok("modern"));
+        assert_eq!(parse_legality_format("EDH").as_deref(), Ok("commander"));
+        assert_eq!(
+            parse_legality_format("Pauper Commander").as_deref(),
+            Ok("paupercommander")
+        );
+
+        let error = parse_legality_format("standrad").unwrap_err();
+        assert!(error.contains("Unknown format 'standrad'"));
+        assert!(error.contains("standard, pioneer"));
+    }
+}
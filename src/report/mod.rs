@@ -0,0 +1,7 @@
+pub mod builder;
+pub mod legality;
+pub mod price;
+pub mod types;
+
+pub use builder::*;
+pub use types::*;
@@ -0,0 +1,38 @@
+use crate::input::{DeckList, DeckSection};
+
+use super::types::{CardPrice, PriceReport};
+
+/// Price the deck from each card's USD price.
+///
+/// Maybeboard cards aren't counted.
+pub fn price_deck(deck: &DeckList) -> PriceReport {
+    let mut report = PriceReport::default();
+
+    for entry in deck
+        .entries
+        .iter()
+        .filter(|e| e.section != DeckSection::Maybeboard)
+    {
+        let usd = entry
+            .card
+            .as_ref()
+            .and_then(|c| c.prices.as_ref())
+            .and_then(|p| p.usd.as_deref())
+            .and_then(|usd| usd.parse::<f64>().ok());
+
+        match usd {
+            Some(unit_usd) => report.cards.push(CardPrice {
+                card: entry.card_name.clone(),
+                quantity: entry.quantity,
+                unit_usd,
+            }),
+            None => report.unpriced.push(entry.card_name.clone()),
+        }
+    }
+
+    report
+        .cards
+        .sort_by(|a, b| b.total_usd().total_cmp(&a.total_usd()));
+    report.total_usd = report.cards.iter().map(CardPrice::total_usd).sum();
+    report
+}
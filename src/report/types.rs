@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::curve::CurveAnalysis;
+use crate::synergy::SynergyMatrix;
+
+/// A card that keeps the deck from being legal in its format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegalityIssue {
+    pub card: String,
+    pub reason: String,
+}
+
+/// Format legality check for a whole deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegalityReport {
+    /// Scryfall format key, e.g. "commander" or "modern"
+    pub format: String,
+    pub issues: Vec<LegalityIssue>,
+}
+
+impl LegalityReport {
+    pub fn is_legal(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Price of one decklist entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardPrice {
+    pub card: String,
+    pub quantity: u32,
+    pub unit_usd: f64,
+}
+
+impl CardPrice {
+    pub fn total_usd(&self) -> f64 {
+        self.unit_usd * self.quantity as f64
+    }
+}
+
+/// What the deck costs to build
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriceReport {
+    pub total_usd: f64,
+    /// Priced entries, most expensive first
+    pub cards: Vec<CardPrice>,
+    /// Cards without a USD price
+    pub unpriced: Vec<String>,
+}
+
+/// Curve, mana base, synergy, legality and price analysis of one deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeckReport {
+    pub deck_name: Option<String>,
+    pub deck_format: Option<String>,
+    pub total_cards: u32,
+    pub unique_cards: u32,
+    /// Curve analysis, including the mana base unless it was skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curve: Option<CurveAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synergy: Option<SynergyMatrix>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legality: Option<LegalityReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceReport>,
+    /// LLM commentary with terminal styling removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub llm_insights: Option<String>,
+}
@@ -0,0 +1,415 @@
+use crate::api::Card;
+use crate::combo::ComboDatabase;
+use crate::composition::{card_roles, card_types, CardRole, CardType};
+use crate::curve::{CurveAnalyzer, CurveArchetype, CurveFit};
+use crate::deck::Format;
+use crate::input::{DeckEntry, DeckList, DeckSection};
+use crate::recommend::RecommendationEngine;
+use crate::synergy::{get_detector, SynergyDetector, SynergyMatrix, Theme};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::types::{
+    MaybeboardSwap, SectionAnalysis, SideboardRole, SideboardRoleCount, SideboardSummary,
+    ThemeChange,
+};
+
+lazy_static! {
+    /// Oracle patterns for sideboard roles not covered by the composition roles
+    static ref SIDEBOARD_PATTERNS: Vec<(SideboardRole, Vec<Regex>)> = vec![
+        (
+            SideboardRole::GraveyardHate,
+            vec![
+                Regex::new(r"(?i)exile (all|each|target|up to \w+ target)[^.]*\bgraveyards?\b").unwrap(),
+                Regex::new(r"(?i)\bgraveyards?\b[^.]*(can't|exile it instead)").unwrap(),
+                Regex::new(r"(?i)cards in graveyards").unwrap(),
+            ],
+        ),
+        (
+            SideboardRole::ArtifactRemoval,
+            vec![Regex::new(r"(?i)(destroy|exile)[^.]*\bartifacts?\b").unwrap()],
+        ),
+        (
+            SideboardRole::EnchantmentRemoval,
+            vec![Regex::new(r"(?i)(destroy|exile)[^.]*\benchantments?\b").unwrap()],
+        ),
+        (
+            SideboardRole::Discard,
+            vec![
+                Regex::new(r"(?i)target (player|opponent) (reveals their hand|discards)").unwrap(),
+                Regex::new(r"(?i)look at target (player|opponent)'s hand").unwrap(),
+            ],
+        ),
+        (
+            SideboardRole::LifeGain,
+            vec![Regex::new(r"(?i)you gain \w+ life").unwrap()],
+        ),
+    ];
+}
+
+pub struct SectionAnalyzer;
+
+impl SectionAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Summarize the sideboard by role and work out what each maybeboard card
+    /// would change if swapped into the mainboard, looking for combos from
+    /// `combos`
+    pub fn analyze(
+        &self,
+        deck_list: &DeckList,
+        format: Format,
+        combos: Arc<ComboDatabase>,
+    ) -> SectionAnalysis {
+        SectionAnalysis {
+            deck_name: deck_list.name.clone(),
+            format: format.name().to_string(),
+            mainboard_cards: deck_list.mainboard_cards(),
+            sideboard: summarize_sideboard(deck_list, format),
+            maybeboard: evaluate_maybeboard(deck_list, format, get_detector(combos).as_ref()),
+        }
+    }
+}
+
+impl Default for SectionAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sideboard roles a card fills; cards with no answer role count as threats
+/// if they are creatures or planeswalkers
+pub fn sideboard_roles(card: &Card) -> Vec<SideboardRole> {
+    let oracle = card.all_oracle_text().join("\n");
+    let mut roles: Vec<SideboardRole> = SIDEBOARD_PATTERNS
+        .iter()
+        .filter(|(_, patterns)| patterns.iter().any(|p| p.is_match(&oracle)))
+        .map(|(role, _)| *role)
+        .collect();
+
+    for role in card_roles(card) {
+        let mapped = match role {
+            // Artifact and enchantment answers also match the removal rules
+            CardRole::Removal
+                if !roles.contains(&SideboardRole::ArtifactRemoval)
+                    && !roles.contains(&SideboardRole::EnchantmentRemoval) =>
+            {
+                SideboardRole::CreatureRemoval
+            }
+            CardRole::Wipe => SideboardRole::BoardWipe,
+            CardRole::Counterspell => SideboardRole::Counterspell,
+            CardRole::Protection => SideboardRole::Protection,
+            _ => continue,
+        };
+        if !roles.contains(&mapped) {
+            roles.push(mapped);
+        }
+    }
+
+    if roles.is_empty() {
+        let types = card_types(card);
+        roles.push(
+            if types.contains(&CardType::Creature) || types.contains(&CardType::Planeswalker) {
+                SideboardRole::Threat
+            } else {
+                SideboardRole::Other
+            },
+        );
+    }
+    roles
+}
+
+/// Count sideboard cards by role, or `None` without a sideboard
+fn summarize_sideboard(deck_list: &DeckList, format: Format) -> Option<SideboardSummary> {
+    let entries: Vec<&DeckEntry> = deck_list.sideboard().collect();
+    if entries.is_empty() {
+        return None;
+    }
+
+    let mut role_cards: HashMap<SideboardRole, (u32, Vec<String>)> = HashMap::new();
+    for entry in &entries {
+        let Some(card) = &entry.card else {
+            continue;
+        };
+        for role in sideboard_roles(card) {
+            let (count, names) = role_cards.entry(role).or_default();
+            *count += entry.quantity;
+            names.push(card.name.clone());
+        }
+    }
+
+    let mut roles: Vec<SideboardRoleCount> = SideboardRole::all()
+        .into_iter()
+        .filter_map(|role| {
+            let (count, cards) = role_cards.remove(&role)?;
+            Some(SideboardRoleCount { role, count, cards })
+        })
+        .collect();
+    // Stable sort keeps the role order for ties
+    roles.sort_by_key(|r| std::cmp::Reverse(r.count));
+
+    Some(SideboardSummary {
+        total_cards: entries.iter().map(|e| e.quantity).sum(),
+        max_size: format.max_sideboard(),
+        roles,
+    })
+}
+
+/// Headline numbers compared before and after a swap
+struct Snapshot {
+    matrix: SynergyMatrix,
+    average_cmc: f64,
+    curve_fit: f64,
+}
+
+impl Snapshot {
+    fn new(
+        deck_list: &DeckList,
+        archetype: CurveArchetype,
+        detector: &dyn SynergyDetector,
+    ) -> Self {
+        let curve = CurveAnalyzer::new().analyze(deck_list);
+        Self {
+            matrix: detector.analyze(deck_list),
+            average_cmc: curve.stats.average_cmc,
+            curve_fit: CurveFit::evaluate(&curve.buckets, archetype).score,
+        }
+    }
+
+    fn theme_count(&self, theme: &Theme) -> u32 {
+        self.matrix
+            .detected_themes
+            .iter()
+            .find(|t| &t.theme == theme)
+            .map_or(0, |t| t.card_count)
+    }
+}
+
+/// Swap each maybeboard card in for the weakest mainboard card and compare
+/// themes, synergy and curve with the current mainboard
+fn evaluate_maybeboard(
+    deck_list: &DeckList,
+    format: Format,
+    detector: &dyn SynergyDetector,
+) -> Vec<MaybeboardSwap> {
+    let candidates: Vec<&Card> = deck_list
+        .maybeboard()
+        .filter_map(|e| e.card.as_ref())
+        .collect();
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+
+    let matrix = detector.analyze(deck_list);
+    let curve = CurveAnalyzer::new().analyze(deck_list);
+    let archetype = CurveArchetype::select(format, Some(&matrix), curve.stats.average_cmc);
+    let fit = CurveFit::evaluate(&curve.buckets, archetype);
+    let swap_out = RecommendationEngine::with_counts(1, 0)
+        .recommend_cuts(deck_list, &matrix, Some(&fit), format)
+        .into_iter()
+        .next()
+        .map(|cut| cut.card);
+    let before = Snapshot {
+        matrix,
+        average_cmc: curve.stats.average_cmc,
+        curve_fit: fit.score,
+    };
+
+    candidates
+        .into_iter()
+        .map(|card| {
+            let trial = swap_in(deck_list, card, swap_out.as_deref());
+            let after = Snapshot::new(&trial, archetype, detector);
+
+            let mut themes: Vec<&Theme> = Vec::new();
+            for analysis in before
+                .matrix
+                .detected_themes
+                .iter()
+                .chain(after.matrix.detected_themes.iter())
+            {
+                if !themes.contains(&&analysis.theme) {
+                    themes.push(&analysis.theme);
+                }
+            }
+            let theme_changes = themes
+                .into_iter()
+                .map(|theme| ThemeChange {
+                    theme: theme.clone(),
+                    before: before.theme_count(theme),
+                    after: after.theme_count(theme),
+                })
+                .filter(|change| change.before != change.after)
+                .collect();
+
+            MaybeboardSwap {
+                card: card.name.clone(),
+                swap_out: swap_out.clone(),
+                theme_changes,
+                new_edges: after
+                    .matrix
+                    .edges
+                    .iter()
+                    .filter(|e| e.card_a == card.name || e.card_b == card.name)
+                    .count() as u32,
+                synergy_score_change: after.matrix.stats.synergy_score
+                    - before.matrix.stats.synergy_score,
+                average_cmc_change: after.average_cmc - before.average_cmc,
+                curve_fit_change: after.curve_fit - before.curve_fit,
+            }
+        })
+        .collect()
+}
+
+/// Copy of the deck with one copy of `card` moved into the mainboard in place
+/// of one copy of `swap_out`
+fn swap_in(deck_list: &DeckList, card: &Card, swap_out: Option<&str>) -> DeckList {
+    let mut trial = deck_list.clone();
+    trial
+        .entries
+        .retain(|e| e.section != DeckSection::Maybeboard);
+
+    if let Some(name) = swap_out {
+        if let Some(entry) = trial
+            .entries
+            .iter_mut()
+            .find(|e| e.section == DeckSection::Mainboard && e.card_name == name)
+        {
+            entry.quantity -= 1;
+        }
+        trial.entries.retain(|e| e.quantity > 0);
+    }
+
+    match trial
+        .entries
+        .iter_mut()
+        .find(|e| e.section == DeckSection::Mainboard && e.card_name == card.name)
+    {
+        Some(entry) => entry.quantity += 1,
+        None => trial.entries.push(DeckEntry {
+            quantity: 1,
+            card_name: card.name.clone(),
+            card: Some(card.clone()),
+            section: DeckSection::Mainboard,
+        }),
+    }
+    trial
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::DeckSource;
+    use crate::testing::{card, entry};
+
+    #[test]
+    fn test_sideboard_roles_and_size() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        deck.entries.push(entry(
+            4,
+            card("Rest in Peace")
+                .type_line("Enchantment")
+                .oracle("When Rest in Peace enters, exile all graveyards.\nIf a card or token would be put into a graveyard from anywhere, exile it instead.")
+                .build(),
+            DeckSection::Sideboard,
+        ));
+        deck.entries.push(entry(
+            4,
+            card("Naturalize")
+                .type_line("Instant")
+                .oracle("Destroy target artifact or enchantment.")
+                .build(),
+            DeckSection::Sideboard,
+        ));
+        deck.entries.push(entry(
+            4,
+            card("Negate")
+                .type_line("Instant")
+                .oracle("Counter target noncreature spell.")
+                .build(),
+            DeckSection::Sideboard,
+        ));
+        deck.entries.push(entry(
+            4,
+            card("Grizzly Bears").type_line("Creature — Bear").build(),
+            DeckSection::Sideboard,
+        ));
+
+        let summary = summarize_sideboard(&deck, Format::Modern).unwrap();
+        assert_eq!(summary.total_cards, 16);
+        assert!(summary.is_oversized());
+
+        let role = |role: SideboardRole| {
+            summary
+                .roles
+                .iter()
+                .find(|r| r.role == role)
+                .map(|r| r.cards.clone())
+                .unwrap_or_default()
+        };
+        assert_eq!(role(SideboardRole::GraveyardHate), vec!["Rest in Peace"]);
+        assert_eq!(role(SideboardRole::ArtifactRemoval), vec!["Naturalize"]);
+        assert_eq!(role(SideboardRole::EnchantmentRemoval), vec!["Naturalize"]);
+        assert!(role(SideboardRole::CreatureRemoval).is_empty());
+        assert_eq!(role(SideboardRole::Counterspell), vec!["Negate"]);
+        assert_eq!(role(SideboardRole::Threat), vec!["Grizzly Bears"]);
+
+        assert!(summarize_sideboard(&deck, Format::Commander).is_some_and(|s| !s.is_oversized()));
+    }
+
+    #[test]
+    fn test_maybeboard_swap_changes_themes() {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        for (name, text) in [
+            (
+                "Raise the Alarm",
+                "Create two 1/1 white Soldier creature tokens.",
+            ),
+            (
+                "Spectral Procession",
+                "Create three 1/1 white Spirit creature tokens with flying.",
+            ),
+            (
+                "Lingering Souls",
+                "Create two 1/1 white Spirit creature tokens with flying.",
+            ),
+            (
+                "Intangible Virtue",
+                "Creature tokens you control get +1/+1 and have vigilance.",
+            ),
+        ] {
+            deck.entries.push(entry(
+                1,
+                card(name).type_line("Sorcery").oracle(text).build(),
+                DeckSection::Mainboard,
+            ));
+        }
+        deck.entries.push(entry(
+            1,
+            card("Gray Ogre").type_line("Creature — Ogre").build(),
+            DeckSection::Mainboard,
+        ));
+        deck.entries.push(entry(
+            1,
+            card("Secure the Wastes")
+                .type_line("Instant")
+                .oracle("Create X 1/1 white Warrior creature tokens.")
+                .build(),
+            DeckSection::Maybeboard,
+        ));
+
+        let detector = get_detector(Arc::new(ComboDatabase::default()));
+        let swaps = evaluate_maybeboard(&deck, Format::Modern, detector.as_ref());
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(swaps[0].swap_out.as_deref(), Some("Gray Ogre"));
+        assert!(swaps[0].new_edges > 0);
+        assert!(swaps[0]
+            .theme_changes
+            .iter()
+            .any(|c| c.theme == Theme::Tokens && c.after > c.before));
+    }
+}
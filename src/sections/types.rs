@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::synergy::Theme;
+
+/// What a sideboard card is brought in to answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SideboardRole {
+    GraveyardHate,
+    ArtifactRemoval,
+    EnchantmentRemoval,
+    CreatureRemoval,
+    BoardWipe,
+    Counterspell,
+    Discard,
+    LifeGain,
+    Protection,
+    Threat,
+    Other,
+}
+
+impl SideboardRole {
+    pub fn all() -> Vec<SideboardRole> {
+        vec![
+            SideboardRole::GraveyardHate,
+            SideboardRole::ArtifactRemoval,
+            SideboardRole::EnchantmentRemoval,
+            SideboardRole::CreatureRemoval,
+            SideboardRole::BoardWipe,
+            SideboardRole::Counterspell,
+            SideboardRole::Discard,
+            SideboardRole::LifeGain,
+            SideboardRole::Protection,
+            SideboardRole::Threat,
+            SideboardRole::Other,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SideboardRole::GraveyardHate => "Graveyard Hate",
+            SideboardRole::ArtifactRemoval => "Artifact Removal",
+            SideboardRole::EnchantmentRemoval => "Enchantment Removal",
+            SideboardRole::CreatureRemoval => "Creature Removal",
+            SideboardRole::BoardWipe => "Board Wipes",
+            SideboardRole::Counterspell => "Counterspells",
+            SideboardRole::Discard => "Discard",
+            SideboardRole::LifeGain => "Life Gain",
+            SideboardRole::Protection => "Protection",
+            SideboardRole::Threat => "Threats",
+            SideboardRole::Other => "Other",
+        }
+    }
+}
+
+impl fmt::Display for SideboardRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Sideboard cards filling a single role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SideboardRoleCount {
+    pub role: SideboardRole,
+    pub count: u32,
+    pub cards: Vec<String>,
+}
+
+/// Sideboard broken down by role, with a size check for Constructed formats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SideboardSummary {
+    pub total_cards: u32,
+    /// Largest sideboard the format allows, if it limits sideboards
+    pub max_size: Option<u32>,
+    /// Roles with at least one card, most cards first
+    pub roles: Vec<SideboardRoleCount>,
+}
+
+impl SideboardSummary {
+    pub fn is_oversized(&self) -> bool {
+        self.max_size.is_some_and(|max| self.total_cards > max)
+    }
+}
+
+/// A theme's card count before and after a swap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeChange {
+    pub theme: Theme,
+    pub before: u32,
+    pub after: u32,
+}
+
+/// What swapping one maybeboard card into the mainboard would change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaybeboardSwap {
+    pub card: String,
+    /// Mainboard card taken out to make room, the weakest by keep score
+    pub swap_out: Option<String>,
+    /// Themes whose card count would change
+    pub theme_changes: Vec<ThemeChange>,
+    /// Synergy edges the card would have in the mainboard
+    pub new_edges: u32,
+    pub synergy_score_change: f64,
+    pub average_cmc_change: f64,
+    pub curve_fit_change: f64,
+}
+
+/// Sideboard and maybeboard analysis for a deck
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionAnalysis {
+    pub deck_name: Option<String>,
+    /// Format whose sideboard rules and curve template were used
+    pub format: String,
+    pub mainboard_cards: u32,
+    pub sideboard: Option<SideboardSummary>,
+    pub maybeboard: Vec<MaybeboardSwap>,
+}
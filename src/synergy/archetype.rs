@@ -0,0 +1,541 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+use crate::composition::{card_roles, CardRole};
+use crate::curve::CurveAnalyzer;
+use crate::input::DeckList;
+
+use super::keywords::{is_aura, is_equipment, is_instant_or_sorcery, is_land};
+use super::types::{ArchetypeScore, CardSynergyProfile, Keyword, Theme};
+
+/// Scores below this aren't reported as the deck's archetype
+pub const MIN_ARCHETYPE_SCORE: f64 = 0.4;
+
+lazy_static! {
+    /// Effects that tax or lock opponents
+    static ref STAX_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)(don't|doesn't) untap during").unwrap(),
+        Regex::new(r"(?i)spells? (your opponents cast )?costs? \{\d+\} more").unwrap(),
+        Regex::new(r"(?i)(players|opponents|each player) can't").unwrap(),
+        Regex::new(r"(?i)can't cast more than one spell").unwrap(),
+        Regex::new(r"(?i)enters? (the battlefield )?tapped").unwrap(),
+        Regex::new(r"(?i)activated abilities of [^.]* can't be activated").unwrap(),
+    ];
+
+    /// Rituals and cost reducers that fuel storm turns
+    static ref STORM_FUEL_PATTERNS: Vec<Regex> = vec![
+        Regex::new(r"(?i)add \{[WUBRGC]\}\{[WUBRGC]\}\{[WUBRGC]\}").unwrap(),
+        Regex::new(r"(?i)add three mana").unwrap(),
+        Regex::new(r"(?i)(instant and sorcery|instant or sorcery) spells you cast cost \{\d+\} less").unwrap(),
+        Regex::new(r"(?i)copy (it|that spell) for each").unwrap(),
+    ];
+}
+
+/// Deck-wide signals the archetype scores are built from.
+///
+/// Card counts include quantities; ratios are over nonland cards.
+#[derive(Debug, Default)]
+struct DeckFeatures {
+    nonland: f64,
+    average_cmc: f64,
+    creature_ratio: f64,
+    cheap_ratio: f64,
+    spell_ratio: f64,
+    interaction: f64,
+    sweepers_and_counters: f64,
+    draw: f64,
+    aggressive_keywords: f64,
+    protection_keywords: f64,
+    equipment_and_auras: f64,
+    stax_pieces: f64,
+    storm_cards: f64,
+    storm_fuel: f64,
+    theme_counts: HashMap<Theme, f64>,
+}
+
+impl DeckFeatures {
+    fn collect(deck: &DeckList, profiles: &HashMap<String, CardSynergyProfile>) -> Self {
+        let curve = CurveAnalyzer::new().analyze(deck);
+        let nonland = curve.stats.total_nonland_cards as f64;
+        let cheap: u32 = curve
+            .buckets
+            .iter()
+            .filter(|b| b.cmc <= 2)
+            .map(|b| b.total_count)
+            .sum();
+
+        let mut features = Self {
+            nonland,
+            average_cmc: curve.stats.average_cmc,
+            creature_ratio: curve.stats.total_creatures as f64 / nonland.max(1.0),
+            cheap_ratio: cheap as f64 / nonland.max(1.0),
+            ..Self::default()
+        };
+
+        let mut spells = 0.0;
+        for entry in deck.mainboard() {
+            let Some(card) = &entry.card else {
+                continue;
+            };
+            if is_land(card) {
+                continue;
+            }
+            let quantity = entry.quantity as f64;
+            let oracle = card.all_oracle_text().join("\n");
+
+            if is_instant_or_sorcery(card) {
+                spells += quantity;
+            }
+            if is_equipment(card) || is_aura(card) {
+                features.equipment_and_auras += quantity;
+            }
+
+            let roles = card_roles(card);
+            if roles.iter().any(|r| {
+                matches!(
+                    r,
+                    CardRole::Removal | CardRole::Wipe | CardRole::Counterspell
+                )
+            }) {
+                features.interaction += quantity;
+            }
+            if roles
+                .iter()
+                .any(|r| matches!(r, CardRole::Wipe | CardRole::Counterspell))
+            {
+                features.sweepers_and_counters += quantity;
+            }
+            if roles.contains(&CardRole::Draw) {
+                features.draw += quantity;
+            }
+
+            if STAX_PATTERNS.iter().any(|p| p.is_match(&oracle)) {
+                features.stax_pieces += quantity;
+            }
+            if STORM_FUEL_PATTERNS.iter().any(|p| p.is_match(&oracle)) {
+                features.storm_fuel += quantity;
+            }
+
+            if let Some(profile) = profiles.get(&card.name) {
+                if profile.keywords.iter().any(|k| {
+                    matches!(
+                        k,
+                        Keyword::Haste
+                            | Keyword::Menace
+                            | Keyword::Trample
+                            | Keyword::FirstStrike
+                            | Keyword::DoubleStrike
+                            | Keyword::Flying
+                    )
+                }) {
+                    features.aggressive_keywords += quantity;
+                }
+                if profile.keywords.iter().any(|k| {
+                    matches!(
+                        k,
+                        Keyword::Hexproof | Keyword::Indestructible | Keyword::Ward
+                    )
+                }) || profile.granted_keywords.iter().any(|k| {
+                    matches!(
+                        k,
+                        Keyword::Hexproof
+                            | Keyword::Indestructible
+                            | Keyword::Shroud
+                            | Keyword::Protection
+                    )
+                }) {
+                    features.protection_keywords += quantity;
+                }
+                if profile.keywords.contains(&Keyword::Storm) {
+                    features.storm_cards += quantity;
+                }
+                for theme in &profile.themes {
+                    *features.theme_counts.entry(theme.clone()).or_insert(0.0) += quantity;
+                }
+            }
+        }
+        features.spell_ratio = spells / nonland.max(1.0);
+
+        features
+    }
+
+    fn ratio(&self, count: f64) -> f64 {
+        count / self.nonland.max(1.0)
+    }
+
+    fn theme_count(&self, theme: &Theme) -> f64 {
+        self.theme_counts.get(theme).copied().unwrap_or(0.0)
+    }
+}
+
+/// Map `value` onto 0-1, reaching 0 at `low` and 1 at `high`
+fn scale(value: f64, low: f64, high: f64) -> f64 {
+    ((value - low) / (high - low)).clamp(0.0, 1.0)
+}
+
+/// Accumulates weighted signals into a score, keeping the evidence for
+/// signals that are clearly present
+struct ScoreBuilder {
+    theme: Theme,
+    score: f64,
+    evidence: Vec<String>,
+}
+
+impl ScoreBuilder {
+    fn new(theme: Theme) -> Self {
+        Self {
+            theme,
+            score: 0.0,
+            evidence: Vec::new(),
+        }
+    }
+
+    fn signal(mut self, weight: f64, strength: f64, evidence: String) -> Self {
+        self.score += weight * strength;
+        if strength >= 0.5 {
+            self.evidence.push(evidence);
+        }
+        self
+    }
+
+    fn build(self) -> ArchetypeScore {
+        ArchetypeScore {
+            theme: self.theme,
+            score: self.score.clamp(0.0, 1.0),
+            evidence: self.evidence,
+        }
+    }
+}
+
+fn percent(ratio: f64) -> String {
+    format!("{:.0}%", ratio * 100.0)
+}
+
+/// Score every strategy archetype for a deck, most likely first
+pub fn classify_archetypes(
+    deck: &DeckList,
+    profiles: &HashMap<String, CardSynergyProfile>,
+) -> Vec<ArchetypeScore> {
+    let f = DeckFeatures::collect(deck, profiles);
+    if f.nonland == 0.0 {
+        return Vec::new();
+    }
+
+    let mut scores = vec![
+        ScoreBuilder::new(Theme::Aggro)
+            .signal(
+                0.35,
+                scale(f.creature_ratio, 0.35, 0.6),
+                format!(
+                    "{} of nonland cards are creatures",
+                    percent(f.creature_ratio)
+                ),
+            )
+            .signal(
+                0.3,
+                scale(3.5 - f.average_cmc, 0.5, 1.5),
+                format!("Low average mana value ({:.2})", f.average_cmc),
+            )
+            .signal(
+                0.2,
+                scale(f.ratio(f.aggressive_keywords), 0.1, 0.3),
+                format!(
+                    "{:.0} cards with haste, evasion or combat keywords",
+                    f.aggressive_keywords
+                ),
+            )
+            .signal(
+                0.15,
+                scale(f.cheap_ratio, 0.3, 0.55),
+                format!("{} of spells cost 2 or less", percent(f.cheap_ratio)),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Control)
+            .signal(
+                0.4,
+                scale(f.ratio(f.interaction), 0.15, 0.35),
+                format!(
+                    "{:.0} removal spells, counterspells and wipes",
+                    f.interaction
+                ),
+            )
+            .signal(
+                0.2,
+                scale(f.ratio(f.draw), 0.08, 0.2),
+                format!("{:.0} card draw sources", f.draw),
+            )
+            .signal(
+                0.2,
+                scale(1.0 - f.creature_ratio, 0.6, 0.85),
+                format!(
+                    "Only {} of nonland cards are creatures",
+                    percent(f.creature_ratio)
+                ),
+            )
+            .signal(
+                0.2,
+                scale(f.sweepers_and_counters, 2.0, 6.0),
+                format!(
+                    "{:.0} board wipes and counterspells",
+                    f.sweepers_and_counters
+                ),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Midrange)
+            .signal(
+                0.4,
+                1.0 - scale((f.average_cmc - 3.0).abs(), 0.3, 1.0),
+                format!("Mid-range average mana value ({:.2})", f.average_cmc),
+            )
+            .signal(
+                0.3,
+                1.0 - scale((f.creature_ratio - 0.4).abs(), 0.05, 0.25),
+                format!("Balanced creature count ({})", percent(f.creature_ratio)),
+            )
+            .signal(
+                0.3,
+                scale(f.ratio(f.interaction), 0.08, 0.2),
+                format!("{:.0} interaction spells", f.interaction),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Stax)
+            .signal(
+                1.0,
+                scale(f.stax_pieces, 1.0, 5.0),
+                format!("{:.0} tax or lock pieces", f.stax_pieces),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Voltron)
+            .signal(
+                0.5,
+                scale(f.equipment_and_auras, 4.0, 10.0),
+                format!("{:.0} Equipment and Auras", f.equipment_and_auras),
+            )
+            .signal(
+                0.3,
+                scale(f.ratio(f.theme_count(&Theme::Voltron)), 0.08, 0.2),
+                format!(
+                    "{:.0} cards that suit up a single threat",
+                    f.theme_count(&Theme::Voltron)
+                ),
+            )
+            .signal(
+                0.2,
+                scale(f.protection_keywords, 2.0, 6.0),
+                format!("{:.0} protection effects", f.protection_keywords),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Spellslinger)
+            .signal(
+                0.5,
+                scale(f.spell_ratio, 0.3, 0.55),
+                format!(
+                    "{} of nonland cards are instants or sorceries",
+                    percent(f.spell_ratio)
+                ),
+            )
+            .signal(
+                0.5,
+                scale(f.ratio(f.theme_count(&Theme::Spellslinger)), 0.03, 0.1),
+                format!(
+                    "{:.0} cards that reward casting spells",
+                    f.theme_count(&Theme::Spellslinger)
+                ),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Aristocrats)
+            .signal(
+                0.5,
+                scale(f.ratio(f.theme_count(&Theme::Aristocrats)), 0.03, 0.1),
+                format!(
+                    "{:.0} death-trigger payoffs",
+                    f.theme_count(&Theme::Aristocrats)
+                ),
+            )
+            .signal(
+                0.5,
+                scale(f.ratio(f.theme_count(&Theme::Sacrifice)), 0.05, 0.15),
+                format!(
+                    "{:.0} sacrifice outlets and fodder",
+                    f.theme_count(&Theme::Sacrifice)
+                ),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Reanimator)
+            .signal(
+                0.5,
+                scale(f.ratio(f.theme_count(&Theme::Reanimator)), 0.02, 0.08),
+                format!(
+                    "{:.0} reanimation spells",
+                    f.theme_count(&Theme::Reanimator)
+                ),
+            )
+            .signal(
+                0.3,
+                scale(f.ratio(f.theme_count(&Theme::Graveyard)), 0.08, 0.2),
+                format!("{:.0} graveyard cards", f.theme_count(&Theme::Graveyard)),
+            )
+            .signal(
+                0.2,
+                scale(
+                    f.ratio(f.theme_count(&Theme::Discard) + f.theme_count(&Theme::Mill)),
+                    0.03,
+                    0.1,
+                ),
+                format!(
+                    "{:.0} discard and self-mill enablers",
+                    f.theme_count(&Theme::Discard) + f.theme_count(&Theme::Mill)
+                ),
+            )
+            .build(),
+        ScoreBuilder::new(Theme::Storm)
+            .signal(
+                0.5,
+                scale(f.storm_cards, 1.0, 3.0),
+                format!("{:.0} cards with storm", f.storm_cards),
+            )
+            .signal(
+                0.3,
+                scale(f.storm_fuel, 3.0, 8.0),
+                format!("{:.0} rituals and spell cost reducers", f.storm_fuel),
+            )
+            .signal(
+                0.2,
+                scale(f.spell_ratio, 0.35, 0.6),
+                format!("{} instants and sorceries", percent(f.spell_ratio)),
+            )
+            .build(),
+    ];
+
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Card;
+    use crate::input::{DeckSection, DeckSource};
+    use crate::synergy::{RuleBasedDetector, SynergyDetector};
+    use crate::testing::{card, entry};
+
+    fn deck(cards: Vec<(u32, Card)>) -> DeckList {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        for (quantity, card) in cards {
+            deck.entries
+                .push(entry(quantity, card, DeckSection::Mainboard));
+        }
+        deck
+    }
+
+    #[test]
+    fn test_aggro_deck() {
+        let deck = deck(vec![
+            (
+                4,
+                card("Goblin Guide")
+                    .cmc(1.0)
+                    .type_line("Creature — Goblin Scout")
+                    .oracle("Haste")
+                    .build(),
+            ),
+            (
+                4,
+                card("Monastery Swiftspear")
+                    .cmc(1.0)
+                    .type_line("Creature — Human Monk")
+                    .oracle("Haste\nProwess")
+                    .build(),
+            ),
+            (
+                4,
+                card("Kiln Fiend")
+                    .cmc(2.0)
+                    .type_line("Creature — Elemental Beast")
+                    .oracle("Menace")
+                    .build(),
+            ),
+            (
+                4,
+                card("Bloodghast")
+                    .cmc(2.0)
+                    .type_line("Creature — Vampire Spirit")
+                    .oracle("Haste")
+                    .build(),
+            ),
+            (
+                4,
+                card("Lightning Bolt")
+                    .cmc(1.0)
+                    .type_line("Instant")
+                    .oracle("Lightning Bolt deals 3 damage to any target.")
+                    .build(),
+            ),
+            (
+                20,
+                card("Mountain")
+                    .cmc(0.0)
+                    .type_line("Basic Land — Mountain")
+                    .build(),
+            ),
+        ]);
+        let matrix = RuleBasedDetector::new().analyze(&deck);
+
+        let top = &matrix.archetypes[0];
+        assert_eq!(top.theme, Theme::Aggro);
+        assert!(top.score >= MIN_ARCHETYPE_SCORE);
+        assert!(top.evidence.iter().any(|e| e.contains("creatures")));
+    }
+
+    #[test]
+    fn test_control_deck() {
+        let deck = deck(vec![
+            (4, card("Counterspell")
+                .cmc(2.0)
+                .type_line("Instant")
+                .oracle("Counter target spell.")
+                .build()),
+            (4, card("Wrath of God")
+                .cmc(4.0)
+                .type_line("Sorcery")
+                .oracle("Destroy all creatures. They can't be regenerated.")
+                .build()),
+            (4, card("Doom Blade")
+                .cmc(2.0)
+                .type_line("Instant")
+                .oracle("Destroy target nonblack creature.")
+                .build()),
+            (4, card("Divination")
+                .cmc(3.0)
+                .type_line("Sorcery")
+                .oracle("Draw two cards.")
+                .build()),
+            (2, card("Dream Trawler")
+                .cmc(4.0)
+                .type_line("Creature — Sphinx")
+                .oracle("Flying, lifelink\nWhenever you draw a card, Dream Trawler gets +1/+0 until end of turn.")
+                .build()),
+            (22, card("Island")
+                .cmc(0.0)
+                .type_line("Basic Land — Island")
+                .build()),
+        ]);
+        let matrix = RuleBasedDetector::new().analyze(&deck);
+
+        assert_eq!(matrix.archetypes[0].theme, Theme::Control);
+        let aggro = matrix
+            .archetypes
+            .iter()
+            .find(|a| a.theme == Theme::Aggro)
+            .unwrap();
+        assert!(aggro.score < matrix.archetypes[0].score);
+    }
+
+    #[test]
+    fn test_empty_deck_has_no_archetype() {
+        let matrix = RuleBasedDetector::new().analyze(&DeckList::new(DeckSource::Manual));
+        assert!(matrix.archetypes.is_empty());
+        assert!(matrix.likely_archetype().is_none());
+    }
+}
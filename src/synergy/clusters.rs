@@ -0,0 +1,384 @@
+//! Card packages and central cards from the shape of the synergy graph.
+//!
+//! Theme buckets say which strategies a deck leans on, but not which cards
+//! actually work as a unit. `find_clusters` runs Louvain community detection
+//! over the synergy edges to find tightly connected packages, and
+//! `betweenness_centrality` ranks the cards that sit on the most paths
+//! between others.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use super::types::{CardSynergyProfile, SynergyCluster, SynergyEdge, Theme};
+
+/// Smallest group of cards reported as a package
+const MIN_CLUSTER_SIZE: usize = 2;
+
+/// Most dominant themes listed per package
+const MAX_CLUSTER_THEMES: usize = 3;
+
+/// Tolerance when comparing modularity gains and path lengths
+const EPSILON: f64 = 1e-9;
+
+/// Undirected synergy graph over card indices, with edge strengths as weights
+struct Graph {
+    names: Vec<String>,
+    adjacency: Vec<BTreeMap<usize, f64>>,
+}
+
+impl Graph {
+    /// Build from synergy edges, summing parallel edges. Cards without edges
+    /// are left out.
+    fn new(edges: &[SynergyEdge]) -> Self {
+        let names: Vec<String> = edges
+            .iter()
+            .flat_map(|e| [e.card_a.clone(), e.card_b.clone()])
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.as_str(), i))
+            .collect();
+
+        let mut adjacency = vec![BTreeMap::new(); names.len()];
+        for edge in edges.iter().filter(|e| e.card_a != e.card_b) {
+            let (a, b) = (index[edge.card_a.as_str()], index[edge.card_b.as_str()]);
+            *adjacency[a].entry(b).or_insert(0.0) += edge.strength;
+            *adjacency[b].entry(a).or_insert(0.0) += edge.strength;
+        }
+
+        Self { names, adjacency }
+    }
+}
+
+/// Assign each node a community by Louvain modularity optimization.
+///
+/// Nodes are visited in name order and ties keep the current community, so the
+/// result is deterministic.
+fn louvain(adjacency: &[BTreeMap<usize, f64>]) -> Vec<usize> {
+    let mut membership: Vec<usize> = (0..adjacency.len()).collect();
+    // Self-loops hold twice the weight inside an aggregated node, so a row sum
+    // is always that node's degree
+    let mut level: Vec<BTreeMap<usize, f64>> = adjacency.to_vec();
+
+    loop {
+        let n = level.len();
+        let degree: Vec<f64> = level.iter().map(|row| row.values().sum()).collect();
+        let total: f64 = degree.iter().sum();
+        if total <= 0.0 {
+            break;
+        }
+
+        let mut community: Vec<usize> = (0..n).collect();
+        let mut community_degree = degree.clone();
+        let mut moved_any = false;
+
+        loop {
+            let mut moved = false;
+            for node in 0..n {
+                let current = community[node];
+                community_degree[current] -= degree[node];
+
+                let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+                for (&neighbor, &weight) in &level[node] {
+                    if neighbor != node {
+                        *links.entry(community[neighbor]).or_insert(0.0) += weight;
+                    }
+                }
+
+                let gain = |c: usize, link: f64| link - community_degree[c] * degree[node] / total;
+                let mut best = current;
+                let mut best_gain = gain(current, links.get(&current).copied().unwrap_or(0.0));
+                for (&candidate, &link) in &links {
+                    let candidate_gain = gain(candidate, link);
+                    if candidate_gain > best_gain + EPSILON {
+                        best = candidate;
+                        best_gain = candidate_gain;
+                    }
+                }
+
+                community_degree[best] += degree[node];
+                if best != current {
+                    community[node] = best;
+                    moved = true;
+                    moved_any = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+
+        if !moved_any {
+            break;
+        }
+
+        // Collapse each community into a single node for the next level
+        let mut renumber: BTreeMap<usize, usize> = BTreeMap::new();
+        for &c in &community {
+            let next = renumber.len();
+            renumber.entry(c).or_insert(next);
+        }
+        let mut aggregated = vec![BTreeMap::new(); renumber.len()];
+        for (node, row) in level.iter().enumerate() {
+            let from = renumber[&community[node]];
+            for (&neighbor, &weight) in row {
+                let to = renumber[&community[neighbor]];
+                *aggregated[from].entry(to).or_insert(0.0) += weight;
+            }
+        }
+        for m in membership.iter_mut() {
+            *m = renumber[&community[*m]];
+        }
+        level = aggregated;
+    }
+
+    membership
+}
+
+/// Brandes betweenness centrality, treating stronger synergies as shorter
+/// paths (length `1 / strength`), normalized to 0-1.
+fn betweenness(adjacency: &[BTreeMap<usize, f64>]) -> Vec<f64> {
+    let n = adjacency.len();
+    let mut centrality = vec![0.0; n];
+
+    for source in 0..n {
+        let mut distance = vec![f64::INFINITY; n];
+        let mut paths = vec![0.0; n];
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut visited = vec![false; n];
+        let mut order: Vec<usize> = Vec::with_capacity(n);
+        distance[source] = 0.0;
+        paths[source] = 1.0;
+
+        // Dijkstra; the graphs are small enough that a linear scan beats a heap
+        while let Some(node) = (0..n)
+            .filter(|&i| !visited[i] && distance[i].is_finite())
+            .min_by(|&a, &b| distance[a].total_cmp(&distance[b]))
+        {
+            visited[node] = true;
+            order.push(node);
+            for (&neighbor, &weight) in &adjacency[node] {
+                if visited[neighbor] || weight <= 0.0 {
+                    continue;
+                }
+                let through = distance[node] + 1.0 / weight;
+                if through < distance[neighbor] - EPSILON {
+                    distance[neighbor] = through;
+                    paths[neighbor] = paths[node];
+                    predecessors[neighbor] = vec![node];
+                } else if (through - distance[neighbor]).abs() <= EPSILON {
+                    paths[neighbor] += paths[node];
+                    predecessors[neighbor].push(node);
+                }
+            }
+        }
+
+        let mut dependency = vec![0.0; n];
+        for &node in order.iter().rev() {
+            for &predecessor in &predecessors[node] {
+                dependency[predecessor] +=
+                    paths[predecessor] / paths[node] * (1.0 + dependency[node]);
+            }
+            if node != source {
+                centrality[node] += dependency[node];
+            }
+        }
+    }
+
+    // Each pair was counted from both ends
+    let pairs = if n > 2 {
+        ((n - 1) * (n - 2)) as f64
+    } else {
+        1.0
+    };
+    centrality.iter().map(|c| c / pairs).collect()
+}
+
+/// Betweenness centrality of every card with at least one synergy edge
+pub fn betweenness_centrality(edges: &[SynergyEdge]) -> HashMap<String, f64> {
+    let graph = Graph::new(edges);
+    let scores = betweenness(&graph.adjacency);
+    graph.names.into_iter().zip(scores).collect()
+}
+
+/// Group cards into tightly connected synergy packages, largest first.
+///
+/// Each package lists its cards (most connected inside the package first),
+/// the themes shared by at least half of them, and its bridge cards: members
+/// with synergies into other packages, most central first.
+pub fn find_clusters(
+    profiles: &HashMap<String, CardSynergyProfile>,
+    edges: &[SynergyEdge],
+) -> Vec<SynergyCluster> {
+    let graph = Graph::new(edges);
+    let membership = louvain(&graph.adjacency);
+
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (node, &community) in membership.iter().enumerate() {
+        groups.entry(community).or_default().push(node);
+    }
+
+    let centrality = |name: &str| profiles.get(name).map_or(0.0, |p| p.centrality);
+
+    let mut clusters: Vec<SynergyCluster> = groups
+        .values()
+        .filter(|members| members.len() >= MIN_CLUSTER_SIZE)
+        .map(|members| {
+            let community = membership[members[0]];
+            let internal = |node: usize| -> f64 {
+                graph.adjacency[node]
+                    .iter()
+                    .filter(|(n, _)| membership[**n] == community)
+                    .map(|(_, w)| w)
+                    .sum()
+            };
+
+            let mut cards: Vec<(usize, f64)> = members.iter().map(|&m| (m, internal(m))).collect();
+            cards.sort_by(|a, b| {
+                b.1.total_cmp(&a.1)
+                    .then_with(|| graph.names[a.0].cmp(&graph.names[b.0]))
+            });
+
+            let mut bridges: Vec<&String> = members
+                .iter()
+                .filter(|&&m| {
+                    graph.adjacency[m]
+                        .keys()
+                        .any(|n| membership[*n] != community)
+                })
+                .map(|&m| &graph.names[m])
+                .collect();
+            bridges.sort_by(|a, b| {
+                centrality(b)
+                    .total_cmp(&centrality(a))
+                    .then_with(|| a.cmp(b))
+            });
+
+            SynergyCluster {
+                cards: cards.iter().map(|(m, _)| graph.names[*m].clone()).collect(),
+                themes: dominant_themes(members.iter().map(|&m| &graph.names[m]), profiles),
+                bridges: bridges.into_iter().cloned().collect(),
+            }
+        })
+        .collect();
+
+    clusters.sort_by(|a, b| {
+        b.cards
+            .len()
+            .cmp(&a.cards.len())
+            .then_with(|| a.cards.cmp(&b.cards))
+    });
+    clusters
+}
+
+/// Themes held by at least half of a package's cards, most common first
+fn dominant_themes<'a>(
+    cards: impl Iterator<Item = &'a String>,
+    profiles: &HashMap<String, CardSynergyProfile>,
+) -> Vec<Theme> {
+    let mut size = 0;
+    let mut counts: Vec<(Theme, usize)> = Vec::new();
+    for card in cards {
+        size += 1;
+        for theme in profiles
+            .get(card)
+            .map(|p| p.themes.as_slice())
+            .unwrap_or(&[])
+        {
+            match counts.iter_mut().find(|(t, _)| t == theme) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((theme.clone(), 1)),
+            }
+        }
+    }
+
+    counts.retain(|(_, count)| count * 2 >= size);
+    counts.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.0.display_name().cmp(&b.0.display_name()))
+    });
+    counts
+        .into_iter()
+        .take(MAX_CLUSTER_THEMES)
+        .map(|(theme, _)| theme)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synergy::types::SynergyRelation;
+
+    fn edge(a: &str, b: &str, strength: f64) -> SynergyEdge {
+        SynergyEdge {
+            card_a: a.to_string(),
+            card_b: b.to_string(),
+            relation: SynergyRelation::Supports,
+            themes: Vec::new(),
+            strength,
+            reason: String::new(),
+        }
+    }
+
+    /// Two triangles joined through a single bridge card
+    fn two_packages() -> Vec<SynergyEdge> {
+        vec![
+            edge("A1", "A2", 1.0),
+            edge("A2", "A3", 1.0),
+            edge("A1", "A3", 1.0),
+            edge("B1", "B2", 1.0),
+            edge("B2", "B3", 1.0),
+            edge("B1", "B3", 1.0),
+            edge("A3", "Bridge", 0.5),
+            edge("Bridge", "B1", 0.5),
+        ]
+    }
+
+    #[test]
+    fn test_finds_packages_and_bridges() {
+        let edges = two_packages();
+        let centrality = betweenness_centrality(&edges);
+        let mut profiles = HashMap::new();
+        for (name, score) in &centrality {
+            let mut profile = CardSynergyProfile::new(name.clone());
+            profile.centrality = *score;
+            if name.starts_with('A') {
+                profile.themes = vec![Theme::Tokens];
+            }
+            profiles.insert(name.clone(), profile);
+        }
+
+        let clusters = find_clusters(&profiles, &edges);
+        let package = |card: &str| {
+            clusters
+                .iter()
+                .find(|c| c.cards.iter().any(|n| n == card))
+                .unwrap()
+        };
+        let (tokens, other) = (package("A1"), package("B1"));
+        assert!(!std::ptr::eq(tokens, other));
+        assert!(["A2", "A3"]
+            .iter()
+            .all(|c| std::ptr::eq(package(c), tokens)));
+        assert!(["B2", "B3"].iter().all(|c| std::ptr::eq(package(c), other)));
+
+        assert_eq!(tokens.themes, vec![Theme::Tokens]);
+        assert!(other.themes.is_empty());
+        assert!(!tokens.bridges.is_empty() && !other.bridges.is_empty());
+        assert!(!tokens.bridges.contains(&"A1".to_string()));
+    }
+
+    #[test]
+    fn test_betweenness_ranks_the_bridge() {
+        let centrality = betweenness_centrality(&two_packages());
+        let top = centrality
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(name, _)| name.as_str());
+        assert_eq!(top, Some("Bridge"));
+        assert_eq!(centrality["A1"], 0.0);
+        assert!(centrality.values().all(|c| (0.0..=1.0).contains(c)));
+    }
+}
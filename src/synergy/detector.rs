@@ -1,16 +1,217 @@
 #![allow(dead_code)]
 
-use std::collections::HashMap;
-
-use crate::input::DeckList;
-
-use super::keywords::{extract_creature_types, extract_keywords, is_creature};
-use super::themes::{classify_card_role, detect_card_themes, detect_tribal_themes};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::api::Card;
+use crate::combo::{combo_edges, find_combos, ComboDatabase};
+use crate::input::{DeckEntry, DeckList, DeckSection};
+
+use super::archetype::classify_archetypes;
+use super::clusters::{betweenness_centrality, find_clusters};
+use super::interactions::find_interactions;
+use super::keywords::{extract_creature_types, is_creature, oracle_keyword_instances};
+use super::oracle::NormalizedOracle;
+use super::themes::{classify_oracle_role, detect_oracle_themes, detect_tribal_themes};
 use super::types::{
-    CardSynergyProfile, OrphanCard, SynergyEdge, SynergyMatrix, SynergyRelation, SynergyStats,
-    Theme, ThemeAnalysis,
+    CardSynergyProfile, OrphanCard, SynergyEdge, SynergyMatrix, SynergyRelation, SynergyRole,
+    SynergyStats, Theme, ThemeAnalysis,
 };
 
+/// Combined edges weaker than this are dropped
+const MIN_EDGE_STRENGTH: f64 = 0.1;
+
+/// Average per-card synergy strength that earns full marks in the deck score
+const TARGET_CARD_SYNERGY: f64 = 3.0;
+
+/// One shared theme's contribution to a card pair
+struct ThemeLink {
+    theme: Theme,
+    role_a: SynergyRole,
+    role_b: SynergyRole,
+    weight: f64,
+}
+
+/// Base weight for two cards sharing a theme, by their roles in it
+fn role_weight(a: SynergyRole, b: SynergyRole) -> f64 {
+    use SynergyRole::*;
+    match (a, b) {
+        (Enabler, Payoff) | (Payoff, Enabler) => 1.0,
+        (Support, Enabler) | (Enabler, Support) | (Support, Payoff) | (Payoff, Support) => 0.5,
+        (Enabler, Enabler) | (Payoff, Payoff) => 0.35,
+        (Support, Support) => 0.2,
+    }
+}
+
+/// Map a theme match confidence onto a 0.6-1.0 multiplier so that a single
+/// pattern match still counts, while strong matches count fully
+fn confidence_weight(confidence: f64) -> f64 {
+    (0.5 + confidence).clamp(0.6, 1.0)
+}
+
+/// Order edges strongest first, breaking ties by name for stable output
+fn sort_edges(edges: &mut [SynergyEdge]) {
+    edges.sort_by(|a, b| {
+        b.strength
+            .total_cmp(&a.strength)
+            .then_with(|| a.card_a.cmp(&b.card_a))
+            .then_with(|| a.card_b.cmp(&b.card_b))
+    });
+}
+
+/// Score how focused a deck's themes are, from 0 to 1.
+///
+/// Combines three signals:
+/// - concentration: one minus the normalized entropy of theme membership, so a
+///   deck spread evenly across many themes scores low
+/// - connectivity: the share of themed cards in the synergy graph component
+///   that holds most of the primary theme's cards
+/// - overlap: Jaccard similarity of the top two themes' cards, rewarding
+///   secondary themes that reinforce the primary one
+fn theme_coherence(
+    profiles: &HashMap<String, CardSynergyProfile>,
+    themes: &[ThemeAnalysis],
+    edges: &[SynergyEdge],
+) -> f64 {
+    let Some(primary) = themes.first() else {
+        return 0.0;
+    };
+
+    // Concentration
+    let memberships: Vec<f64> = themes.iter().map(|t| t.card_count as f64).collect();
+    let total: f64 = memberships.iter().sum();
+    let concentration = if themes.len() > 1 && total > 0.0 {
+        let entropy: f64 = memberships
+            .iter()
+            .map(|m| m / total)
+            .filter(|p| *p > 0.0)
+            .map(|p| -p * p.ln())
+            .sum();
+        1.0 - entropy / (themes.len() as f64).ln()
+    } else {
+        1.0
+    };
+
+    // Connectivity: union-find over the synergy edges
+    let names: Vec<&String> = profiles.keys().collect();
+    let index: HashMap<&String, usize> = names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut parent: Vec<usize> = (0..names.len()).collect();
+    fn find(parent: &mut [usize], i: usize) -> usize {
+        let mut root = i;
+        while parent[root] != root {
+            root = parent[root];
+        }
+        parent[i] = root;
+        root
+    }
+    for edge in edges {
+        if let (Some(&a), Some(&b)) = (index.get(&edge.card_a), index.get(&edge.card_b)) {
+            let (root_a, root_b) = (find(&mut parent, a), find(&mut parent, b));
+            parent[root_a] = root_b;
+        }
+    }
+
+    let mut primary_components: HashMap<usize, usize> = HashMap::new();
+    for card in primary.all_cards() {
+        if let Some(&i) = index.get(&card) {
+            *primary_components.entry(find(&mut parent, i)).or_insert(0) += 1;
+        }
+    }
+    let themed: Vec<usize> = names
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| !profiles[**n].themes.is_empty())
+        .map(|(i, _)| i)
+        .collect();
+    let connectivity = match primary_components
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+    {
+        Some((component, _)) if !themed.is_empty() => {
+            let connected = themed
+                .iter()
+                .filter(|&&i| find(&mut parent, i) == component)
+                .count();
+            connected as f64 / themed.len() as f64
+        }
+        _ => 0.0,
+    };
+
+    // Overlap between the top two themes
+    let overlap = match themes.get(1) {
+        Some(secondary) => {
+            let a: std::collections::HashSet<&String> = primary.all_cards().into_iter().collect();
+            let b: std::collections::HashSet<&String> = secondary.all_cards().into_iter().collect();
+            let union = a.union(&b).count();
+            if union > 0 {
+                a.intersection(&b).count() as f64 / union as f64
+            } else {
+                0.0
+            }
+        }
+        // A single theme has nothing to dilute it
+        None => 1.0,
+    };
+
+    (0.4 * concentration + 0.4 * connectivity + 0.2 * overlap).clamp(0.0, 1.0)
+}
+
+/// What a single card contributes to the analysis, independent of the rest
+/// of the deck
+struct CardAnalysis {
+    oracle: NormalizedOracle,
+    /// Keywords, themes and role hint, before any deck-level scoring
+    profile: CardSynergyProfile,
+    /// How confidently the card matched each of its themes
+    confidences: Vec<(Theme, f64)>,
+}
+
+impl CardAnalysis {
+    fn new(card: &Card) -> Self {
+        let oracle = NormalizedOracle::new(card);
+        let mut profile = CardSynergyProfile::new(card.name.clone());
+        let mut confidences = Vec::new();
+
+        // Extract keywords
+        for instance in oracle_keyword_instances(&oracle) {
+            if instance.granted {
+                profile.granted_keywords.push(instance.keyword);
+            } else {
+                profile.keywords.push(instance.keyword);
+            }
+        }
+
+        // Detect themes
+        for (theme, confidence, role_hint) in detect_oracle_themes(card, &oracle) {
+            confidences.push((theme.clone(), confidence));
+            profile.themes.push(theme);
+            if profile.role.is_none() {
+                profile.role = role_hint;
+            }
+        }
+
+        Self {
+            oracle,
+            profile,
+            confidences,
+        }
+    }
+}
+
+/// Work kept between analyses of a deck that is being edited, so that each
+/// re-analysis only redoes what the added or removed cards affect
+#[derive(Default)]
+pub struct AnalysisCache {
+    /// Analysis of every card seen so far
+    cards: HashMap<String, CardAnalysis>,
+    /// Mainboard cards in the last analysis
+    deck_cards: HashSet<String>,
+    /// Themes detected in the last analysis
+    themes: HashSet<Theme>,
+    /// Theme and interaction edges from the last analysis, before combos
+    edges: Vec<SynergyEdge>,
+}
+
 /// Trait for synergy detection implementations
 pub trait SynergyDetector: Send + Sync {
     fn analyze(&self, deck: &DeckList) -> SynergyMatrix;
@@ -20,44 +221,55 @@ pub trait SynergyDetector: Send + Sync {
 pub struct RuleBasedDetector {
     /// Minimum number of cards for a theme to be significant
     min_theme_cards: u32,
+    /// Known combos to look for, if any
+    combos: Option<Arc<ComboDatabase>>,
 }
 
 impl RuleBasedDetector {
     pub fn new() -> Self {
-        Self { min_theme_cards: 5 }
+        Self {
+            min_theme_cards: 5,
+            combos: None,
+        }
     }
 
     pub fn with_min_theme_cards(min_cards: u32) -> Self {
         Self {
             min_theme_cards: min_cards,
+            combos: None,
         }
     }
 
-    /// Analyze card profiles and extract themes
-    fn build_card_profiles(&self, deck: &DeckList) -> HashMap<String, CardSynergyProfile> {
+    /// Look for combos from the given database during analysis
+    pub fn with_combos(mut self, combos: Arc<ComboDatabase>) -> Self {
+        self.combos = Some(combos);
+        self
+    }
+
+    /// Collect the deck's card profiles, along with how confidently each card
+    /// matched each theme
+    fn build_card_profiles(
+        &self,
+        deck: &DeckList,
+        cards: &HashMap<String, CardAnalysis>,
+    ) -> (
+        HashMap<String, CardSynergyProfile>,
+        HashMap<(String, Theme), f64>,
+    ) {
         let mut profiles = HashMap::new();
+        let mut confidences = HashMap::new();
 
         for entry in deck.mainboard() {
             if let Some(card) = &entry.card {
-                let mut profile = CardSynergyProfile::new(card.name.clone());
-
-                // Extract keywords
-                profile.keywords = extract_keywords(card);
-
-                // Detect themes
-                let detected = detect_card_themes(card);
-                for (theme, _confidence, role_hint) in detected {
-                    profile.themes.push(theme.clone());
-                    if profile.role.is_none() {
-                        profile.role = role_hint;
-                    }
+                let analysis = &cards[&card.name];
+                for (theme, confidence) in &analysis.confidences {
+                    confidences.insert((card.name.clone(), theme.clone()), *confidence);
                 }
-
-                profiles.insert(card.name.clone(), profile);
+                profiles.insert(card.name.clone(), analysis.profile.clone());
             }
         }
 
-        profiles
+        (profiles, confidences)
     }
 
     /// Aggregate themes across all cards
@@ -65,6 +277,7 @@ impl RuleBasedDetector {
         &self,
         profiles: &HashMap<String, CardSynergyProfile>,
         deck: &DeckList,
+        analyses: &HashMap<String, CardAnalysis>,
     ) -> Vec<ThemeAnalysis> {
         let mut theme_cards: HashMap<Theme, Vec<String>> = HashMap::new();
 
@@ -118,7 +331,7 @@ impl RuleBasedDetector {
         }
 
         // Convert to ThemeAnalysis
-        let total_cards = deck.total_cards();
+        let total_cards = deck.mainboard_cards();
         let mut analyses: Vec<ThemeAnalysis> = theme_cards
             .into_iter()
             .filter(|(_, cards)| cards.len() as u32 >= self.min_theme_cards)
@@ -136,15 +349,17 @@ impl RuleBasedDetector {
                             .find(|e| e.card.as_ref().is_some_and(|c| c.name == card_name))
                         {
                             if let Some(card) = &card_entry.card {
-                                let role = classify_card_role(card, &theme);
+                                let role = classify_oracle_role(
+                                    card,
+                                    &analyses[&card.name].oracle,
+                                    &theme,
+                                );
                                 match role {
-                                    super::types::SynergyRole::Enabler => {
+                                    SynergyRole::Enabler => {
                                         analysis.enablers.push(card_name.clone())
                                     }
-                                    super::types::SynergyRole::Payoff => {
-                                        analysis.payoffs.push(card_name.clone())
-                                    }
-                                    super::types::SynergyRole::Support => {
+                                    SynergyRole::Payoff => analysis.payoffs.push(card_name.clone()),
+                                    SynergyRole::Support => {
                                         analysis.support.push(card_name.clone())
                                     }
                                 }
@@ -158,81 +373,209 @@ impl RuleBasedDetector {
             .collect();
 
         // Sort by card count descending
-        analyses.sort_by(|a, b| b.card_count.cmp(&a.card_count));
+        analyses.sort_by_key(|a| std::cmp::Reverse(a.card_count));
         analyses
     }
 
-    /// Build synergy edges between cards
+    /// Build weighted synergy edges between cards.
+    ///
+    /// Every theme two cards share contributes a weight based on their roles in
+    /// that theme (enabler→payoff counts most, support↔support least), scaled by
+    /// how confidently each card was matched to the theme. Contributions from
+    /// multiple shared themes are combined into a single edge, and pairs whose
+    /// combined strength stays below `MIN_EDGE_STRENGTH` are dropped so large
+    /// themes don't connect everything to everything. Only pairs with at
+    /// least one card in `changed` are built.
     fn build_edges(
         &self,
-        _profiles: &HashMap<String, CardSynergyProfile>,
+        confidences: &HashMap<(String, Theme), f64>,
         themes: &[ThemeAnalysis],
+        changed: &HashSet<String>,
     ) -> Vec<SynergyEdge> {
-        let mut edges = Vec::new();
-        let mut seen_pairs: std::collections::HashSet<(String, String)> =
-            std::collections::HashSet::new();
+        let mut pairs: HashMap<(String, String), Vec<ThemeLink>> = HashMap::new();
 
-        // Cards sharing themes have synergy
         for theme_analysis in themes {
-            let all_cards = theme_analysis.all_cards();
-
-            for i in 0..all_cards.len() {
-                for j in (i + 1)..all_cards.len() {
-                    let card_a = all_cards[i].clone();
-                    let card_b = all_cards[j].clone();
+            let theme = &theme_analysis.theme;
+            let mut members: Vec<(&String, SynergyRole)> = Vec::new();
+            members.extend(
+                theme_analysis
+                    .enablers
+                    .iter()
+                    .map(|c| (c, SynergyRole::Enabler)),
+            );
+            members.extend(
+                theme_analysis
+                    .payoffs
+                    .iter()
+                    .map(|c| (c, SynergyRole::Payoff)),
+            );
+            members.extend(
+                theme_analysis
+                    .support
+                    .iter()
+                    .map(|c| (c, SynergyRole::Support)),
+            );
 
+            for i in 0..members.len() {
+                for j in (i + 1)..members.len() {
                     // Normalize pair order
-                    let pair = if card_a < card_b {
-                        (card_a.clone(), card_b.clone())
+                    let (a, b) = if members[i].0 < members[j].0 {
+                        (members[i], members[j])
                     } else {
-                        (card_b.clone(), card_a.clone())
+                        (members[j], members[i])
                     };
-
-                    // Skip if we've already added this pair
-                    if seen_pairs.contains(&pair) {
+                    if a.0 == b.0 || !(changed.contains(a.0) || changed.contains(b.0)) {
                         continue;
                     }
-                    seen_pairs.insert(pair);
-
-                    // Determine relationship type
-                    let relation = if theme_analysis.enablers.contains(&card_a)
-                        && theme_analysis.payoffs.contains(&card_b)
-                    {
-                        SynergyRelation::Enables
-                    } else if theme_analysis.payoffs.contains(&card_a)
-                        && theme_analysis.enablers.contains(&card_b)
-                    {
-                        SynergyRelation::PayoffFor
-                    } else {
-                        SynergyRelation::Supports
+
+                    let confidence = |card: &String| {
+                        let raw = confidences
+                            .get(&(card.clone(), theme.clone()))
+                            .copied()
+                            .unwrap_or(1.0);
+                        confidence_weight(raw)
                     };
+                    let weight = role_weight(a.1, b.1) * confidence(a.0) * confidence(b.0);
+
+                    pairs
+                        .entry((a.0.clone(), b.0.clone()))
+                        .or_default()
+                        .push(ThemeLink {
+                            theme: theme.clone(),
+                            role_a: a.1,
+                            role_b: b.1,
+                            weight,
+                        });
+                }
+            }
+        }
+
+        let mut edges: Vec<SynergyEdge> = pairs
+            .into_iter()
+            .filter_map(|((card_a, card_b), mut links)| {
+                // Combine shared themes like independent chances of a synergy
+                let strength = 1.0 - links.iter().map(|l| 1.0 - l.weight).product::<f64>();
+                if strength < MIN_EDGE_STRENGTH {
+                    return None;
+                }
 
-                    edges.push(SynergyEdge {
+                links.sort_by(|x, y| y.weight.total_cmp(&x.weight));
+                let strongest = &links[0];
+
+                // Orient the edge so card_a is the enabler when there is one
+                let (card_a, card_b, relation) = match (strongest.role_a, strongest.role_b) {
+                    (SynergyRole::Enabler, SynergyRole::Payoff) => {
+                        (card_a, card_b, SynergyRelation::Enables)
+                    }
+                    (SynergyRole::Payoff, SynergyRole::Enabler) => {
+                        (card_b, card_a, SynergyRelation::Enables)
+                    }
+                    _ => (card_a, card_b, SynergyRelation::Supports),
+                };
+
+                let mut reason = match relation {
+                    SynergyRelation::Enables => format!(
+                        "{} enables {}'s {} payoff",
                         card_a,
                         card_b,
-                        relation,
-                        themes: vec![theme_analysis.theme.clone()],
-                        strength: 0.5,
-                        reason: format!(
-                            "Both support {} theme",
-                            theme_analysis.theme.display_name()
-                        ),
-                    });
+                        strongest.theme.display_name()
+                    ),
+                    _ => format!("Both support {} theme", strongest.theme.display_name()),
+                };
+                if links.len() > 1 {
+                    let others: Vec<_> =
+                        links[1..].iter().map(|l| l.theme.display_name()).collect();
+                    reason.push_str(&format!(" (also {})", others.join(", ")));
+                }
+
+                Some(SynergyEdge {
+                    card_a,
+                    card_b,
+                    relation,
+                    themes: links.into_iter().map(|l| l.theme).collect(),
+                    strength,
+                    reason,
+                })
+            })
+            .collect();
+
+        sort_edges(&mut edges);
+        edges
+    }
+
+    /// Fold explicit card-pair interactions into the theme edges.
+    ///
+    /// An interaction on a pair that already shares themes strengthens that
+    /// edge and replaces its generic reason with the specific one. The merged
+    /// edge takes the interaction's direction and relation, so its reason
+    /// still reads from `card_a` to `card_b`.
+    fn merge_interactions(&self, edges: &mut Vec<SynergyEdge>, interactions: Vec<SynergyEdge>) {
+        for interaction in interactions {
+            let existing = edges.iter_mut().find(|e| {
+                (e.card_a == interaction.card_a && e.card_b == interaction.card_b)
+                    || (e.card_a == interaction.card_b && e.card_b == interaction.card_a)
+            });
+
+            match existing {
+                Some(edge) => {
+                    let shared: Vec<_> = edge
+                        .themes
+                        .iter()
+                        .filter(|t| !interaction.themes.contains(t))
+                        .cloned()
+                        .collect();
+                    let mut reason = interaction.reason.clone();
+                    if !shared.is_empty() {
+                        let names: Vec<_> = shared.iter().map(|t| t.display_name()).collect();
+                        reason.push_str(&format!(" (also {})", names.join(", ")));
+                    }
+
+                    let strength = 1.0 - (1.0 - edge.strength) * (1.0 - interaction.strength);
+                    let mut themes = interaction.themes;
+                    themes.extend(shared);
+
+                    *edge = SynergyEdge {
+                        card_a: interaction.card_a,
+                        card_b: interaction.card_b,
+                        relation: interaction.relation,
+                        themes,
+                        strength,
+                        reason,
+                    };
                 }
+                None => edges.push(interaction),
             }
         }
 
-        edges
+        sort_edges(edges);
+    }
+
+    /// Fill each profile's synergy score and partners from the edges
+    fn score_profiles(
+        &self,
+        profiles: &mut HashMap<String, CardSynergyProfile>,
+        edges: &[SynergyEdge],
+    ) {
+        for edge in edges {
+            for (card, partner) in [(&edge.card_a, &edge.card_b), (&edge.card_b, &edge.card_a)] {
+                if let Some(profile) = profiles.get_mut(card) {
+                    profile.synergy_score += edge.strength;
+                    // Edges are sorted by strength, so partners stay strongest-first
+                    profile.synergizes_with.push(partner.clone());
+                }
+            }
+        }
     }
 
     /// Calculate synergy statistics
     fn calculate_stats(
         &self,
         profiles: &HashMap<String, CardSynergyProfile>,
+        themes: &[ThemeAnalysis],
         edges: &[SynergyEdge],
         deck: &DeckList,
     ) -> SynergyStats {
-        let total_cards = deck.unique_cards();
+        let total_cards = deck.mainboard().count();
         let possible_edges = if total_cards > 1 {
             (total_cards * (total_cards - 1)) / 2
         } else {
@@ -267,20 +610,22 @@ impl RuleBasedDetector {
             })
             .collect();
 
-        // Find hub cards (most edges)
-        let mut edge_counts: HashMap<String, u32> = HashMap::new();
-        for edge in edges {
-            *edge_counts.entry(edge.card_a.clone()).or_insert(0) += 1;
-            *edge_counts.entry(edge.card_b.clone()).or_insert(0) += 1;
-        }
-
-        let mut sorted_by_edges: Vec<_> = edge_counts.into_iter().collect();
-        sorted_by_edges.sort_by(|a, b| b.1.cmp(&a.1));
-
-        let hub_cards: Vec<String> = sorted_by_edges
+        // Find hub cards (most central, then highest total synergy strength)
+        let mut scored: Vec<_> = profiles
+            .values()
+            .filter(|p| p.synergy_score > 0.0)
+            .collect();
+        scored.sort_by(|a, b| {
+            b.centrality
+                .total_cmp(&a.centrality)
+                .then_with(|| b.synergy_score.total_cmp(&a.synergy_score))
+                .then_with(|| a.card_name.cmp(&b.card_name))
+        });
+
+        let hub_cards: Vec<String> = scored
             .into_iter()
             .take(5)
-            .map(|(name, _)| name)
+            .map(|p| p.card_name.clone())
             .collect();
 
         // Keyword distribution
@@ -291,20 +636,35 @@ impl RuleBasedDetector {
             }
         }
 
+        let theme_coverage = if total_cards > 0 {
+            themed_count as f64 / total_cards as f64
+        } else {
+            0.0
+        };
+        let theme_coherence = theme_coherence(profiles, themes, edges);
+
+        // Deck score: how much of the deck is on theme, how focused those themes
+        // are, and how strongly the average card connects to the rest
+        let average_synergy = if profiles.is_empty() {
+            0.0
+        } else {
+            profiles.values().map(|p| p.synergy_score).sum::<f64>() / profiles.len() as f64
+        };
+        let connectivity = (average_synergy / TARGET_CARD_SYNERGY).min(1.0);
+        let synergy_score =
+            (0.35 * theme_coverage + 0.35 * theme_coherence + 0.30 * connectivity) * 100.0;
+
         SynergyStats {
             synergy_density: if possible_edges > 0 {
                 edges.len() as f64 / possible_edges as f64
             } else {
                 0.0
             },
-            theme_coverage: if total_cards > 0 {
-                themed_count as f64 / total_cards as f64
-            } else {
-                0.0
-            },
+            theme_coverage,
             orphan_cards,
             hub_cards,
-            theme_coherence: 0.0, // TODO: Calculate properly
+            theme_coherence,
+            synergy_score,
             keyword_distribution: keyword_dist,
         }
     }
@@ -342,6 +702,14 @@ impl RuleBasedDetector {
             observations.push("High synergy density! Cards work well together.".to_string());
         }
 
+        // Theme coherence observations
+        if themes.len() > 1 && stats.theme_coherence < 0.4 {
+            observations.push(
+                "Themes are scattered. Focusing on fewer themes would tighten the deck."
+                    .to_string(),
+            );
+        }
+
         // Theme coverage observations
         if stats.theme_coverage < 0.5 {
             observations.push(format!(
@@ -374,41 +742,171 @@ impl Default for RuleBasedDetector {
     }
 }
 
-impl SynergyDetector for RuleBasedDetector {
-    fn analyze(&self, deck: &DeckList) -> SynergyMatrix {
+impl RuleBasedDetector {
+    /// Analyze a deck, reusing the work in `cache` from earlier versions of it.
+    ///
+    /// Only cards not analyzed before are profiled, and edges between two cards
+    /// that were in the last analysis are reused as long as the same themes are
+    /// detected.
+    pub fn analyze_cached(&self, deck: &DeckList, cache: &mut AnalysisCache) -> SynergyMatrix {
         let mut matrix = SynergyMatrix::new();
 
         // Set deck metadata
         matrix.deck_name = deck.name.clone();
         matrix.deck_format = deck.format.clone();
-        matrix.total_cards = deck.total_cards();
-        matrix.unique_cards = deck.unique_cards() as u32;
+        matrix.total_cards = deck.mainboard_cards();
+        matrix.unique_cards = deck.mainboard().count() as u32;
+
+        let mut cards: Vec<&Card> = Vec::new();
+        for card in deck.mainboard().filter_map(|e| e.card.as_ref()) {
+            if !cards.iter().any(|c| c.name == card.name) {
+                cards.push(card);
+            }
+        }
+        for card in &cards {
+            cache
+                .cards
+                .entry(card.name.clone())
+                .or_insert_with(|| CardAnalysis::new(card));
+        }
 
         // Build card profiles
-        matrix.card_profiles = self.build_card_profiles(deck);
+        let (profiles, confidences) = self.build_card_profiles(deck, &cache.cards);
+        matrix.card_profiles = profiles;
 
         // Aggregate themes
-        matrix.detected_themes = self.aggregate_themes(&matrix.card_profiles, deck);
+        matrix.detected_themes = self.aggregate_themes(&matrix.card_profiles, deck, &cache.cards);
 
         // Set primary theme
         matrix.primary_theme = matrix.detected_themes.first().map(|t| t.theme.clone());
 
-        // Build synergy edges
-        matrix.edges = self.build_edges(&matrix.card_profiles, &matrix.detected_themes);
+        // Rebuild edges touching added or removed cards, or every edge if a
+        // theme appeared or dropped out
+        let deck_cards: HashSet<String> = cards.iter().map(|c| c.name.clone()).collect();
+        let mut changed: HashSet<String> = deck_cards
+            .symmetric_difference(&cache.deck_cards)
+            .cloned()
+            .collect();
+        let themes: HashSet<Theme> = matrix
+            .detected_themes
+            .iter()
+            .map(|t| t.theme.clone())
+            .collect();
+        if themes != cache.themes {
+            changed.extend(deck_cards.iter().cloned());
+        }
+        let touches_changed = |a: &str, b: &str| changed.contains(a) || changed.contains(b);
+
+        // Build synergy edges and add explicit card-pair interactions
+        matrix.edges = self.build_edges(&confidences, &matrix.detected_themes, &changed);
+        self.merge_interactions(
+            &mut matrix.edges,
+            find_interactions(&cards, touches_changed),
+        );
+        matrix.edges.extend(
+            cache
+                .edges
+                .iter()
+                .filter(|e| !touches_changed(&e.card_a, &e.card_b))
+                .cloned(),
+        );
+        sort_edges(&mut matrix.edges);
+
+        cache.deck_cards = deck_cards;
+        cache.themes = themes;
+        cache.edges = matrix.edges.clone();
+
+        // Add known combos
+        if let Some(db) = &self.combos {
+            matrix.combos = find_combos(db, deck);
+            self.merge_interactions(&mut matrix.edges, combo_edges(&matrix.combos));
+        }
+        self.score_profiles(&mut matrix.card_profiles, &matrix.edges);
+        for (name, centrality) in betweenness_centrality(&matrix.edges) {
+            if let Some(profile) = matrix.card_profiles.get_mut(&name) {
+                profile.centrality = centrality;
+            }
+        }
+        matrix.clusters = find_clusters(&matrix.card_profiles, &matrix.edges);
 
         // Calculate statistics
-        matrix.stats = self.calculate_stats(&matrix.card_profiles, &matrix.edges, deck);
+        matrix.stats = self.calculate_stats(
+            &matrix.card_profiles,
+            &matrix.detected_themes,
+            &matrix.edges,
+            deck,
+        );
+
+        // Score strategy archetypes
+        matrix.archetypes = classify_archetypes(deck, &matrix.card_profiles);
 
         // Generate observations
         matrix.observations = self.generate_observations(&matrix.detected_themes, &matrix.stats);
 
         matrix
     }
+
+    /// Edges `candidate` would form if added to `deck`, strongest first.
+    ///
+    /// Only pairs involving the candidate are scored, against the deck's
+    /// themes with the candidate included. Card analyses are kept in `cache`,
+    /// so checking many candidates against one deck analyzes each deck card
+    /// once. The cached edges are left untouched.
+    pub fn candidate_edges(
+        &self,
+        deck: &DeckList,
+        candidate: &Card,
+        cache: &mut AnalysisCache,
+    ) -> Vec<SynergyEdge> {
+        let mut trial = deck.clone();
+        trial.entries.push(DeckEntry {
+            quantity: 1,
+            card_name: candidate.name.clone(),
+            card: Some(candidate.clone()),
+            section: DeckSection::Mainboard,
+        });
+
+        let mut cards: Vec<&Card> = Vec::new();
+        for card in trial.mainboard().filter_map(|e| e.card.as_ref()) {
+            if !cards.iter().any(|c| c.name == card.name) {
+                cards.push(card);
+            }
+        }
+        for card in &cards {
+            cache
+                .cards
+                .entry(card.name.clone())
+                .or_insert_with(|| CardAnalysis::new(card));
+        }
+
+        let (profiles, confidences) = self.build_card_profiles(&trial, &cache.cards);
+        let themes = self.aggregate_themes(&profiles, &trial, &cache.cards);
+        let changed = HashSet::from([candidate.name.clone()]);
+        let touches_candidate = |a: &str, b: &str| a == candidate.name || b == candidate.name;
+
+        let mut edges = self.build_edges(&confidences, &themes, &changed);
+        self.merge_interactions(&mut edges, find_interactions(&cards, touches_candidate));
+        if let Some(db) = &self.combos {
+            let combos: Vec<_> = find_combos(db, &trial)
+                .into_iter()
+                .filter(|m| m.present.contains(&candidate.name))
+                .collect();
+            self.merge_interactions(&mut edges, combo_edges(&combos));
+        }
+        sort_edges(&mut edges);
+        edges
+    }
+}
+
+impl SynergyDetector for RuleBasedDetector {
+    fn analyze(&self, deck: &DeckList) -> SynergyMatrix {
+        self.analyze_cached(deck, &mut AnalysisCache::default())
+    }
 }
 
-/// Factory function to get a synergy detector
-pub fn get_detector() -> Box<dyn SynergyDetector> {
-    Box::new(RuleBasedDetector::new())
+/// Factory function to get a synergy detector that looks for combos from `combos`
+pub fn get_detector(combos: Arc<ComboDatabase>) -> Box<dyn SynergyDetector> {
+    Box::new(RuleBasedDetector::new().with_combos(combos))
 }
 
 #[cfg(test)]
@@ -479,6 +977,245 @@ mod tests {
             .any(|t| t.theme == Theme::Tokens));
     }
 
+    #[test]
+    fn test_enabler_payoff_edges_outweigh_support() {
+        let deck = create_token_deck();
+        let matrix = RuleBasedDetector::new().analyze(&deck);
+
+        let edge = |a: &str, b: &str| {
+            matrix
+                .edges
+                .iter()
+                .find(|e| (e.card_a == a && e.card_b == b) || (e.card_a == b && e.card_b == a))
+                .unwrap_or_else(|| panic!("missing edge {a} - {b}"))
+        };
+
+        // The theme edge says Raise the Alarm enables Intangible Virtue. The
+        // explicit anthem interaction replaces it and takes its direction from
+        // the interaction, so the same pair reads as Virtue paying off tokens.
+        let enables = edge("Raise the Alarm", "Intangible Virtue");
+        assert_eq!(enables.relation, SynergyRelation::PayoffFor);
+        assert_eq!(enables.card_a, "Intangible Virtue");
+        assert_eq!(enables.card_b, "Raise the Alarm");
+
+        let redundant = edge("Raise the Alarm", "Spectral Procession");
+        assert!(enables.strength > redundant.strength);
+        assert!(matrix.edges.iter().all(|e| e.strength <= 1.0));
+    }
+
+    #[test]
+    fn test_shared_themes_accumulate_into_one_edge() {
+        let deck = create_token_deck();
+        let matrix = RuleBasedDetector::new().analyze(&deck);
+
+        let mut pairs = std::collections::HashSet::new();
+        for edge in &matrix.edges {
+            let pair = if edge.card_a < edge.card_b {
+                (&edge.card_a, &edge.card_b)
+            } else {
+                (&edge.card_b, &edge.card_a)
+            };
+            assert!(pairs.insert(pair), "duplicate edge {pair:?}");
+        }
+
+        let detector = RuleBasedDetector::new();
+        let supporting = |theme: Theme| {
+            let mut analysis = ThemeAnalysis::new(theme);
+            analysis.support = vec!["Card A".to_string(), "Card B".to_string()];
+            analysis
+        };
+        let confidences = HashMap::new();
+        let changed = HashSet::from(["Card A".to_string()]);
+
+        let one = detector.build_edges(&confidences, &[supporting(Theme::Tokens)], &changed);
+        let two = detector.build_edges(
+            &confidences,
+            &[supporting(Theme::Tokens), supporting(Theme::Sacrifice)],
+            &changed,
+        );
+        assert_eq!(one.len(), 1);
+        assert_eq!(two.len(), 1);
+        assert!(two[0].strength > one[0].strength);
+        assert_eq!(two[0].themes, vec![Theme::Tokens, Theme::Sacrifice]);
+        assert!(two[0].reason.contains(&Theme::Tokens.display_name()));
+        assert!(two[0].reason.contains(&Theme::Sacrifice.display_name()));
+    }
+
+    #[test]
+    fn test_profiles_are_scored() {
+        let deck = create_token_deck();
+        let matrix = RuleBasedDetector::new().analyze(&deck);
+
+        let virtue = &matrix.card_profiles["Intangible Virtue"];
+        assert!(virtue.synergy_score > 0.0);
+        assert!(virtue
+            .synergizes_with
+            .contains(&"Raise the Alarm".to_string()));
+
+        let hub = &matrix.stats.hub_cards[0];
+        let top_score = matrix.card_profiles[hub].synergy_score;
+        assert!(matrix
+            .card_profiles
+            .values()
+            .all(|p| p.synergy_score <= top_score));
+    }
+
+    /// Token deck with an unrelated graveyard package bolted on
+    fn create_split_deck() -> DeckList {
+        let mut deck = create_token_deck();
+
+        let graveyard_cards = vec![
+            ("Grisly Salvage", "Reveal the top five cards of your library. Put a creature or land card from among them into your hand and the rest into your graveyard.", "Instant"),
+            ("Raise Dead", "Return target creature card from your graveyard to your hand.", "Sorcery"),
+            ("Gurmag Angler", "Delve", "Creature — Zombie Fish"),
+            ("Treasure Cruise", "Delve\nDraw three cards.", "Sorcery"),
+            ("Scrapheap Scrounger", "Exile another creature card from your graveyard: Return Scrapheap Scrounger from your graveyard to the battlefield tapped.", "Artifact Creature — Construct"),
+            ("Tormenting Voice", "As an additional cost to cast this spell, discard a card.\nDraw two cards.\nFlashback {4}{R}", "Sorcery"),
+        ];
+
+        for (name, text, type_line) in graveyard_cards {
+            deck.entries.push(DeckEntry {
+                quantity: 4,
+                card_name: name.to_string(),
+                card: Some(mock_card(name, text, type_line)),
+                section: DeckSection::Mainboard,
+            });
+        }
+
+        deck
+    }
+
+    #[test]
+    fn test_focused_deck_is_more_coherent() {
+        let detector = RuleBasedDetector::new();
+        let focused = detector.analyze(&create_token_deck());
+        let split = detector.analyze(&create_split_deck());
+
+        assert!(split.detected_themes.len() > focused.detected_themes.len());
+        assert!(focused.stats.theme_coherence > split.stats.theme_coherence);
+        assert!(focused.stats.synergy_score > split.stats.synergy_score);
+        assert!(split.stats.theme_coherence > 0.0);
+        assert!(focused.stats.theme_coherence <= 1.0);
+    }
+
+    #[test]
+    fn test_cached_analysis_matches_fresh_analysis() {
+        let detector = RuleBasedDetector::new();
+        let edges = |matrix: &SynergyMatrix| {
+            let mut edges: Vec<_> = matrix
+                .edges
+                .iter()
+                .map(|e| {
+                    let strength = format!("{:.6}", e.strength);
+                    (e.card_a.clone(), e.card_b.clone(), e.relation, strength)
+                })
+                .collect();
+            edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+            edges
+        };
+
+        // Cutting a card may drop a theme below the threshold; putting it
+        // back restores the original deck
+        let full = create_split_deck();
+        let mut cache = AnalysisCache::default();
+        detector.analyze_cached(&full, &mut cache);
+        for i in 0..full.entries.len() {
+            let mut cut = full.clone();
+            cut.entries.remove(i);
+            for deck in [&cut, &full] {
+                let cached = detector.analyze_cached(deck, &mut cache);
+                assert_eq!(edges(&cached), edges(&detector.analyze(deck)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_candidate_edges_match_fresh_analysis() {
+        let detector = RuleBasedDetector::new();
+        let edges = |edges: &[SynergyEdge]| {
+            let mut edges: Vec<_> = edges
+                .iter()
+                .map(|e| {
+                    let strength = format!("{:.6}", e.strength);
+                    (e.card_a.clone(), e.card_b.clone(), e.relation, strength)
+                })
+                .collect();
+            edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+            edges
+        };
+
+        // Each card, cut and offered back as a candidate, forms the same
+        // edges as in an analysis of the full deck
+        let full = create_split_deck();
+        let matrix = detector.analyze(&full);
+        let mut cache = AnalysisCache::default();
+        for i in 0..full.entries.len() {
+            let mut cut = full.clone();
+            let candidate = cut.entries.remove(i).card.unwrap();
+            let expected: Vec<SynergyEdge> = matrix
+                .edges
+                .iter()
+                .filter(|e| e.card_a == candidate.name || e.card_b == candidate.name)
+                .cloned()
+                .collect();
+            let found = detector.candidate_edges(&cut, &candidate, &mut cache);
+            assert_eq!(edges(&found), edges(&expected));
+        }
+    }
+
+    #[test]
+    fn test_empty_deck_scores_zero() {
+        let matrix = RuleBasedDetector::new().analyze(&DeckList::new(DeckSource::Manual));
+        assert_eq!(matrix.stats.theme_coherence, 0.0);
+        assert_eq!(matrix.stats.synergy_score, 0.0);
+    }
+
+    #[test]
+    fn test_interactions_upgrade_theme_edges() {
+        let deck = create_token_deck();
+        let matrix = RuleBasedDetector::new().analyze(&deck);
+
+        let edge = matrix
+            .edges
+            .iter()
+            .find(|e| e.card_a == "Intangible Virtue" && e.card_b == "Spectral Procession")
+            .expect("anthem should pay off the token maker");
+        assert_eq!(edge.relation, SynergyRelation::PayoffFor);
+        assert!(edge
+            .reason
+            .starts_with("Intangible Virtue pumps the creature tokens"));
+        assert!(edge.themes.contains(&Theme::Tokens));
+    }
+
+    #[test]
+    fn test_combos_become_edges() {
+        let deck = create_token_deck();
+        let db = ComboDatabase {
+            combos: vec![crate::combo::Combo {
+                id: "test".to_string(),
+                cards: vec![
+                    "Intangible Virtue".to_string(),
+                    "Spectral Procession".to_string(),
+                ],
+                color_identity: "W".to_string(),
+                results: vec!["Big spirits".to_string()],
+                description: String::new(),
+            }],
+        };
+        let matrix = RuleBasedDetector::new()
+            .with_combos(Arc::new(db))
+            .analyze(&deck);
+
+        assert_eq!(matrix.combos.len(), 1);
+        let edge = matrix
+            .edges
+            .iter()
+            .find(|e| e.relation == SynergyRelation::Combos)
+            .expect("complete combo should add an edge");
+        assert!(edge.themes.contains(&Theme::Combo));
+        assert_eq!(edge.strength, 1.0);
+    }
+
     #[test]
     fn test_primary_theme() {
         let deck = create_token_deck();
@@ -0,0 +1,315 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::api::Card;
+
+use super::types::{SynergyEdge, SynergyRelation, Theme};
+
+/// Which cards can fill one side of an interaction
+pub struct CardMatcher {
+    /// Oracle text patterns (any must match, if present)
+    pub oracle_patterns: Vec<Regex>,
+    /// Type line patterns (any must match, if present)
+    pub type_patterns: Vec<Regex>,
+}
+
+impl CardMatcher {
+    fn oracle(patterns: &[&str]) -> Self {
+        Self {
+            oracle_patterns: patterns.iter().map(|p| Regex::new(p).unwrap()).collect(),
+            type_patterns: Vec::new(),
+        }
+    }
+
+    fn with_types(mut self, patterns: &[&str]) -> Self {
+        self.type_patterns = patterns.iter().map(|p| Regex::new(p).unwrap()).collect();
+        self
+    }
+
+    pub fn matches(&self, card: &Card) -> bool {
+        let oracle = card.all_oracle_text().join("\n");
+        let types = card.all_type_lines().join(" ");
+
+        (self.oracle_patterns.is_empty()
+            || self.oracle_patterns.iter().any(|p| p.is_match(&oracle)))
+            && (self.type_patterns.is_empty()
+                || self.type_patterns.iter().any(|p| p.is_match(&types)))
+    }
+}
+
+/// A directed interaction between two kinds of cards.
+///
+/// `first` and `second` describe the two sides; `relation` reads from first to
+/// second, so `Enables` means the first card enables the second and
+/// `PayoffFor` means the first card pays off the second.
+pub struct InteractionRule {
+    pub first: CardMatcher,
+    pub second: CardMatcher,
+    pub relation: SynergyRelation,
+    pub theme: Theme,
+    pub strength: f64,
+    /// Reason template; `{a}` and `{b}` are replaced with the first and second card names
+    pub reason: &'static str,
+}
+
+impl InteractionRule {
+    fn reason_for(&self, a: &str, b: &str) -> String {
+        self.reason.replace("{a}", a).replace("{b}", b)
+    }
+}
+
+lazy_static! {
+    pub static ref INTERACTION_RULES: Vec<InteractionRule> = vec![
+        // Sacrifice outlets feed death triggers
+        InteractionRule {
+            first: CardMatcher::oracle(&[
+                r"(?i)sacrifice (a|another) (creature|permanent)[^.]*:",
+                r"(?i)sacrifice (a|another|any number of) (other )?creatures?",
+            ]),
+            second: CardMatcher::oracle(&[r"(?i)whenever [^.]*creatures? [^.]*dies"]),
+            relation: SynergyRelation::Enables,
+            theme: Theme::Sacrifice,
+            strength: 0.8,
+            reason: "{a} can sacrifice creatures on demand to trigger {b}",
+        },
+
+        // Token makers give sacrifice outlets fodder
+        InteractionRule {
+            first: CardMatcher::oracle(&[r"(?i)create[^.]*creature tokens?"]),
+            second: CardMatcher::oracle(&[
+                r"(?i)sacrifice (a|another) (creature|permanent)[^.]*:",
+                r"(?i)sacrifice (a|another|any number of) (other )?creatures?",
+            ]),
+            relation: SynergyRelation::Enables,
+            theme: Theme::Sacrifice,
+            strength: 0.6,
+            reason: "{a} makes tokens to feed {b}'s sacrifice outlet",
+        },
+
+        // Lifegain payoffs reward lifelink and incidental life gain
+        InteractionRule {
+            first: CardMatcher::oracle(&[r"(?i)whenever you gain life"]),
+            second: CardMatcher::oracle(&[
+                r"(?i)\blifelink\b",
+                r"(?i)you gain \d+ life",
+                r"(?i)you gain life",
+            ]),
+            relation: SynergyRelation::PayoffFor,
+            theme: Theme::Lifegain,
+            strength: 0.75,
+            reason: "{a} triggers whenever {b} gains you life",
+        },
+
+        // Blink effects re-use enter-the-battlefield abilities
+        InteractionRule {
+            first: CardMatcher::oracle(&[
+                r"(?i)exile[^.]*(return|returns) (it|that card|them|those cards) to the battlefield",
+                r"(?i)\bflicker\b",
+            ]),
+            second: CardMatcher::oracle(&[r"(?im)^when [^.]*enters"])
+                .with_types(&[r"(?i)\bcreature\b"]),
+            relation: SynergyRelation::Enables,
+            theme: Theme::Blink,
+            strength: 0.8,
+            reason: "{a} can blink {b} to reuse its enter ability",
+        },
+
+        // Anthems for tokens pay off token makers
+        InteractionRule {
+            first: CardMatcher::oracle(&[
+                r"(?i)(creature )?tokens you control get",
+                r"(?i)creatures? tokens? you control (get|have)",
+            ]),
+            second: CardMatcher::oracle(&[r"(?i)create[^.]*creature tokens?"]),
+            relation: SynergyRelation::PayoffFor,
+            theme: Theme::Tokens,
+            strength: 0.75,
+            reason: "{a} pumps the creature tokens {b} creates",
+        },
+
+        // Token doublers multiply token makers
+        InteractionRule {
+            first: CardMatcher::oracle(&[
+                r"(?i)would create one or more tokens[^.]*twice that many",
+            ]),
+            second: CardMatcher::oracle(&[r"(?i)create[^.]*tokens?"]),
+            relation: SynergyRelation::PayoffFor,
+            theme: Theme::Tokens,
+            strength: 0.7,
+            reason: "{a} doubles the tokens {b} creates",
+        },
+
+        // Spell payoffs reward cheap instants and sorceries
+        InteractionRule {
+            first: CardMatcher::oracle(&[
+                r"(?i)whenever you cast (an|a) (instant or sorcery|noncreature) spell",
+            ]),
+            second: CardMatcher::oracle(&[]).with_types(&[r"(?i)\b(instant|sorcery)\b"]),
+            relation: SynergyRelation::PayoffFor,
+            theme: Theme::Spellslinger,
+            strength: 0.5,
+            reason: "{a} triggers when you cast {b}",
+        },
+
+        // Self-mill and discard fill the graveyard for recursion
+        InteractionRule {
+            first: CardMatcher::oracle(&[
+                r"(?i)\bmill\b",
+                r"(?i)discard (a|two|one or more) cards?",
+                r"(?i)put[^.]*into your graveyard",
+            ]),
+            second: CardMatcher::oracle(&[
+                r"(?i)\bflashback\b",
+                r"(?i)\bescape\b",
+                r"(?i)\bdelve\b",
+                r"(?i)\bunearth\b",
+                r"(?i)from your graveyard to the battlefield",
+            ]),
+            relation: SynergyRelation::Enables,
+            theme: Theme::Graveyard,
+            strength: 0.6,
+            reason: "{a} fills the graveyard for {b}",
+        },
+    ];
+}
+
+/// Find explicit interactions between every ordered pair of cards that
+/// `include` accepts.
+///
+/// A pair matched by several rules keeps only the strongest interaction.
+pub fn find_interactions(
+    cards: &[&Card],
+    include: impl Fn(&str, &str) -> bool,
+) -> Vec<SynergyEdge> {
+    let mut edges: Vec<SynergyEdge> = Vec::new();
+
+    for rule in INTERACTION_RULES.iter() {
+        let firsts: Vec<&&Card> = cards.iter().filter(|c| rule.first.matches(c)).collect();
+        if firsts.is_empty() {
+            continue;
+        }
+        let seconds: Vec<&&Card> = cards.iter().filter(|c| rule.second.matches(c)).collect();
+
+        for a in &firsts {
+            for b in &seconds {
+                if a.name == b.name || !include(&a.name, &b.name) {
+                    continue;
+                }
+
+                let existing = edges.iter_mut().find(|e| {
+                    (e.card_a == a.name && e.card_b == b.name)
+                        || (e.card_a == b.name && e.card_b == a.name)
+                });
+                let edge = SynergyEdge {
+                    card_a: a.name.clone(),
+                    card_b: b.name.clone(),
+                    relation: rule.relation,
+                    themes: vec![rule.theme.clone()],
+                    strength: rule.strength,
+                    reason: rule.reason_for(&a.name, &b.name),
+                };
+
+                match existing {
+                    Some(existing) if existing.strength >= rule.strength => {}
+                    Some(existing) => *existing = edge,
+                    None => edges.push(edge),
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    fn edge_between<'a>(edges: &'a [SynergyEdge], a: &str, b: &str) -> Option<&'a SynergyEdge> {
+        edges.iter().find(|e| e.card_a == a && e.card_b == b)
+    }
+
+    #[test]
+    fn test_sacrifice_outlet_enables_death_trigger() {
+        let altar = card("Viscera Seer")
+            .type_line("Creature — Vampire Wizard")
+            .oracle("Sacrifice a creature: Scry 1.")
+            .build();
+        let artist = card("Blood Artist")
+            .type_line("Creature — Vampire")
+            .oracle("Whenever Blood Artist or another creature dies, target player loses 1 life and you gain 1 life.")
+            .build();
+
+        let edges = find_interactions(&[&altar, &artist], |_, _| true);
+        let edge = edge_between(&edges, "Viscera Seer", "Blood Artist").unwrap();
+        assert_eq!(edge.relation, SynergyRelation::Enables);
+        assert_eq!(
+            edge.reason,
+            "Viscera Seer can sacrifice creatures on demand to trigger Blood Artist"
+        );
+    }
+
+    #[test]
+    fn test_lifegain_payoff() {
+        let payoff = card("Ajani's Pridemate")
+            .type_line("Creature — Cat Soldier")
+            .oracle("Whenever you gain life, put a +1/+1 counter on Ajani's Pridemate.")
+            .build();
+        let lifelinker = card("Vampire Nighthawk")
+            .type_line("Creature — Vampire")
+            .oracle("Flying, deathtouch, lifelink")
+            .build();
+
+        let edges = find_interactions(&[&lifelinker, &payoff], |_, _| true);
+        let edge = edge_between(&edges, "Ajani's Pridemate", "Vampire Nighthawk").unwrap();
+        assert_eq!(edge.relation, SynergyRelation::PayoffFor);
+        assert_eq!(edges.len(), 1);
+    }
+
+    #[test]
+    fn test_blink_and_anthem_interactions() {
+        let blink = card("Ephemerate")
+            .type_line("Instant")
+            .oracle("Exile target creature you control, then return it to the battlefield under its owner's control.\nRebound")
+            .build();
+        let etb = card("Mulldrifter")
+            .type_line("Creature — Elemental")
+            .oracle("Flying\nWhen Mulldrifter enters, draw two cards.\nEvoke {2}{U}")
+            .build();
+        let maker = card("Raise the Alarm")
+            .type_line("Instant")
+            .oracle("Create two 1/1 white Soldier creature tokens.")
+            .build();
+        let anthem = card("Intangible Virtue")
+            .type_line("Enchantment")
+            .oracle("Creature tokens you control get +1/+1 and have vigilance.")
+            .build();
+
+        let edges = find_interactions(&[&blink, &etb, &maker, &anthem], |_, _| true);
+        assert_eq!(
+            edge_between(&edges, "Ephemerate", "Mulldrifter")
+                .unwrap()
+                .relation,
+            SynergyRelation::Enables
+        );
+        assert_eq!(
+            edge_between(&edges, "Intangible Virtue", "Raise the Alarm")
+                .unwrap()
+                .relation,
+            SynergyRelation::PayoffFor
+        );
+        // The blink spell isn't a creature with an enter trigger of its own
+        assert!(edge_between(&edges, "Mulldrifter", "Ephemerate").is_none());
+    }
+
+    #[test]
+    fn test_unrelated_cards_do_not_interact() {
+        let bolt = card("Lightning Bolt")
+            .type_line("Instant")
+            .oracle("Lightning Bolt deals 3 damage to any target.")
+            .build();
+        let bear = card("Grizzly Bears").type_line("Creature — Bear").build();
+        assert!(find_interactions(&[&bolt, &bear], |_, _| true).is_empty());
+    }
+}
@@ -0,0 +1,430 @@
+//! Keyword abilities, keyword actions and ability words from the
+//! Comprehensive Rules (702, 701 and 207.2c), and matching of keyword
+//! ability lines against them.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::types::Keyword;
+
+lazy_static! {
+    /// Landwalk abilities ("Islandwalk", "Nonbasic landwalk")
+    static ref LANDWALK: Regex = Regex::new(
+        r"(?i)^(?:(?:nonbasic|legendary|snow|artifact) )?(?:plains|island|swamp|mountain|forest|desert|land)walk\b"
+    )
+    .unwrap();
+
+    /// Cycling and typecycling ("Cycling", "Basic landcycling", "Wizardcycling")
+    static ref CYCLING: Regex = Regex::new(r"(?i)^(?:basic )?[a-z]*cycling\b").unwrap();
+}
+
+/// A keyword found on a card
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeywordInstance {
+    pub keyword: Keyword,
+    /// Cost, number or text following the keyword (e.g. "{2}" for "Ward {2}")
+    pub parameter: Option<String>,
+    /// Whether the card gives the keyword to other objects rather than having it
+    pub granted: bool,
+}
+
+/// What may follow a keyword ability's name on a keyword line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordParam {
+    /// Nothing (e.g. "Flying")
+    None,
+    /// A mana or alternative cost (e.g. "Kicker {1}{G}", "Ward—Pay 3 life")
+    Cost,
+    /// A number (e.g. "Toxic 2", "Crew 3")
+    Number,
+    /// Free text (e.g. "Protection from red", "Affinity for artifacts")
+    Text,
+}
+
+/// Keyword abilities (CR 702)
+pub const KEYWORD_ABILITIES: &[(&str, KeywordParam)] = &[
+    ("Deathtouch", KeywordParam::None),
+    ("Defender", KeywordParam::None),
+    ("Double Strike", KeywordParam::None),
+    ("Enchant", KeywordParam::Text),
+    ("Equip", KeywordParam::Text),
+    ("First Strike", KeywordParam::None),
+    ("Flash", KeywordParam::None),
+    ("Flying", KeywordParam::None),
+    ("Haste", KeywordParam::None),
+    ("Hexproof", KeywordParam::Text),
+    ("Indestructible", KeywordParam::None),
+    ("Intimidate", KeywordParam::None),
+    ("Lifelink", KeywordParam::None),
+    ("Protection", KeywordParam::Text),
+    ("Reach", KeywordParam::None),
+    ("Shroud", KeywordParam::None),
+    ("Trample", KeywordParam::None),
+    ("Vigilance", KeywordParam::None),
+    ("Ward", KeywordParam::Cost),
+    ("Banding", KeywordParam::None),
+    ("Rampage", KeywordParam::Number),
+    ("Cumulative Upkeep", KeywordParam::Cost),
+    ("Flanking", KeywordParam::None),
+    ("Phasing", KeywordParam::None),
+    ("Buyback", KeywordParam::Cost),
+    ("Shadow", KeywordParam::None),
+    ("Cycling", KeywordParam::Cost),
+    ("Echo", KeywordParam::Cost),
+    ("Horsemanship", KeywordParam::None),
+    ("Fading", KeywordParam::Number),
+    ("Kicker", KeywordParam::Cost),
+    ("Multikicker", KeywordParam::Cost),
+    ("Flashback", KeywordParam::Cost),
+    ("Madness", KeywordParam::Cost),
+    ("Fear", KeywordParam::None),
+    ("Morph", KeywordParam::Cost),
+    ("Megamorph", KeywordParam::Cost),
+    ("Amplify", KeywordParam::Number),
+    ("Provoke", KeywordParam::None),
+    ("Storm", KeywordParam::None),
+    ("Affinity", KeywordParam::Text),
+    ("Entwine", KeywordParam::Cost),
+    ("Modular", KeywordParam::Number),
+    ("Sunburst", KeywordParam::None),
+    ("Bushido", KeywordParam::Number),
+    ("Soulshift", KeywordParam::Number),
+    ("Splice", KeywordParam::Text),
+    ("Offering", KeywordParam::None),
+    ("Ninjutsu", KeywordParam::Cost),
+    ("Commander Ninjutsu", KeywordParam::Cost),
+    ("Epic", KeywordParam::None),
+    ("Convoke", KeywordParam::None),
+    ("Dredge", KeywordParam::Number),
+    ("Transmute", KeywordParam::Cost),
+    ("Bloodthirst", KeywordParam::Number),
+    ("Haunt", KeywordParam::None),
+    ("Replicate", KeywordParam::Cost),
+    ("Forecast", KeywordParam::Text),
+    ("Graft", KeywordParam::Number),
+    ("Recover", KeywordParam::Cost),
+    ("Ripple", KeywordParam::Number),
+    ("Split Second", KeywordParam::None),
+    ("Suspend", KeywordParam::Cost),
+    ("Vanishing", KeywordParam::Number),
+    ("Absorb", KeywordParam::Number),
+    ("Aura Swap", KeywordParam::Cost),
+    ("Delve", KeywordParam::None),
+    ("Fortify", KeywordParam::Cost),
+    ("Frenzy", KeywordParam::Number),
+    ("Gravestorm", KeywordParam::None),
+    ("Poisonous", KeywordParam::Number),
+    ("Transfigure", KeywordParam::Cost),
+    ("Champion", KeywordParam::Text),
+    ("Changeling", KeywordParam::None),
+    ("Evoke", KeywordParam::Cost),
+    ("Hideaway", KeywordParam::Number),
+    ("Prowl", KeywordParam::Cost),
+    ("Reinforce", KeywordParam::Cost),
+    ("Conspire", KeywordParam::None),
+    ("Persist", KeywordParam::None),
+    ("Wither", KeywordParam::None),
+    ("Retrace", KeywordParam::None),
+    ("Devour", KeywordParam::Number),
+    ("Exalted", KeywordParam::None),
+    ("Unearth", KeywordParam::Cost),
+    ("Cascade", KeywordParam::None),
+    ("Annihilator", KeywordParam::Number),
+    ("Level Up", KeywordParam::Cost),
+    ("Rebound", KeywordParam::None),
+    ("Umbra Armor", KeywordParam::None),
+    ("Totem Armor", KeywordParam::None),
+    ("Infect", KeywordParam::None),
+    ("Battle Cry", KeywordParam::None),
+    ("Living Weapon", KeywordParam::None),
+    ("Undying", KeywordParam::None),
+    ("Miracle", KeywordParam::Cost),
+    ("Soulbond", KeywordParam::None),
+    ("Overload", KeywordParam::Cost),
+    ("Scavenge", KeywordParam::Cost),
+    ("Unleash", KeywordParam::None),
+    ("Cipher", KeywordParam::None),
+    ("Evolve", KeywordParam::None),
+    ("Extort", KeywordParam::None),
+    ("Fuse", KeywordParam::None),
+    ("Bestow", KeywordParam::Cost),
+    ("Tribute", KeywordParam::Number),
+    ("Dethrone", KeywordParam::None),
+    ("Hidden Agenda", KeywordParam::None),
+    ("Outlast", KeywordParam::Cost),
+    ("Prowess", KeywordParam::None),
+    ("Dash", KeywordParam::Cost),
+    ("Exploit", KeywordParam::None),
+    ("Menace", KeywordParam::None),
+    ("Renown", KeywordParam::Number),
+    ("Awaken", KeywordParam::Cost),
+    ("Devoid", KeywordParam::None),
+    ("Ingest", KeywordParam::None),
+    ("Myriad", KeywordParam::None),
+    ("Surge", KeywordParam::Cost),
+    ("Skulk", KeywordParam::None),
+    ("Emerge", KeywordParam::Cost),
+    ("Escalate", KeywordParam::Cost),
+    ("Melee", KeywordParam::None),
+    ("Crew", KeywordParam::Number),
+    ("Fabricate", KeywordParam::Number),
+    ("Partner", KeywordParam::Text),
+    ("Undaunted", KeywordParam::None),
+    ("Improvise", KeywordParam::None),
+    ("Aftermath", KeywordParam::None),
+    ("Embalm", KeywordParam::Cost),
+    ("Eternalize", KeywordParam::Cost),
+    ("Afflict", KeywordParam::Number),
+    ("Ascend", KeywordParam::None),
+    ("Assist", KeywordParam::None),
+    ("Jump-Start", KeywordParam::None),
+    ("Mentor", KeywordParam::None),
+    ("Afterlife", KeywordParam::Number),
+    ("Riot", KeywordParam::None),
+    ("Spectacle", KeywordParam::Cost),
+    ("Escape", KeywordParam::Cost),
+    ("Companion", KeywordParam::Cost),
+    ("Mutate", KeywordParam::Cost),
+    ("Encore", KeywordParam::Cost),
+    ("Boast", KeywordParam::Cost),
+    ("Foretell", KeywordParam::Cost),
+    ("Demonstrate", KeywordParam::None),
+    ("Daybound", KeywordParam::None),
+    ("Nightbound", KeywordParam::None),
+    ("Disturb", KeywordParam::Cost),
+    ("Decayed", KeywordParam::None),
+    ("Cleave", KeywordParam::Cost),
+    ("Training", KeywordParam::None),
+    ("Compleated", KeywordParam::None),
+    ("Reconfigure", KeywordParam::Cost),
+    ("Blitz", KeywordParam::Cost),
+    ("Casualty", KeywordParam::Number),
+    ("Enlist", KeywordParam::None),
+    ("Read Ahead", KeywordParam::None),
+    ("Ravenous", KeywordParam::None),
+    ("Squad", KeywordParam::Cost),
+    ("Space Sculptor", KeywordParam::None),
+    ("Visit", KeywordParam::None),
+    ("Prototype", KeywordParam::Cost),
+    ("Living Metal", KeywordParam::None),
+    ("More Than Meets the Eye", KeywordParam::Cost),
+    ("For Mirrodin!", KeywordParam::None),
+    ("Toxic", KeywordParam::Number),
+    ("Backup", KeywordParam::Number),
+    ("Bargain", KeywordParam::None),
+    ("Craft", KeywordParam::Text),
+    ("Disguise", KeywordParam::Cost),
+    ("Solved", KeywordParam::None),
+    ("Plot", KeywordParam::Cost),
+    ("Saddle", KeywordParam::Number),
+    ("Spree", KeywordParam::None),
+    ("Freerunning", KeywordParam::Cost),
+    ("Gift", KeywordParam::Text),
+    ("Offspring", KeywordParam::Cost),
+    ("Impending", KeywordParam::Cost),
+    ("Exhaust", KeywordParam::None),
+    ("Max Speed", KeywordParam::None),
+    ("Start Your Engines!", KeywordParam::None),
+    ("Harmonize", KeywordParam::Cost),
+    ("Mobilize", KeywordParam::Number),
+    ("Job Select", KeywordParam::None),
+    ("Tiered", KeywordParam::None),
+    ("Station", KeywordParam::None),
+    ("Warp", KeywordParam::Cost),
+];
+
+/// Keyword actions (CR 701) that say something about a card's strategy.
+///
+/// Everyday actions such as cast, destroy, exile and sacrifice are left out
+/// because nearly every card uses them.
+pub const KEYWORD_ACTIONS: &[&str] = &[
+    "Adapt",
+    "Amass",
+    "Bolster",
+    "Clash",
+    "Cloak",
+    "Collect Evidence",
+    "Connive",
+    "Detain",
+    "Discover",
+    "Endure",
+    "Exert",
+    "Explore",
+    "Fateseal",
+    "Fight",
+    "Forage",
+    "Goad",
+    "Incubate",
+    "Investigate",
+    "Learn",
+    "Manifest",
+    "Manifest Dread",
+    "Meld",
+    "Mill",
+    "Monstrosity",
+    "Populate",
+    "Proliferate",
+    "Regenerate",
+    "Scry",
+    "Support",
+    "Surveil",
+    "Suspect",
+    "Time Travel",
+    "Transform",
+    "Venture into the Dungeon",
+    "Vote",
+];
+
+/// Rules forms of keyword actions that are also everyday words, so that only
+/// the action itself matches ("support 2", "learn.", "votes for"). Other
+/// keyword actions match their name anywhere in a sentence.
+pub const ANCHORED_ACTIONS: &[(&str, &str)] = &[
+    ("Learn", r"\blearn\."),
+    ("Support", r"(?:^|[.,:—]\s*)support (?:\d+|x)\b"),
+    (
+        "Transform",
+        r"\btransform (?:CARDNAME|it|them|that|target|up to)\b|\btransforms into\b|\btransformed\b",
+    ),
+    ("Vote", r"\bvote(?:s|d)? for\b"),
+];
+
+/// Ability words (CR 207.2c); they label an ability as "Word — ..." and have
+/// no rules meaning of their own
+pub const ABILITY_WORDS: &[&str] = &[
+    "Adamant",
+    "Addendum",
+    "Alliance",
+    "Battalion",
+    "Bloodrush",
+    "Celebration",
+    "Channel",
+    "Chroma",
+    "Cohort",
+    "Constellation",
+    "Converge",
+    "Corrupted",
+    "Council's Dilemma",
+    "Coven",
+    "Delirium",
+    "Descend 4",
+    "Descend 8",
+    "Domain",
+    "Eerie",
+    "Eminence",
+    "Enrage",
+    "Fateful Hour",
+    "Fathomless Descent",
+    "Ferocious",
+    "Flurry",
+    "Formidable",
+    "Grandeur",
+    "Hellbent",
+    "Heroic",
+    "Imprint",
+    "Inspired",
+    "Join Forces",
+    "Kinship",
+    "Landfall",
+    "Lieutenant",
+    "Magecraft",
+    "Metalcraft",
+    "Morbid",
+    "Pack Tactics",
+    "Paradox",
+    "Parley",
+    "Radiance",
+    "Raid",
+    "Rally",
+    "Renew",
+    "Revolt",
+    "Spell Mastery",
+    "Strive",
+    "Survival",
+    "Sweep",
+    "Tempting Offer",
+    "Threshold",
+    "Undergrowth",
+    "Valiant",
+    "Will of the Council",
+];
+
+/// Match a keyword ability name at the start of `text`.
+///
+/// Returns the keyword, what may follow it and the length of the name.
+pub(super) fn match_ability_name(text: &str) -> Option<(Keyword, KeywordParam, usize)> {
+    if let Some(m) = LANDWALK.find(text) {
+        return Some((Keyword::Landwalk, KeywordParam::None, m.end()));
+    }
+    if let Some(m) = CYCLING.find(text) {
+        return Some((Keyword::Cycling, KeywordParam::Cost, m.end()));
+    }
+
+    KEYWORD_ABILITIES
+        .iter()
+        .filter(|(name, _)| {
+            text.get(..name.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+                && !text[name.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '-')
+        })
+        .max_by_key(|(name, _)| name.len())
+        .map(|(name, param)| (Keyword::from_name(name), *param, name.len()))
+}
+
+/// Whether `value` is a valid parameter for a keyword taking `param`
+pub(super) fn valid_parameter(param: KeywordParam, value: &str) -> bool {
+    match param {
+        KeywordParam::None => value.is_empty(),
+        KeywordParam::Number => {
+            !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == 'X')
+        }
+        KeywordParam::Cost => {
+            value.is_empty()
+                || value.contains('{')
+                || value.chars().all(|c| c.is_ascii_digit() || c == 'X')
+        }
+        KeywordParam::Text => true,
+    }
+}
+
+/// Parse a line made up only of keyword abilities, such as "Flying, vigilance"
+/// or "Ward—Pay 3 life". Returns `None` if anything on the line isn't a keyword.
+pub(super) fn parse_keyword_line(line: &str) -> Option<Vec<KeywordInstance>> {
+    let mut rest = line.trim().trim_end_matches('.');
+    let mut found = Vec::new();
+
+    while !rest.is_empty() {
+        let (keyword, param, len) = match_ability_name(rest)?;
+        let after = &rest[len..];
+
+        // "Ward—Pay 3 life": the cost runs to the end of the line
+        if let Some(cost) = after.trim_start().strip_prefix('—') {
+            if param == KeywordParam::None || param == KeywordParam::Number {
+                return None;
+            }
+            found.push(KeywordInstance {
+                keyword,
+                parameter: Some(cost.trim().to_string()),
+                granted: false,
+            });
+            break;
+        }
+
+        let (value, next) = match after.find(", ") {
+            Some(idx) => (&after[..idx], &after[idx + 2..]),
+            None => (after, ""),
+        };
+        let value = value.trim();
+        if !valid_parameter(param, value) {
+            return None;
+        }
+
+        found.push(KeywordInstance {
+            keyword,
+            parameter: (!value.is_empty()).then(|| value.to_string()),
+            granted: false,
+        });
+        rest = next;
+    }
+
+    (!found.is_empty()).then_some(found)
+}
@@ -4,49 +4,36 @@ use crate::api::Card;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use super::keyword_table::{
+    match_ability_name, parse_keyword_line, valid_parameter, ABILITY_WORDS, ANCHORED_ACTIONS,
+    KEYWORD_ACTIONS,
+};
+use super::oracle::{AbilityKind, NormalizedOracle};
+
+pub use super::keyword_table::KeywordInstance;
 use super::types::Keyword;
 
 lazy_static! {
-    /// Patterns for detecting keywords in oracle text
-    static ref KEYWORD_PATTERNS: Vec<(Regex, Keyword)> = vec![
-        // Evergreen keywords
-        (Regex::new(r"(?i)\bflying\b").unwrap(), Keyword::Flying),
-        (Regex::new(r"(?i)\btrample\b").unwrap(), Keyword::Trample),
-        (Regex::new(r"(?i)\bhaste\b").unwrap(), Keyword::Haste),
-        (Regex::new(r"(?i)\bvigilance\b").unwrap(), Keyword::Vigilance),
-        (Regex::new(r"(?i)\bdeathtouch\b").unwrap(), Keyword::Deathtouch),
-        (Regex::new(r"(?i)\blifelink\b").unwrap(), Keyword::Lifelink),
-        (Regex::new(r"(?i)\bfirst strike\b").unwrap(), Keyword::FirstStrike),
-        (Regex::new(r"(?i)\bdouble strike\b").unwrap(), Keyword::DoubleStrike),
-        (Regex::new(r"(?i)\bmenace\b").unwrap(), Keyword::Menace),
-        (Regex::new(r"(?i)\breach\b").unwrap(), Keyword::Reach),
-        (Regex::new(r"(?i)\bflash\b").unwrap(), Keyword::Flash),
-        (Regex::new(r"(?i)\bhexproof\b").unwrap(), Keyword::Hexproof),
-        (Regex::new(r"(?i)\bindestructible\b").unwrap(), Keyword::Indestructible),
-        (Regex::new(r"(?i)\bdefender\b").unwrap(), Keyword::Defender),
-        (Regex::new(r"(?i)\bward\b").unwrap(), Keyword::Ward),
-
-        // Set mechanics
-        (Regex::new(r"(?i)\bflashback\b").unwrap(), Keyword::Flashback),
-        (Regex::new(r"(?i)\bunearth\b").unwrap(), Keyword::Unearth),
-        (Regex::new(r"(?i)\bescape\b").unwrap(), Keyword::Escape),
-        (Regex::new(r"(?i)\bdelve\b").unwrap(), Keyword::Delve),
-        (Regex::new(r"(?i)\bconvoke\b").unwrap(), Keyword::Convoke),
-        (Regex::new(r"(?i)\bcascade\b").unwrap(), Keyword::Cascade),
-        (Regex::new(r"(?i)\bstorm\b").unwrap(), Keyword::Storm),
-        (Regex::new(r"(?i)\bproliferate\b").unwrap(), Keyword::Proliferate),
-        (Regex::new(r"(?i)\blandfall\b").unwrap(), Keyword::Landfall),
-        (Regex::new(r"(?i)\bconstellation\b").unwrap(), Keyword::Constellation),
-        (Regex::new(r"(?i)\bdevotion\b").unwrap(), Keyword::Devotion),
-        (Regex::new(r"(?i)\bannihilator\b").unwrap(), Keyword::Annihilator),
-        (Regex::new(r"(?i)\binfect\b").unwrap(), Keyword::Infect),
-        (Regex::new(r"(?i)\bwither\b").unwrap(), Keyword::Wither),
-        (Regex::new(r"(?i)\baffinity\b").unwrap(), Keyword::Affinity),
-        (Regex::new(r"(?i)\bmadness\b").unwrap(), Keyword::Madness),
-        (Regex::new(r"(?i)\boverload\b").unwrap(), Keyword::Overload),
-        (Regex::new(r"(?i)\bcrew\b").unwrap(), Keyword::Crew),
-        (Regex::new(r"(?i)\bequip\b").unwrap(), Keyword::Equip),
-    ];
+    /// Keyword actions as they appear in sentences ("scry 2", "mills three cards")
+    static ref ACTION_PATTERNS: Vec<(Regex, Keyword)> = KEYWORD_ACTIONS
+        .iter()
+        .map(|name| {
+            let pattern = match ANCHORED_ACTIONS.iter().find(|(action, _)| action == name) {
+                Some((_, form)) => format!("(?im){form}"),
+                None => format!(r"(?i)\b{}(?:s|es|ed|d)?\b", regex::escape(name)),
+            };
+            (Regex::new(&pattern).unwrap(), Keyword::from_name(name))
+        })
+        .collect();
+
+    /// Phrases that mention a keyword action without performing it
+    static ref NEGATED_ACTIONS: Regex = Regex::new(r"(?i)can't be regenerated").unwrap();
+
+    /// Devotion isn't a keyword but is tracked like one
+    static ref DEVOTION: Regex = Regex::new(r"(?i)\bdevotion to\b").unwrap();
+
+    /// Clauses that give keywords to something ("creatures you control have flying")
+    static ref GRANT_CLAUSE: Regex = Regex::new(r#"(?i)\b(?:have|has|gain|gains)\s+([^.;:"“]+)"#).unwrap();
 
     /// Common creature types for tribal detection
     static ref COMMON_CREATURE_TYPES: Vec<&'static str> = vec![
@@ -59,30 +46,135 @@ lazy_static! {
     ];
 }
 
-/// Extract keywords from a card's oracle text
-pub fn extract_keywords(card: &Card) -> Vec<Keyword> {
-    let mut keywords = Vec::new();
-    let mut seen = std::collections::HashSet::new();
+/// Phrases that end a granted-keyword clause
+const GRANT_CLAUSE_ENDS: &[&str] = &[
+    " until ",
+    " as long as ",
+    " for as long as ",
+    " this turn",
+    " if ",
+    " unless ",
+    " where ",
+];
 
-    // Combine all oracle text from main card and faces
-    let all_text: String = card
-        .all_oracle_text()
-        .into_iter()
-        .collect::<Vec<_>>()
-        .join(" ");
-
-    for (pattern, keyword) in KEYWORD_PATTERNS.iter() {
-        if pattern.is_match(&all_text) {
-            // Use display_name as key to avoid duplicates
-            let key = keyword.display_name();
-            if !seen.contains(&key) {
-                seen.insert(key);
-                keywords.push(keyword.clone());
+/// Find keywords a sentence gives to other objects
+fn parse_granted_keywords(line: &str) -> Vec<KeywordInstance> {
+    let mut found = Vec::new();
+
+    for caps in GRANT_CLAUSE.captures_iter(line) {
+        let mut clause = caps[1].to_string();
+        let lower = clause.to_lowercase();
+        if let Some(end) = GRANT_CLAUSE_ENDS.iter().filter_map(|e| lower.find(e)).min() {
+            clause.truncate(end);
+        }
+
+        let clause = clause
+            .replace(", and ", ", ")
+            .replace(" and ", ", ")
+            .replace(" or ", ", ");
+        for item in clause.split(", ").map(str::trim) {
+            let Some((keyword, param, len)) = match_ability_name(item) else {
+                continue;
+            };
+            let value = item[len..].trim();
+            if valid_parameter(param, value) {
+                found.push(KeywordInstance {
+                    keyword,
+                    parameter: (!value.is_empty()).then(|| value.to_string()),
+                    granted: true,
+                });
+            }
+        }
+    }
+
+    found
+}
+
+/// Extract every keyword on a card, inherent and granted.
+///
+/// Keyword abilities only count on lines made up entirely of keywords, so
+/// "target creature with flying" doesn't make a card fly, and reminder text
+/// is ignored. Keyword actions and ability words count wherever they appear.
+pub fn extract_keyword_instances(card: &Card) -> Vec<KeywordInstance> {
+    oracle_keyword_instances(&NormalizedOracle::new(card))
+}
+
+/// Extract every keyword from a card's already normalized oracle text
+pub fn oracle_keyword_instances(oracle: &NormalizedOracle) -> Vec<KeywordInstance> {
+    let mut found: Vec<KeywordInstance> = Vec::new();
+    let mut push = |instance: KeywordInstance| {
+        if !found
+            .iter()
+            .any(|f| f.keyword == instance.keyword && f.granted == instance.granted)
+        {
+            found.push(instance);
+        }
+    };
+
+    for line in oracle.lines() {
+        if line.kind == AbilityKind::Keyword {
+            if let Some(keywords) = parse_keyword_line(&line.text) {
+                keywords.into_iter().for_each(&mut push);
+                continue;
+            }
+        }
+
+        if let Some((label, _)) = line.text.split_once(" — ") {
+            if let Some(word) = ABILITY_WORDS
+                .iter()
+                .find(|w| w.eq_ignore_ascii_case(label.trim()))
+            {
+                push(KeywordInstance {
+                    keyword: Keyword::from_name(word),
+                    parameter: None,
+                    granted: false,
+                });
             }
         }
+
+        parse_granted_keywords(&line.text)
+            .into_iter()
+            .for_each(&mut push);
+    }
+
+    let text = oracle.text();
+    let text = NEGATED_ACTIONS.replace_all(&text, "");
+    for (pattern, keyword) in ACTION_PATTERNS.iter() {
+        if pattern.is_match(&text) {
+            push(KeywordInstance {
+                keyword: keyword.clone(),
+                parameter: None,
+                granted: false,
+            });
+        }
     }
+    if DEVOTION.is_match(&text) {
+        push(KeywordInstance {
+            keyword: Keyword::Devotion,
+            parameter: None,
+            granted: false,
+        });
+    }
+
+    found
+}
+
+/// Extract the keywords a card has itself
+pub fn extract_keywords(card: &Card) -> Vec<Keyword> {
+    extract_keyword_instances(card)
+        .into_iter()
+        .filter(|k| !k.granted)
+        .map(|k| k.keyword)
+        .collect()
+}
 
-    keywords
+/// Extract the keywords a card gives to other objects
+pub fn extract_granted_keywords(card: &Card) -> Vec<Keyword> {
+    extract_keyword_instances(card)
+        .into_iter()
+        .filter(|k| k.granted)
+        .map(|k| k.keyword)
+        .collect()
 }
 
 /// Extract creature types from a card's type line
@@ -205,6 +297,139 @@ mod tests {
         assert!(keywords.contains(&Keyword::Lifelink));
     }
 
+    #[test]
+    fn test_reminder_text_is_ignored() {
+        let card = mock_card(
+            "Reach (This creature can block creatures with flying.)",
+            "Creature — Spider",
+        );
+        let keywords = extract_keywords(&card);
+        assert_eq!(keywords, vec![Keyword::Reach]);
+
+        let mulldrifter = mock_card(
+            "Flying\nWhen this creature enters, draw two cards.\nEvoke {2}{U} (You may cast this spell for its evoke cost. If you do, it's sacrificed when it enters.)",
+            "Creature — Elemental",
+        );
+        let keywords = extract_keywords(&mulldrifter);
+        assert!(keywords.contains(&Keyword::Flying));
+        assert!(keywords.contains(&Keyword::Evoke));
+    }
+
+    #[test]
+    fn test_parameterized_keywords() {
+        let card = mock_card("Ward {2}\nToxic 2\nBackup 1", "Creature — Phyrexian");
+        let instances = extract_keyword_instances(&card);
+        let param = |k: Keyword| {
+            instances
+                .iter()
+                .find(|i| i.keyword == k)
+                .and_then(|i| i.parameter.clone())
+        };
+        assert_eq!(param(Keyword::Ward), Some("{2}".to_string()));
+        assert_eq!(param(Keyword::Toxic), Some("2".to_string()));
+        assert_eq!(param(Keyword::Backup), Some("1".to_string()));
+
+        let card = mock_card("Flying\nWard—Pay 3 life.", "Creature — Dragon");
+        let instances = extract_keyword_instances(&card);
+        let ward = instances
+            .iter()
+            .find(|i| i.keyword == Keyword::Ward)
+            .unwrap();
+        assert_eq!(ward.parameter.as_deref(), Some("Pay 3 life"));
+
+        let card = mock_card(
+            "Draw a card.\nBasic landcycling {1}{G} ({1}{G}, Discard this card: Search your library for a basic land card.)",
+            "Sorcery",
+        );
+        assert!(extract_keywords(&card).contains(&Keyword::Cycling));
+    }
+
+    #[test]
+    fn test_granted_keywords_are_separate() {
+        let card = mock_card(
+            "Creatures you control have flying and vigilance.",
+            "Enchantment",
+        );
+        assert!(extract_keywords(&card).is_empty());
+        let granted = extract_granted_keywords(&card);
+        assert_eq!(granted, vec![Keyword::Flying, Keyword::Vigilance]);
+
+        let card = mock_card(
+            "Target creature gains hexproof and indestructible until end of turn.",
+            "Instant",
+        );
+        assert_eq!(
+            extract_granted_keywords(&card),
+            vec![Keyword::Hexproof, Keyword::Indestructible]
+        );
+    }
+
+    #[test]
+    fn test_keyword_mentions_are_not_keywords() {
+        let card = mock_card(
+            "Destroy target creature with flying. It can't be regenerated.",
+            "Instant",
+        );
+        assert!(extract_keyword_instances(&card).is_empty());
+
+        let card = mock_card(
+            "Landfall — Whenever a land you control enters, scry 1.",
+            "Creature — Elemental",
+        );
+        let keywords = extract_keywords(&card);
+        assert!(keywords.contains(&Keyword::Landfall));
+        assert!(keywords.contains(&Keyword::Scry));
+    }
+
+    #[test]
+    fn test_everyday_words_need_the_action_form() {
+        let cases = [
+            (
+                "When this creature enters, support 2.",
+                Keyword::from_name("Support"),
+            ),
+            (
+                "Whenever a creature you control dies, learn.",
+                Keyword::from_name("Learn"),
+            ),
+            (
+                "At the beginning of your upkeep, transform CARDNAME.",
+                Keyword::from_name("Transform"),
+            ),
+            (
+                "Will of the council — Starting with you, each player votes for time or money.",
+                Keyword::from_name("Vote"),
+            ),
+        ];
+        for (text, keyword) in cases {
+            assert!(
+                extract_keywords(&mock_card(text, "Sorcery")).contains(&keyword),
+                "{text}"
+            );
+        }
+
+        let card = mock_card(
+            "Whenever this creature attacks, creatures that support it get +1/+1.",
+            "Creature — Soldier",
+        );
+        assert!(!extract_keywords(&card).contains(&Keyword::from_name("Support")));
+
+        let card = mock_card(
+            "Target opponent reveals their hand and you learn which cards are in it.",
+            "Sorcery",
+        );
+        assert!(!extract_keywords(&card).contains(&Keyword::from_name("Learn")));
+
+        let card = mock_card("Players can't transform permanents.", "Enchantment");
+        assert!(!extract_keywords(&card).contains(&Keyword::from_name("Transform")));
+
+        let card = mock_card(
+            "Each player may vote to skip their draw step.",
+            "Enchantment",
+        );
+        assert!(!extract_keywords(&card).contains(&Keyword::from_name("Vote")));
+    }
+
     #[test]
     fn test_extract_creature_types() {
         let card = mock_card("", "Creature — Human Wizard");
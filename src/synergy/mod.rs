@@ -1,5 +1,11 @@
+pub mod archetype;
+pub mod clusters;
 pub mod detector;
+pub mod interactions;
+pub mod keyword_table;
 pub mod keywords;
+pub mod oracle;
+pub mod rules;
 pub mod themes;
 pub mod types;
 
@@ -0,0 +1,305 @@
+//! Oracle text preprocessing shared by theme detection, keyword extraction
+//! and LLM prompts.
+//!
+//! Raw oracle text trips up pattern matching in two ways: reminder text
+//! mentions keywords and effects the card doesn't have, and a card's own name
+//! can contain words like "Token" or "Zombie". `NormalizedOracle` removes
+//! reminder text, replaces self-references with `CARDNAME` and splits each
+//! face into classified ability lines.
+
+use crate::api::Card;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::keyword_table::parse_keyword_line;
+
+/// Placeholder for a card's references to itself
+pub const SELF_REFERENCE: &str = "CARDNAME";
+
+lazy_static! {
+    /// Reminder text in parentheses
+    static ref REMINDER_TEXT: Regex = Regex::new(r"\s*\([^)]*\)").unwrap();
+
+    /// Ability word or flavor word label ("Landfall — ")
+    static ref ABILITY_LABEL: Regex = Regex::new(r"^[A-Z][\w' ]*? — ").unwrap();
+
+    /// Triggered abilities start with "When", "Whenever" or "At"
+    static ref TRIGGER: Regex = Regex::new(r"^(?:When|Whenever|At)\b").unwrap();
+
+    /// Activated abilities have a cost followed by a colon, before any quoted text
+    static ref ACTIVATION: Regex = Regex::new(r#"^[^"“.]+:"#).unwrap();
+}
+
+/// What kind of ability an oracle line is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbilityKind {
+    /// A line made up of keyword abilities ("Flying, vigilance")
+    Keyword,
+    /// "When", "Whenever" or "At" abilities
+    Triggered,
+    /// "Cost: Effect" abilities, including loyalty abilities
+    Activated,
+    /// Abilities that are always on
+    Static,
+    /// The effect of an instant or sorcery
+    Spell,
+}
+
+/// A single ability from a card's oracle text
+#[derive(Debug, Clone, PartialEq)]
+pub struct OracleLine {
+    pub kind: AbilityKind,
+    pub text: String,
+}
+
+/// A card's oracle text with reminder text removed, self-references replaced
+/// and abilities split into lines, one list per face
+#[derive(Debug, Clone, Default)]
+pub struct NormalizedOracle {
+    pub faces: Vec<Vec<OracleLine>>,
+}
+
+impl NormalizedOracle {
+    pub fn new(card: &Card) -> Self {
+        let names = self_names(card);
+        let mut faces = Vec::new();
+
+        if let Some(text) = &card.oracle_text {
+            faces.push(split_abilities(text, &card.type_line, &names));
+        }
+        for face in card.card_faces.iter().flatten() {
+            if let Some(text) = &face.oracle_text {
+                let type_line = face.type_line.as_deref().unwrap_or(&card.type_line);
+                faces.push(split_abilities(text, type_line, &names));
+            }
+        }
+
+        Self { faces }
+    }
+
+    /// Every ability line on every face
+    pub fn lines(&self) -> impl Iterator<Item = &OracleLine> {
+        self.faces.iter().flatten()
+    }
+
+    /// Text of each face, one ability per line
+    pub fn face_texts(&self) -> Vec<String> {
+        self.faces
+            .iter()
+            .map(|lines| {
+                lines
+                    .iter()
+                    .map(|l| l.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect()
+    }
+
+    /// All normalized text, one ability per line
+    pub fn text(&self) -> String {
+        self.face_texts().join("\n")
+    }
+}
+
+/// Remove reminder text, replacing the parentheses with nothing
+pub fn strip_reminder_text(text: &str) -> String {
+    REMINDER_TEXT.replace_all(text, "").to_string()
+}
+
+/// Names a card uses to refer to itself, longest first.
+///
+/// Legendary cards also use the part of their name before the comma
+/// ("Krenko" for "Krenko, Mob Boss").
+fn self_names(card: &Card) -> Vec<String> {
+    let legendary = card
+        .all_type_lines()
+        .iter()
+        .any(|t| t.contains("Legendary"));
+
+    let mut names: Vec<String> = Vec::new();
+    let full_names = card
+        .name
+        .split(" // ")
+        .map(str::to_string)
+        .chain(card.card_faces.iter().flatten().map(|f| f.name.clone()));
+    for name in full_names {
+        if legendary {
+            if let Some((short, _)) = name.split_once(", ") {
+                names.push(short.to_string());
+            }
+        }
+        names.push(name);
+    }
+
+    names.retain(|n| n.len() >= 3);
+    names.sort_by_key(|n| std::cmp::Reverse(n.len()));
+    names.dedup();
+    names
+}
+
+/// Replace a card's references to itself with `CARDNAME`
+fn replace_self_references(text: &str, names: &[String]) -> String {
+    names.iter().fold(text.to_string(), |text, name| {
+        replace_word(&text, name, SELF_REFERENCE)
+    })
+}
+
+/// Replace whole-word occurrences of `word`, ignoring ones inside longer words
+fn replace_word(text: &str, word: &str, replacement: &str) -> String {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut output = String::with_capacity(text.len());
+    let mut copied = 0;
+
+    for (start, _) in text.match_indices(word) {
+        let end = start + word.len();
+        if text[..start].ends_with(is_word_char) || text[end..].starts_with(is_word_char) {
+            continue;
+        }
+        output.push_str(&text[copied..start]);
+        output.push_str(replacement);
+        copied = end;
+    }
+
+    output.push_str(&text[copied..]);
+    output
+}
+
+/// Classify one ability line
+fn classify_line(line: &str, spell: bool, previous: Option<AbilityKind>) -> AbilityKind {
+    // Modes belong to the ability that offers them
+    if line.starts_with('•') {
+        if let Some(kind) = previous {
+            return kind;
+        }
+    }
+    if parse_keyword_line(line).is_some() {
+        return AbilityKind::Keyword;
+    }
+
+    let body = ABILITY_LABEL
+        .find(line)
+        .map_or(line, |label| &line[label.end()..]);
+    if TRIGGER.is_match(body) {
+        AbilityKind::Triggered
+    } else if ACTIVATION.is_match(body) {
+        AbilityKind::Activated
+    } else if spell {
+        AbilityKind::Spell
+    } else {
+        AbilityKind::Static
+    }
+}
+
+/// Normalize one face's oracle text and split it into ability lines
+fn split_abilities(text: &str, type_line: &str, names: &[String]) -> Vec<OracleLine> {
+    let spell = type_line.contains("Instant") || type_line.contains("Sorcery");
+    let text = replace_self_references(&strip_reminder_text(text), names);
+
+    let mut lines: Vec<OracleLine> = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let kind = classify_line(line, spell, lines.last().map(|l| l.kind));
+        lines.push(OracleLine {
+            kind,
+            text: line.to_string(),
+        });
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::CardFace;
+    use crate::testing::card;
+
+    #[test]
+    fn test_strips_reminder_text_and_self_references() {
+        let creature = card("Krenko, Mob Boss")
+            .type_line("Legendary Creature — Goblin Warrior")
+            .oracle("{T}: Create X 1/1 red Goblin creature tokens, where X is the number of Goblins you control.")
+            .build();
+        let oracle = NormalizedOracle::new(&creature);
+        assert_eq!(oracle.faces[0][0].kind, AbilityKind::Activated);
+
+        let creature = card("Zombie Master")
+            .type_line("Creature — Zombie")
+            .oracle("Other Zombie creatures have swampwalk. (They can't be blocked as long as defending player controls a Swamp.)\nWhenever Zombie Master attacks, draw a card.")
+            .build();
+        let oracle = NormalizedOracle::new(&creature);
+        assert_eq!(
+            oracle.text(),
+            "Other Zombie creatures have swampwalk.\nWhenever CARDNAME attacks, draw a card."
+        );
+
+        // Only whole-word mentions are self-references
+        let creature = card("Ooze")
+            .type_line("Creature — Ooze")
+            .oracle("Other Oozes you control get +1/+1.\nOoze can't block.")
+            .build();
+        assert_eq!(
+            NormalizedOracle::new(&creature).text(),
+            "Other Oozes you control get +1/+1.\nCARDNAME can't block."
+        );
+    }
+
+    #[test]
+    fn test_classifies_ability_lines() {
+        let creature = card("Test Card")
+            .type_line("Creature — Beast")
+            .oracle("Flying, trample\nLandfall — Whenever a land you control enters, put a +1/+1 counter on target creature.\n{2}{G}, Sacrifice a creature: Draw a card.\nCreatures you control get +1/+1.\n+1: Scry 1.")
+            .build();
+        let kinds: Vec<_> = NormalizedOracle::new(&creature)
+            .lines()
+            .map(|l| l.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                AbilityKind::Keyword,
+                AbilityKind::Triggered,
+                AbilityKind::Activated,
+                AbilityKind::Static,
+                AbilityKind::Activated,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modal_spells_and_faces() {
+        let mut split = card("Fire // Ice").type_line("Instant // Instant").build();
+        split.oracle_text = None;
+        split.card_faces = Some(vec![
+            CardFace {
+                name: "Fire".to_string(),
+                mana_cost: None,
+                type_line: Some("Instant".to_string()),
+                oracle_text: Some(
+                    "Fire deals 2 damage divided as you choose among one or two targets."
+                        .to_string(),
+                ),
+                power: None,
+                toughness: None,
+                colors: None,
+            },
+            CardFace {
+                name: "Ice".to_string(),
+                mana_cost: None,
+                type_line: Some("Instant".to_string()),
+                oracle_text: Some(
+                    "Choose one —\n• Tap target permanent.\n• Draw a card.".to_string(),
+                ),
+                power: None,
+                toughness: None,
+                colors: None,
+            },
+        ]);
+
+        let oracle = NormalizedOracle::new(&split);
+        assert_eq!(oracle.faces.len(), 2);
+        assert!(oracle.faces[0][0]
+            .text
+            .starts_with("CARDNAME deals 2 damage"));
+        assert!(oracle.faces[1].iter().all(|l| l.kind == AbilityKind::Spell));
+    }
+}
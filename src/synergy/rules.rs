@@ -0,0 +1,218 @@
+use regex::Regex;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::themes::ThemeRule;
+use super::types::{SynergyRole, Theme};
+
+static CUSTOM_RULES: OnceLock<LoadedRules> = OnceLock::new();
+
+/// User-defined rules and the file they came from
+#[derive(Default)]
+struct LoadedRules {
+    path: Option<PathBuf>,
+    rules: Vec<ThemeRule>,
+}
+
+const DEFAULT_MIN_CONFIDENCE: f64 = 0.1;
+
+/// A user-defined theme rule as written in a rules file
+#[derive(Debug, Deserialize)]
+struct RuleSpec {
+    theme: String,
+    #[serde(default)]
+    oracle: Vec<String>,
+    #[serde(default)]
+    types: Vec<String>,
+    #[serde(default)]
+    role: Option<String>,
+    #[serde(default)]
+    min_confidence: Option<f64>,
+}
+
+/// Top-level layout of a rules file: `[[rule]]` tables in TOML, or a
+/// `rules` array in JSON
+#[derive(Debug, Deserialize)]
+struct RuleFile {
+    #[serde(default, alias = "rules")]
+    rule: Vec<RuleSpec>,
+}
+
+/// Rules files looked for in the config directory, in order
+pub fn default_rules_paths() -> Vec<PathBuf> {
+    let dir = dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("scry");
+    vec![dir.join("rules.toml"), dir.join("rules.json")]
+}
+
+/// Load theme rules from a TOML or JSON file, chosen by extension
+pub fn load_rules(path: &Path) -> Result<Vec<ThemeRule>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read rules file {}: {}", path.display(), e))?;
+
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let file: RuleFile = if is_json {
+        serde_json::from_str(&contents).map_err(|e| e.to_string())
+    } else {
+        toml::from_str(&contents).map_err(|e| e.to_string())
+    }
+    .map_err(|e| format!("Failed to parse rules file {}: {}", path.display(), e))?;
+
+    file.rule
+        .into_iter()
+        .map(build_rule)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Compile a rule spec into a `ThemeRule` with a `Theme::Custom` theme.
+///
+/// Patterns are matched case-insensitively.
+fn build_rule(spec: RuleSpec) -> Result<ThemeRule, String> {
+    let name = spec.theme.trim();
+    if name.is_empty() {
+        return Err("rule is missing a theme name".to_string());
+    }
+    if spec.oracle.is_empty() && spec.types.is_empty() {
+        return Err(format!(
+            "rule '{name}' needs at least one oracle or type pattern"
+        ));
+    }
+
+    let compile = |patterns: &[String]| -> Result<Vec<Regex>, String> {
+        patterns
+            .iter()
+            .map(|p| {
+                Regex::new(&format!("(?i){p}"))
+                    .map_err(|e| format!("rule '{name}' has an invalid pattern '{p}': {e}"))
+            })
+            .collect()
+    };
+
+    let role_hint = match spec.role.as_deref().map(str::to_lowercase).as_deref() {
+        None => None,
+        Some("enabler") => Some(SynergyRole::Enabler),
+        Some("payoff") => Some(SynergyRole::Payoff),
+        Some("support") => Some(SynergyRole::Support),
+        Some(other) => {
+            return Err(format!(
+                "rule '{name}' has unknown role '{other}' (expected enabler, payoff or support)"
+            ))
+        }
+    };
+
+    let min_confidence = spec.min_confidence.unwrap_or(DEFAULT_MIN_CONFIDENCE);
+    if !(0.0..=1.0).contains(&min_confidence) {
+        return Err(format!(
+            "rule '{name}' has min_confidence {min_confidence}, expected 0.0 - 1.0"
+        ));
+    }
+
+    Ok(ThemeRule {
+        theme: Theme::Custom(name.to_string()),
+        oracle_patterns: compile(&spec.oracle)?,
+        type_patterns: compile(&spec.types)?,
+        min_confidence,
+        role_hint,
+    })
+}
+
+/// Load user-defined rules for the rest of the run.
+///
+/// Uses `path` when given, otherwise the first rules file found in the config
+/// directory. If the file fails to load, no custom rules are loaded and only
+/// the built-in rules apply.
+pub fn init_custom_rules(path: Option<&Path>) -> Result<(), String> {
+    let path = match path {
+        Some(path) => Some(path.to_path_buf()),
+        None => default_rules_paths().into_iter().find(|p| p.exists()),
+    };
+
+    let rules = match &path {
+        Some(path) => load_rules(path)?,
+        None => Vec::new(),
+    };
+
+    CUSTOM_RULES
+        .set(LoadedRules { path, rules })
+        .map_err(|_| "Custom theme rules were already loaded".to_string())
+}
+
+/// User-defined rules loaded by `init_custom_rules` (empty if none)
+pub fn custom_rules() -> &'static [ThemeRule] {
+    &CUSTOM_RULES.get_or_init(LoadedRules::default).rules
+}
+
+/// The rules file loaded by `init_custom_rules`, if any
+pub fn custom_rules_path() -> Option<&'static Path> {
+    CUSTOM_RULES
+        .get_or_init(LoadedRules::default)
+        .path
+        .as_deref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_toml(contents: &str) -> Result<Vec<ThemeRule>, String> {
+        let file: RuleFile = toml::from_str(contents).map_err(|e| e.to_string())?;
+        file.rule.into_iter().map(build_rule).collect()
+    }
+
+    #[test]
+    fn test_parse_toml_rules() {
+        let rules = parse_toml(
+            r#"
+            [[rule]]
+            theme = "Landfall"
+            oracle = ["landfall", "whenever a land (you control )?enters"]
+            role = "payoff"
+
+            [[rule]]
+            theme = "Landfall"
+            oracle = ["play an additional land"]
+            role = "Enabler"
+            min_confidence = 0.5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].theme, Theme::Custom("Landfall".to_string()));
+        assert_eq!(rules[0].role_hint, Some(SynergyRole::Payoff));
+        assert!(rules[0].oracle_patterns[0].is_match("Landfall — Whenever"));
+        assert_eq!(rules[1].role_hint, Some(SynergyRole::Enabler));
+        assert_eq!(rules[1].min_confidence, 0.5);
+    }
+
+    #[test]
+    fn test_parse_json_rules() {
+        let file: RuleFile = serde_json::from_str(
+            r#"{"rules": [{"theme": "Vehicles", "types": ["\\bvehicle\\b"], "oracle": ["crew \\d"]}]}"#,
+        )
+        .unwrap();
+        let rules: Vec<ThemeRule> = file
+            .rule
+            .into_iter()
+            .map(build_rule)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rules[0].type_patterns.len(), 1);
+        assert_eq!(rules[0].min_confidence, DEFAULT_MIN_CONFIDENCE);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        assert!(parse_toml("[[rule]]\ntheme = \"Empty\"").is_err());
+        assert!(parse_toml("[[rule]]\ntheme = \"Bad\"\noracle = [\"(unclosed\"]").is_err());
+        assert!(
+            parse_toml("[[rule]]\ntheme = \"Bad\"\noracle = [\"x\"]\nrole = \"finisher\"").is_err()
+        );
+    }
+}
@@ -4,6 +4,8 @@ use regex::Regex;
 use std::collections::HashMap;
 
 use super::keywords::{is_artifact, is_aura, is_enchantment, is_equipment, is_land};
+use super::oracle::NormalizedOracle;
+use super::rules::custom_rules;
 use super::types::{CounterType, SynergyRole, Theme};
 
 /// A rule for detecting a theme from oracle text
@@ -249,43 +251,83 @@ lazy_static! {
     ];
 }
 
+/// How a single rule matched a card
+pub struct RuleMatch<'a> {
+    pub rule: &'a ThemeRule,
+    /// Patterns (oracle and type) that matched
+    pub matched_patterns: Vec<&'a str>,
+    pub confidence: f64,
+}
+
+impl RuleMatch<'_> {
+    /// Whether the match is confident enough to count
+    pub fn passes(&self) -> bool {
+        self.confidence >= self.rule.min_confidence
+    }
+}
+
+/// Match a rule against a card's combined oracle text and type line
+pub fn match_rule<'a>(
+    rule: &'a ThemeRule,
+    all_text: &str,
+    all_types: &str,
+) -> Option<RuleMatch<'a>> {
+    let matched_patterns: Vec<&str> = rule
+        .oracle_patterns
+        .iter()
+        .filter(|p| p.is_match(all_text))
+        .chain(rule.type_patterns.iter().filter(|p| p.is_match(all_types)))
+        .map(|p| p.as_str())
+        .collect();
+
+    if matched_patterns.is_empty() {
+        return None;
+    }
+
+    let total_patterns = rule.oracle_patterns.len() + rule.type_patterns.len();
+    Some(RuleMatch {
+        rule,
+        confidence: matched_patterns.len() as f64 / total_patterns as f64,
+        matched_patterns,
+    })
+}
+
 /// Detect themes for a single card
 pub fn detect_card_themes(card: &Card) -> Vec<(Theme, f64, Option<SynergyRole>)> {
-    let mut themes = Vec::new();
+    detect_oracle_themes(card, &NormalizedOracle::new(card))
+}
+
+/// Detect themes for a card whose oracle text is already normalized
+pub fn detect_oracle_themes(
+    card: &Card,
+    oracle: &NormalizedOracle,
+) -> Vec<(Theme, f64, Option<SynergyRole>)> {
+    let mut themes: Vec<(Theme, f64, Option<SynergyRole>)> = Vec::new();
 
-    // Combine all oracle text
-    let all_text: String = card
-        .all_oracle_text()
-        .into_iter()
-        .collect::<Vec<_>>()
-        .join(" ");
+    let all_text = oracle.text();
 
     let all_types: String = card.all_type_lines().join(" ");
 
     for rule in THEME_RULES.iter() {
-        let oracle_matches: usize = rule
-            .oracle_patterns
-            .iter()
-            .filter(|p| p.is_match(&all_text))
-            .count();
-
-        let type_matches: usize = rule
-            .type_patterns
-            .iter()
-            .filter(|p| p.is_match(&all_types))
-            .count();
-
-        let total_patterns = rule.oracle_patterns.len() + rule.type_patterns.len();
-        let total_matches = oracle_matches + type_matches;
-
-        if total_matches > 0 {
-            let confidence = total_matches as f64 / total_patterns as f64;
-            if confidence >= rule.min_confidence {
-                themes.push((rule.theme.clone(), confidence, rule.role_hint));
+        if let Some(m) = match_rule(rule, &all_text, &all_types) {
+            if m.passes() {
+                themes.push((rule.theme.clone(), m.confidence, rule.role_hint));
             }
         }
     }
 
+    // User-defined rules; several rules may share a theme, so keep the best match
+    for rule in custom_rules() {
+        let Some(m) = match_rule(rule, &all_text, &all_types).filter(|m| m.passes()) else {
+            continue;
+        };
+        match themes.iter_mut().find(|(t, _, _)| *t == rule.theme) {
+            Some(existing) if existing.1 >= m.confidence => {}
+            Some(existing) => *existing = (rule.theme.clone(), m.confidence, rule.role_hint),
+            None => themes.push((rule.theme.clone(), m.confidence, rule.role_hint)),
+        }
+    }
+
     // Add type-based themes
     if is_equipment(card) {
         themes.push((Theme::Equipment, 1.0, Some(SynergyRole::Support)));
@@ -333,18 +375,14 @@ pub fn detect_tribal_themes(
     }
 
     // Sort by count descending
-    tribal_themes.sort_by(|a, b| b.1.cmp(&a.1));
+    tribal_themes.sort_by_key(|a| std::cmp::Reverse(a.1));
     tribal_themes
 }
 
-/// Determine if a card is an enabler, payoff, or support for a theme
-pub fn classify_card_role(card: &Card, theme: &Theme) -> SynergyRole {
-    let all_text: String = card
-        .all_oracle_text()
-        .into_iter()
-        .collect::<Vec<_>>()
-        .join(" ")
-        .to_lowercase();
+/// Determine if a card is an enabler, payoff, or support for a theme, from
+/// its already normalized oracle text
+pub fn classify_oracle_role(card: &Card, oracle: &NormalizedOracle, theme: &Theme) -> SynergyRole {
+    let all_text = oracle.text().to_lowercase();
 
     match theme {
         Theme::Tokens => {
@@ -398,6 +436,15 @@ pub fn classify_card_role(card: &Card, theme: &Theme) -> SynergyRole {
                 SynergyRole::Support
             }
         }
+        Theme::Custom(_) => {
+            let all_types = card.all_type_lines().join(" ");
+            custom_rules()
+                .iter()
+                .filter(|r| r.theme == *theme)
+                .filter_map(|r| match_rule(r, &all_text, &all_types))
+                .find_map(|m| m.rule.role_hint)
+                .unwrap_or(SynergyRole::Support)
+        }
         _ => SynergyRole::Support,
     }
 }
@@ -456,4 +503,34 @@ mod tests {
         let themes = detect_card_themes(&card);
         assert!(themes.iter().any(|(t, _, _)| *t == Theme::Sacrifice));
     }
+
+    #[test]
+    fn test_reminder_text_does_not_add_themes() {
+        let card = mock_card(
+            "Trample\nCycling {2} ({2}, Discard this card: Draw a card.)",
+            "Creature — Beast",
+        );
+        let themes = detect_card_themes(&card);
+        assert!(!themes.iter().any(|(t, _, _)| *t == Theme::Draw));
+
+        let mut card = mock_card(
+            "When Token Collector enters, gain control of target artifact.",
+            "Creature",
+        );
+        card.name = "Token Collector".to_string();
+        let themes = detect_card_themes(&card);
+        assert!(!themes.iter().any(|(t, _, _)| *t == Theme::Tokens));
+    }
+
+    #[test]
+    fn test_match_rule_reports_patterns() {
+        let card = mock_card("Create a 1/1 white Spirit creature token.", "Sorcery");
+        let rule = &THEME_RULES[0];
+        let m = match_rule(rule, card.oracle_text.as_deref().unwrap(), &card.type_line).unwrap();
+
+        assert_eq!(rule.theme, Theme::Tokens);
+        assert!(m.passes());
+        assert!(m.matched_patterns.contains(&r"(?i)create.*token"));
+        assert!(match_rule(rule, "Draw a card.", "Instant").is_none());
+    }
 }
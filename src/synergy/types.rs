@@ -3,6 +3,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::combo::ComboMatch;
+
 /// Counter types for counter-based themes
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CounterType {
@@ -119,6 +121,9 @@ pub enum Keyword {
     Escape,
     Delve,
     Convoke,
+    Improvise,
+    Evoke,
+    Foretell,
     Cascade,
     Storm,
     Proliferate,
@@ -133,6 +138,44 @@ pub enum Keyword {
     Overload,
     Crew,
     Equip,
+    Prowess,
+    Ninjutsu,
+    Mutate,
+    Disturb,
+    Blitz,
+    Kicker,
+    Cycling,
+    Toxic,
+    Backup,
+    Protection,
+    Shroud,
+    Persist,
+    Undying,
+    Exalted,
+    Dash,
+    Suspend,
+    Miracle,
+    Dredge,
+    Bestow,
+    Embalm,
+    Eternalize,
+    Casualty,
+    Exploit,
+    Afterlife,
+    Riot,
+    Training,
+    Skulk,
+    Landwalk,
+
+    // Keyword actions
+    Scry,
+    Surveil,
+    Mill,
+    Investigate,
+    Explore,
+    Amass,
+    Connive,
+    Goad,
 
     // Generic catch-all
     Other(String),
@@ -162,6 +205,9 @@ impl Keyword {
             Keyword::Escape => "Escape".to_string(),
             Keyword::Delve => "Delve".to_string(),
             Keyword::Convoke => "Convoke".to_string(),
+            Keyword::Improvise => "Improvise".to_string(),
+            Keyword::Evoke => "Evoke".to_string(),
+            Keyword::Foretell => "Foretell".to_string(),
             Keyword::Cascade => "Cascade".to_string(),
             Keyword::Storm => "Storm".to_string(),
             Keyword::Proliferate => "Proliferate".to_string(),
@@ -176,9 +222,125 @@ impl Keyword {
             Keyword::Overload => "Overload".to_string(),
             Keyword::Crew => "Crew".to_string(),
             Keyword::Equip => "Equip".to_string(),
+            Keyword::Prowess => "Prowess".to_string(),
+            Keyword::Ninjutsu => "Ninjutsu".to_string(),
+            Keyword::Mutate => "Mutate".to_string(),
+            Keyword::Disturb => "Disturb".to_string(),
+            Keyword::Blitz => "Blitz".to_string(),
+            Keyword::Kicker => "Kicker".to_string(),
+            Keyword::Cycling => "Cycling".to_string(),
+            Keyword::Toxic => "Toxic".to_string(),
+            Keyword::Backup => "Backup".to_string(),
+            Keyword::Protection => "Protection".to_string(),
+            Keyword::Shroud => "Shroud".to_string(),
+            Keyword::Persist => "Persist".to_string(),
+            Keyword::Undying => "Undying".to_string(),
+            Keyword::Exalted => "Exalted".to_string(),
+            Keyword::Dash => "Dash".to_string(),
+            Keyword::Suspend => "Suspend".to_string(),
+            Keyword::Miracle => "Miracle".to_string(),
+            Keyword::Dredge => "Dredge".to_string(),
+            Keyword::Bestow => "Bestow".to_string(),
+            Keyword::Embalm => "Embalm".to_string(),
+            Keyword::Eternalize => "Eternalize".to_string(),
+            Keyword::Casualty => "Casualty".to_string(),
+            Keyword::Exploit => "Exploit".to_string(),
+            Keyword::Afterlife => "Afterlife".to_string(),
+            Keyword::Riot => "Riot".to_string(),
+            Keyword::Training => "Training".to_string(),
+            Keyword::Skulk => "Skulk".to_string(),
+            Keyword::Landwalk => "Landwalk".to_string(),
+            Keyword::Scry => "Scry".to_string(),
+            Keyword::Surveil => "Surveil".to_string(),
+            Keyword::Mill => "Mill".to_string(),
+            Keyword::Investigate => "Investigate".to_string(),
+            Keyword::Explore => "Explore".to_string(),
+            Keyword::Amass => "Amass".to_string(),
+            Keyword::Connive => "Connive".to_string(),
+            Keyword::Goad => "Goad".to_string(),
             Keyword::Other(s) => s.clone(),
         }
     }
+
+    /// Look up a keyword by its name, ignoring case; unknown names become `Other`
+    pub fn from_name(name: &str) -> Keyword {
+        match name.to_lowercase().as_str() {
+            "flying" => Keyword::Flying,
+            "trample" => Keyword::Trample,
+            "haste" => Keyword::Haste,
+            "vigilance" => Keyword::Vigilance,
+            "deathtouch" => Keyword::Deathtouch,
+            "lifelink" => Keyword::Lifelink,
+            "first strike" => Keyword::FirstStrike,
+            "double strike" => Keyword::DoubleStrike,
+            "menace" => Keyword::Menace,
+            "reach" => Keyword::Reach,
+            "flash" => Keyword::Flash,
+            "hexproof" => Keyword::Hexproof,
+            "indestructible" => Keyword::Indestructible,
+            "defender" => Keyword::Defender,
+            "ward" => Keyword::Ward,
+            "flashback" => Keyword::Flashback,
+            "unearth" => Keyword::Unearth,
+            "escape" => Keyword::Escape,
+            "delve" => Keyword::Delve,
+            "convoke" => Keyword::Convoke,
+            "improvise" => Keyword::Improvise,
+            "evoke" => Keyword::Evoke,
+            "foretell" => Keyword::Foretell,
+            "cascade" => Keyword::Cascade,
+            "storm" => Keyword::Storm,
+            "proliferate" => Keyword::Proliferate,
+            "landfall" => Keyword::Landfall,
+            "constellation" => Keyword::Constellation,
+            "devotion" => Keyword::Devotion,
+            "annihilator" => Keyword::Annihilator,
+            "infect" => Keyword::Infect,
+            "wither" => Keyword::Wither,
+            "affinity" => Keyword::Affinity,
+            "madness" => Keyword::Madness,
+            "overload" => Keyword::Overload,
+            "crew" => Keyword::Crew,
+            "equip" => Keyword::Equip,
+            "prowess" => Keyword::Prowess,
+            "ninjutsu" => Keyword::Ninjutsu,
+            "mutate" => Keyword::Mutate,
+            "disturb" => Keyword::Disturb,
+            "blitz" => Keyword::Blitz,
+            "kicker" => Keyword::Kicker,
+            "cycling" => Keyword::Cycling,
+            "toxic" => Keyword::Toxic,
+            "backup" => Keyword::Backup,
+            "protection" => Keyword::Protection,
+            "shroud" => Keyword::Shroud,
+            "persist" => Keyword::Persist,
+            "undying" => Keyword::Undying,
+            "exalted" => Keyword::Exalted,
+            "dash" => Keyword::Dash,
+            "suspend" => Keyword::Suspend,
+            "miracle" => Keyword::Miracle,
+            "dredge" => Keyword::Dredge,
+            "bestow" => Keyword::Bestow,
+            "embalm" => Keyword::Embalm,
+            "eternalize" => Keyword::Eternalize,
+            "casualty" => Keyword::Casualty,
+            "exploit" => Keyword::Exploit,
+            "afterlife" => Keyword::Afterlife,
+            "riot" => Keyword::Riot,
+            "training" => Keyword::Training,
+            "skulk" => Keyword::Skulk,
+            "landwalk" => Keyword::Landwalk,
+            "scry" => Keyword::Scry,
+            "surveil" => Keyword::Surveil,
+            "mill" => Keyword::Mill,
+            "investigate" => Keyword::Investigate,
+            "explore" => Keyword::Explore,
+            "amass" => Keyword::Amass,
+            "connive" => Keyword::Connive,
+            "goad" => Keyword::Goad,
+            _ => Keyword::Other(name.to_string()),
+        }
+    }
 }
 
 /// Card role within a synergy
@@ -195,9 +357,15 @@ pub struct CardSynergyProfile {
     pub card_name: String,
     pub themes: Vec<Theme>,
     pub keywords: Vec<Keyword>,
+    /// Keywords the card gives to other objects ("creatures you control have flying")
+    #[serde(default)]
+    pub granted_keywords: Vec<Keyword>,
     pub role: Option<SynergyRole>,
     pub synergizes_with: Vec<String>,
     pub synergy_score: f64,
+    /// Betweenness centrality in the synergy graph, 0-1
+    #[serde(default)]
+    pub centrality: f64,
 }
 
 impl CardSynergyProfile {
@@ -206,9 +374,11 @@ impl CardSynergyProfile {
             card_name,
             themes: Vec::new(),
             keywords: Vec::new(),
+            granted_keywords: Vec::new(),
             role: None,
             synergizes_with: Vec::new(),
             synergy_score: 0.0,
+            centrality: 0.0,
         }
     }
 }
@@ -233,6 +403,27 @@ pub struct SynergyEdge {
     pub reason: String,
 }
 
+/// How strongly a deck fits a strategy archetype, and why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchetypeScore {
+    pub theme: Theme,
+    /// Fit from 0 to 1
+    pub score: f64,
+    /// Deck signals supporting the score
+    pub evidence: Vec<String>,
+}
+
+/// A tightly connected group of cards in the synergy graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynergyCluster {
+    /// Member cards, most connected within the package first
+    pub cards: Vec<String>,
+    /// Themes shared by at least half of the cards
+    pub themes: Vec<Theme>,
+    /// Cards with synergies into other packages, most central first
+    pub bridges: Vec<String>,
+}
+
 /// Analysis results for a single theme
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThemeAnalysis {
@@ -285,10 +476,17 @@ pub struct SynergyStats {
     pub theme_coverage: f64,
     /// Cards with no synergy connections (with reasons)
     pub orphan_cards: Vec<OrphanCard>,
-    /// Cards with most connections
+    /// Cards that connect the most of the synergy graph (highest betweenness)
     pub hub_cards: Vec<String>,
-    /// How focused the themes are (higher = more focused)
+    /// How focused the themes are, 0-1 (higher = more focused).
+    ///
+    /// Blends theme concentration (one minus the normalized entropy of theme
+    /// membership), the share of themed cards connected to the primary theme,
+    /// and the card overlap between the top two themes.
     pub theme_coherence: f64,
+    /// Overall deck synergy, 0-100
+    #[serde(default)]
+    pub synergy_score: f64,
     /// Distribution of keywords
     pub keyword_distribution: HashMap<String, u32>,
 }
@@ -301,6 +499,7 @@ impl Default for SynergyStats {
             orphan_cards: Vec::new(),
             hub_cards: Vec::new(),
             theme_coherence: 0.0,
+            synergy_score: 0.0,
             keyword_distribution: HashMap::new(),
         }
     }
@@ -330,6 +529,15 @@ pub struct SynergyMatrix {
 
     /// Observations and insights
     pub observations: Vec<String>,
+    /// Known combos fully or partially present in the deck
+    #[serde(default)]
+    pub combos: Vec<ComboMatch>,
+    /// Strategy archetype scores, most likely first
+    #[serde(default)]
+    pub archetypes: Vec<ArchetypeScore>,
+    /// Tightly connected card packages, largest first
+    #[serde(default)]
+    pub clusters: Vec<SynergyCluster>,
 }
 
 impl SynergyMatrix {
@@ -345,19 +553,46 @@ impl SynergyMatrix {
             edges: Vec::new(),
             stats: SynergyStats::default(),
             observations: Vec::new(),
+            combos: Vec::new(),
+            archetypes: Vec::new(),
+            clusters: Vec::new(),
         }
     }
 
     /// Sort themes by card count (descending)
     pub fn sort_themes(&mut self) {
         self.detected_themes
-            .sort_by(|a, b| b.card_count.cmp(&a.card_count));
+            .sort_by_key(|a| std::cmp::Reverse(a.card_count));
     }
 
     /// Get top N themes
     pub fn top_themes(&self, n: usize) -> Vec<&ThemeAnalysis> {
         self.detected_themes.iter().take(n).collect()
     }
+
+    /// The most likely strategy archetype, if any scores high enough
+    pub fn likely_archetype(&self) -> Option<&ArchetypeScore> {
+        self.archetypes
+            .first()
+            .filter(|a| a.score >= super::archetype::MIN_ARCHETYPE_SCORE)
+    }
+
+    /// Get the N strongest enabler/payoff edges
+    pub fn key_interactions(&self, n: usize) -> Vec<&SynergyEdge> {
+        let mut edges: Vec<_> = self
+            .edges
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e.relation,
+                    SynergyRelation::Enables | SynergyRelation::PayoffFor
+                )
+            })
+            .collect();
+        edges.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+        edges.truncate(n);
+        edges
+    }
 }
 
 impl Default for SynergyMatrix {
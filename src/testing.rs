@@ -0,0 +1,119 @@
+//! Card and deck entry builders shared by unit tests
+
+use std::collections::HashMap;
+
+use crate::api::{Card, ImageUris, Prices};
+use crate::input::{DeckEntry, DeckSection};
+
+/// Builds a test `Card`. Starts as a colorless common with no cost, text or
+/// prices, and each setter fills in one field.
+pub struct CardBuilder {
+    card: Card,
+}
+
+/// Start building a test card named `name`
+pub fn card(name: &str) -> CardBuilder {
+    CardBuilder {
+        card: Card {
+            id: name.to_lowercase().replace(' ', "_"),
+            name: name.to_string(),
+            mana_cost: None,
+            cmc: 0.0,
+            type_line: String::new(),
+            oracle_text: None,
+            power: None,
+            toughness: None,
+            colors: None,
+            color_identity: Vec::new(),
+            set: "TST".to_string(),
+            set_name: "Test Set".to_string(),
+            rarity: "common".to_string(),
+            prices: None,
+            legalities: HashMap::new(),
+            image_uris: None,
+            scryfall_uri: "https://scryfall.com".to_string(),
+            card_faces: None,
+            layout: None,
+        },
+    }
+}
+
+impl CardBuilder {
+    pub fn mana_cost(mut self, mana_cost: &str) -> Self {
+        self.card.mana_cost = Some(mana_cost.to_string());
+        self
+    }
+
+    pub fn cmc(mut self, cmc: f64) -> Self {
+        self.card.cmc = cmc;
+        self
+    }
+
+    pub fn type_line(mut self, type_line: &str) -> Self {
+        self.card.type_line = type_line.to_string();
+        self
+    }
+
+    pub fn oracle(mut self, oracle_text: &str) -> Self {
+        self.card.oracle_text = Some(oracle_text.to_string());
+        self
+    }
+
+    /// Set both the card's colors and its color identity
+    pub fn colors(mut self, colors: &[&str]) -> Self {
+        self.card.colors = Some(colors.iter().map(|c| c.to_string()).collect());
+        self.identity(colors)
+    }
+
+    /// Set the color identity alone, leaving the card's colors unset
+    pub fn identity(mut self, identity: &[&str]) -> Self {
+        self.card.color_identity = identity.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    pub fn usd(mut self, usd: &str) -> Self {
+        self.card.prices = Some(Prices {
+            usd: Some(usd.to_string()),
+            usd_foil: None,
+            eur: None,
+            tix: None,
+        });
+        self
+    }
+
+    /// Set the card's legality status in a Scryfall format
+    pub fn legality(mut self, format: &str, status: &str) -> Self {
+        self.card
+            .legalities
+            .insert(format.to_string(), status.to_string());
+        self
+    }
+
+    /// Set the card's image URIs, all pointing at `url`
+    pub fn image(mut self, url: &str) -> Self {
+        let url = Some(url.to_string());
+        self.card.image_uris = Some(ImageUris {
+            small: url.clone(),
+            normal: url.clone(),
+            large: url.clone(),
+            png: url.clone(),
+            art_crop: url.clone(),
+            border_crop: url,
+        });
+        self
+    }
+
+    pub fn build(self) -> Card {
+        self.card
+    }
+}
+
+/// A hydrated deck entry for `card`
+pub fn entry(quantity: u32, card: Card, section: DeckSection) -> DeckEntry {
+    DeckEntry {
+        quantity,
+        card_name: card.name.clone(),
+        card: Some(card),
+        section,
+    }
+}
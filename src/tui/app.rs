@@ -0,0 +1,227 @@
+use ratatui::crossterm::event::KeyCode;
+use std::sync::Arc;
+
+use crate::combo::ComboDatabase;
+use crate::curve::{
+    calculate_mana_base, detect_format_from_deck, determine_land_count, CurveAnalysis,
+    CurveAnalyzer, CurveArchetype, CurveFit,
+};
+use crate::deck::{Algorithm, ManaBase};
+use crate::input::{DeckEntry, DeckList};
+use crate::synergy::{get_detector, SynergyDetector, SynergyEdge, SynergyMatrix};
+
+/// Which pane receives navigation keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Cards,
+    Detail,
+}
+
+/// State behind the dashboard: the deck, its analyses and the UI selection
+pub struct DashboardApp {
+    pub deck_list: DeckList,
+    pub curve: CurveAnalysis,
+    pub matrix: SynergyMatrix,
+    pub mana_base: ManaBase,
+    pub target_lands: u32,
+    /// Mainboard entries in display order (spells by CMC, then lands)
+    pub cards: Vec<DeckEntry>,
+    pub selected: usize,
+    pub focus: Focus,
+    pub detail_scroll: u16,
+    pub status: String,
+    pub should_quit: bool,
+    pub reload_requested: bool,
+    detector: Box<dyn SynergyDetector>,
+}
+
+impl DashboardApp {
+    pub fn new(deck_list: DeckList, combos: Arc<ComboDatabase>) -> Self {
+        let mut app = Self {
+            deck_list: DeckList::new(deck_list.source.clone()),
+            curve: CurveAnalysis::new(),
+            matrix: SynergyMatrix::new(),
+            mana_base: ManaBase::new(),
+            target_lands: 0,
+            cards: Vec::new(),
+            selected: 0,
+            focus: Focus::Cards,
+            detail_scroll: 0,
+            status: String::new(),
+            should_quit: false,
+            reload_requested: false,
+            detector: get_detector(combos),
+        };
+        app.set_deck(deck_list);
+        app
+    }
+
+    /// Replace the deck and re-run every analysis, keeping the selected card if it still exists
+    pub fn set_deck(&mut self, deck_list: DeckList) {
+        let selected_name = self.selected_entry().map(|e| e.card_name.clone());
+
+        let format = detect_format_from_deck(&deck_list);
+        let mut curve = CurveAnalyzer::new().analyze(&deck_list);
+        let matrix = self.detector.analyze(&deck_list);
+        let archetype = CurveArchetype::select(format, Some(&matrix), curve.stats.average_cmc);
+        curve.curve_fit = Some(CurveFit::evaluate(&curve.buckets, archetype));
+
+        let (target_lands, _) = determine_land_count(&deck_list, None, deck_list.excludes_lands);
+        self.mana_base =
+            calculate_mana_base(&curve, &deck_list, target_lands, format, Algorithm::Simple);
+        self.target_lands = target_lands;
+
+        let mut cards: Vec<DeckEntry> = deck_list.mainboard().cloned().collect();
+        cards.sort_by(|a, b| {
+            let key = |e: &DeckEntry| {
+                let card = e.card.as_ref();
+                let is_land = card.is_some_and(|c| c.type_line.contains("Land"));
+                let cmc = card.map(|c| c.cmc).unwrap_or(0.0);
+                (is_land, cmc)
+            };
+            let (land_a, cmc_a) = key(a);
+            let (land_b, cmc_b) = key(b);
+            land_a
+                .cmp(&land_b)
+                .then(cmc_a.total_cmp(&cmc_b))
+                .then_with(|| a.card_name.cmp(&b.card_name))
+        });
+
+        self.selected = selected_name
+            .and_then(|name| cards.iter().position(|e| e.card_name == name))
+            .unwrap_or(0)
+            .min(cards.len().saturating_sub(1));
+        self.cards = cards;
+        self.curve = curve;
+        self.matrix = matrix;
+        self.deck_list = deck_list;
+    }
+
+    pub fn selected_entry(&self) -> Option<&DeckEntry> {
+        self.cards.get(self.selected)
+    }
+
+    /// Synergy edges touching a card
+    pub fn edges_for(&self, card_name: &str) -> Vec<&SynergyEdge> {
+        self.matrix
+            .edges
+            .iter()
+            .filter(|e| e.card_a == card_name || e.card_b == card_name)
+            .collect()
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('r') => self.reload_requested = true,
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+                if self.focus == Focus::Detail {
+                    self.focus = Focus::Cards;
+                } else if key == KeyCode::Esc {
+                    self.should_quit = true;
+                }
+            }
+            KeyCode::Enter | KeyCode::Right if self.selected_entry().is_some() => {
+                self.focus = Focus::Detail;
+                self.detail_scroll = 0;
+            }
+            KeyCode::Down | KeyCode::Char('j') => match self.focus {
+                Focus::Cards => self.move_selection(1),
+                Focus::Detail => self.detail_scroll = self.detail_scroll.saturating_add(1),
+            },
+            KeyCode::Up | KeyCode::Char('k') => match self.focus {
+                Focus::Cards => self.move_selection(-1),
+                Focus::Detail => self.detail_scroll = self.detail_scroll.saturating_sub(1),
+            },
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = self.cards.len().saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    fn move_selection(&mut self, delta: i64) {
+        if self.cards.is_empty() {
+            return;
+        }
+        let last = self.cards.len() as i64 - 1;
+        self.selected = (self.selected as i64 + delta).clamp(0, last) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{DeckSection, DeckSource};
+    use crate::testing::{card, entry};
+
+    fn deck(cards: &[(&str, &str, f64)]) -> DeckList {
+        let mut deck = DeckList::new(DeckSource::Manual);
+        for (name, type_line, cmc) in cards {
+            deck.entries.push(entry(
+                1,
+                card(name).cmc(*cmc).type_line(type_line).build(),
+                DeckSection::Mainboard,
+            ));
+        }
+        deck
+    }
+
+    #[test]
+    fn test_cards_sorted_spells_then_lands() {
+        let app = DashboardApp::new(
+            deck(&[
+                ("Mountain", "Basic Land — Mountain", 0.0),
+                ("Shock", "Instant", 1.0),
+                ("Fireball", "Sorcery", 1.0),
+                ("Lava Axe", "Sorcery", 5.0),
+            ]),
+            Arc::new(ComboDatabase::default()),
+        );
+        let names: Vec<_> = app.cards.iter().map(|e| e.card_name.as_str()).collect();
+        assert_eq!(names, vec!["Fireball", "Shock", "Lava Axe", "Mountain"]);
+    }
+
+    #[test]
+    fn test_navigation_and_drill_in() {
+        let mut app = DashboardApp::new(
+            deck(&[("Shock", "Instant", 1.0), ("Lava Axe", "Sorcery", 5.0)]),
+            Arc::new(ComboDatabase::default()),
+        );
+        app.handle_key(KeyCode::Up);
+        assert_eq!(app.selected, 0);
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.selected, 1);
+
+        app.handle_key(KeyCode::Enter);
+        assert_eq!(app.focus, Focus::Detail);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.detail_scroll, 1);
+        assert_eq!(app.selected, 1);
+
+        app.handle_key(KeyCode::Esc);
+        assert_eq!(app.focus, Focus::Cards);
+        assert!(!app.should_quit);
+        app.handle_key(KeyCode::Char('q'));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_reload_keeps_selected_card() {
+        let mut app = DashboardApp::new(
+            deck(&[("Shock", "Instant", 1.0), ("Lava Axe", "Sorcery", 5.0)]),
+            Arc::new(ComboDatabase::default()),
+        );
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.selected_entry().unwrap().card_name, "Lava Axe");
+
+        app.set_deck(deck(&[
+            ("Lightning Bolt", "Instant", 1.0),
+            ("Shock", "Instant", 1.0),
+            ("Lava Axe", "Sorcery", 5.0),
+        ]));
+        assert_eq!(app.selected_entry().unwrap().card_name, "Lava Axe");
+    }
+}
@@ -0,0 +1,85 @@
+//! Full-screen terminal dashboard for deck analysis.
+
+pub mod app;
+pub mod ui;
+
+pub use app::*;
+
+use chrono::Local;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::api::traits::CardApi;
+use crate::cli::reload_deck;
+use crate::combo::ComboDatabase;
+use crate::input::{DeckList, DeckSource, FileWatcher};
+
+/// How often to poll for key presses and file changes
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// Run the dashboard until the user quits.
+///
+/// When the deck was loaded from a text file, `watcher` watches that file and
+/// the analysis refreshes whenever it is saved. Create the watcher before the
+/// deck is first parsed so saves made while loading aren't missed.
+pub async fn run_dashboard(
+    deck_list: DeckList,
+    client: Box<dyn CardApi>,
+    watcher: Option<FileWatcher>,
+    combos: Arc<ComboDatabase>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = match &deck_list.source {
+        DeckSource::TextFile(path) => Some(path.clone()),
+        _ => None,
+    };
+    let mut watcher = watcher.filter(|_| path.is_some());
+
+    let mut app = DashboardApp::new(deck_list, combos);
+    app.status = match &path {
+        Some(path) => format!("Watching {path}"),
+        None => "Auto-refresh is only available for decklist files".to_string(),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| ui::draw(frame, &app)) {
+            break Err(e.into());
+        }
+
+        match event::poll(TICK_RATE) {
+            Ok(true) => match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => app.handle_key(key.code),
+                Ok(_) => {}
+                Err(e) => break Err(e.into()),
+            },
+            Ok(false) => {}
+            Err(e) => break Err(e.into()),
+        }
+
+        if app.should_quit {
+            break Ok(());
+        }
+
+        let file_changed = watcher.as_mut().is_some_and(|w| w.changed());
+        if file_changed || std::mem::take(&mut app.reload_requested) {
+            if let Some(path) = &path {
+                app.status = match reload_deck(path, &app.deck_list, client.as_ref()).await {
+                    Ok((deck, fetched)) => {
+                        app.set_deck(deck);
+                        format!(
+                            "Reloaded at {} ({} new card{} fetched)",
+                            Local::now().format("%H:%M:%S"),
+                            fetched,
+                            if fetched == 1 { "" } else { "s" }
+                        )
+                    }
+                    Err(e) => e,
+                };
+            }
+        }
+    };
+    ratatui::restore();
+
+    result
+}
@@ -0,0 +1,398 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Bar, BarChart, BarGroup, Block, Borders, List, ListItem, ListState, Paragraph, Wrap,
+};
+use ratatui::Frame;
+
+use crate::curve::{slot_label, BucketLoad};
+use crate::synergy::SynergyRelation;
+
+use super::app::{DashboardApp, Focus};
+
+/// Draw the whole dashboard
+pub fn draw(frame: &mut Frame, app: &DashboardApp) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(14),
+            Constraint::Min(8),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    draw_header(frame, app, rows[0]);
+
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(45),
+            Constraint::Percentage(25),
+            Constraint::Percentage(30),
+        ])
+        .split(rows[1]);
+    draw_histogram(frame, app, top[0]);
+    draw_pips(frame, app, top[1]);
+    draw_mana_base(frame, app, top[2]);
+
+    match app.focus {
+        Focus::Cards => {
+            let bottom = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(40),
+                ])
+                .split(rows[2]);
+            draw_themes(frame, app, bottom[0]);
+            draw_cards(frame, app, bottom[1]);
+            draw_detail(frame, app, bottom[2]);
+        }
+        Focus::Detail => draw_detail(frame, app, rows[2]),
+    }
+
+    draw_footer(frame, app, rows[3]);
+}
+
+fn panel(title: &str) -> Block<'_> {
+    Block::default().borders(Borders::ALL).title(Span::styled(
+        format!(" {title} "),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn draw_header(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let name = app.deck_list.name.as_deref().unwrap_or("Untitled deck");
+    let mut spans = vec![
+        Span::styled(
+            " SCRY ",
+            Style::default().fg(Color::Black).bg(Color::Green).bold(),
+        ),
+        Span::raw(format!(" {name}")),
+    ];
+    if let Some(format) = &app.deck_list.format {
+        spans.push(Span::styled(
+            format!("  {format}"),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    spans.push(Span::styled(
+        format!(
+            "  {} cards · avg CMC {:.2}",
+            app.deck_list.mainboard().map(|e| e.quantity).sum::<u32>(),
+            app.curve.stats.average_cmc
+        ),
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn draw_histogram(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let title = match &app.curve.curve_fit {
+        Some(fit) => format!("Mana Curve — {} fit {:.0}/100", fit.archetype, fit.score),
+        None => "Mana Curve".to_string(),
+    };
+
+    let bars: Vec<Bar> = app
+        .curve
+        .curve_fit
+        .as_ref()
+        .map(|fit| {
+            fit.deviations
+                .iter()
+                .map(|d| {
+                    let color = match d.load {
+                        BucketLoad::Over => Color::Red,
+                        BucketLoad::Under => Color::Yellow,
+                        BucketLoad::OnTarget => Color::Green,
+                    };
+                    Bar::default()
+                        .value(d.actual_count as u64)
+                        .label(Line::from(slot_label(d.slot)))
+                        .style(Style::default().fg(color))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let chart = BarChart::default()
+        .block(panel(&title))
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(4)
+        .bar_gap(1);
+    frame.render_widget(chart, area);
+}
+
+fn draw_pips(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let pips = &app.curve.pip_breakdown;
+    let total = pips.total();
+    let width = area.width.saturating_sub(14) as f64;
+
+    let lines: Vec<Line> = [
+        ("W", pips.white, Color::White),
+        ("U", pips.blue, Color::Blue),
+        ("B", pips.black, Color::Magenta),
+        ("R", pips.red, Color::Red),
+        ("G", pips.green, Color::Green),
+        ("C", pips.colorless, Color::Gray),
+    ]
+    .into_iter()
+    .filter(|(_, count, _)| *count > 0.0)
+    .map(|(symbol, count, color)| {
+        let share = if total > 0.0 { count / total } else { 0.0 };
+        Line::from(vec![
+            Span::styled(format!("{symbol} "), Style::default().fg(color).bold()),
+            Span::styled(
+                "█".repeat((share * width).round() as usize),
+                Style::default().fg(color),
+            ),
+            Span::raw(format!(" {:.0}%", share * 100.0)),
+        ])
+    })
+    .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(panel("Color Pips")), area);
+}
+
+fn draw_mana_base(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Target lands: ", Style::default().fg(Color::Yellow)),
+        Span::raw(app.target_lands.to_string()),
+    ])];
+
+    let mut basics: Vec<_> = app
+        .mana_base
+        .basics
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .collect();
+    basics.sort_by_key(|(color, _)| color.symbol());
+    for (color, count) in basics {
+        lines.push(Line::from(format!("  {} {}", count, color.basic_land())));
+    }
+    for dual in &app.mana_base.duals {
+        let colors: Vec<_> = dual.colors.iter().map(|c| c.symbol()).collect();
+        lines.push(Line::from(format!(
+            "  {} {} ({})",
+            dual.count,
+            dual.name,
+            colors.join("/")
+        )));
+    }
+    for rec in &app.mana_base.recommendations {
+        lines.push(Line::from(Span::styled(
+            format!("! {rec}"),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(panel("Mana Base")),
+        area,
+    );
+}
+
+fn draw_themes(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let items: Vec<ListItem> = app
+        .matrix
+        .detected_themes
+        .iter()
+        .map(|theme| {
+            ListItem::new(Line::from(vec![
+                Span::styled(theme.theme.display_name(), Style::default().bold()),
+                Span::styled(
+                    format!(" {} ({:.0}%)", theme.card_count, theme.percentage * 100.0),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(panel("Themes")), area);
+}
+
+fn draw_cards(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let items: Vec<ListItem> = app
+        .cards
+        .iter()
+        .map(|entry| {
+            let cmc = entry
+                .card
+                .as_ref()
+                .map(|c| format!("{:>2}", c.cmc as u32))
+                .unwrap_or_else(|| " ?".to_string());
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{cmc} "), Style::default().fg(Color::DarkGray)),
+                Span::raw(format!("{}x {}", entry.quantity, entry.card_name)),
+            ]))
+        })
+        .collect();
+
+    let title = format!("Cards ({})", app.cards.len());
+    let mut state = ListState::default().with_selected(Some(app.selected));
+    let list = List::new(items)
+        .block(panel(&title))
+        .highlight_style(Style::default().bg(Color::DarkGray).bold())
+        .highlight_symbol("▶ ");
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn relation_label(relation: SynergyRelation) -> &'static str {
+    match relation {
+        SynergyRelation::Enables => "enables",
+        SynergyRelation::PayoffFor => "payoff for",
+        SynergyRelation::Supports => "supports",
+        SynergyRelation::Combos => "combos with",
+    }
+}
+
+fn draw_detail(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let Some(entry) = app.selected_entry() else {
+        frame.render_widget(Paragraph::new("").block(panel("Card")), area);
+        return;
+    };
+
+    let mut lines = Vec::new();
+    match &entry.card {
+        Some(card) => {
+            lines.push(Line::from(vec![
+                Span::styled(card.name.clone(), Style::default().bold()),
+                Span::raw("  "),
+                Span::styled(
+                    card.mana_cost.clone().unwrap_or_default(),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+            lines.push(Line::from(Span::styled(
+                card.type_line.clone(),
+                Style::default().fg(Color::DarkGray),
+            )));
+            lines.push(Line::from(""));
+            for text in card.all_oracle_text() {
+                for line in text.lines() {
+                    lines.push(Line::from(line.to_string()));
+                }
+            }
+        }
+        None => lines.push(Line::from(Span::styled(
+            format!("{} (card data not found)", entry.card_name),
+            Style::default().fg(Color::Red),
+        ))),
+    }
+
+    if let Some(profile) = app.matrix.card_profiles.get(&entry.card_name) {
+        if !profile.themes.is_empty() {
+            let themes: Vec<_> = profile.themes.iter().map(|t| t.display_name()).collect();
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("Themes: ", Style::default().fg(Color::Cyan)),
+                Span::raw(themes.join(", ")),
+            ]));
+        }
+        if let Some(role) = profile.role {
+            lines.push(Line::from(vec![
+                Span::styled("Role: ", Style::default().fg(Color::Cyan)),
+                Span::raw(format!("{role:?}")),
+            ]));
+        }
+        if !profile.keywords.is_empty() {
+            let keywords: Vec<_> = profile.keywords.iter().map(|k| k.display_name()).collect();
+            lines.push(Line::from(vec![
+                Span::styled("Keywords: ", Style::default().fg(Color::Cyan)),
+                Span::raw(keywords.join(", ")),
+            ]));
+        }
+        if !profile.granted_keywords.is_empty() {
+            let granted: Vec<_> = profile
+                .granted_keywords
+                .iter()
+                .map(|k| k.display_name())
+                .collect();
+            lines.push(Line::from(vec![
+                Span::styled("Grants: ", Style::default().fg(Color::Cyan)),
+                Span::raw(granted.join(", ")),
+            ]));
+        }
+    }
+
+    let edges = app.edges_for(&entry.card_name);
+    if !edges.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Synergies ({}):", edges.len()),
+            Style::default().fg(Color::Cyan),
+        )));
+        for edge in edges {
+            let (other, label) = if edge.card_a == entry.card_name {
+                (&edge.card_b, relation_label(edge.relation).to_string())
+            } else {
+                (&edge.card_a, format!("← {}", relation_label(edge.relation)))
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {label} "), Style::default().fg(Color::DarkGray)),
+                Span::styled(other.clone(), Style::default().bold()),
+                Span::styled(
+                    format!(" ({:.2})", edge.strength),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            lines.push(Line::from(Span::styled(
+                format!("    {}", edge.reason),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((app.detail_scroll, 0))
+        .block(panel("Card"));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_footer(frame: &mut Frame, app: &DashboardApp, area: Rect) {
+    let keys = match app.focus {
+        Focus::Cards => "↑/↓ select  enter details  r reload  q quit",
+        Focus::Detail => "↑/↓ scroll  esc back  r reload  q quit",
+    };
+    let line = Line::from(vec![
+        Span::styled(keys, Style::default().fg(Color::DarkGray)),
+        Span::raw("  "),
+        Span::styled(app.status.clone(), Style::default().fg(Color::Yellow)),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combo::ComboDatabase;
+    use crate::input::{DeckList, DeckSource};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_draws_empty_deck_in_both_views() {
+        let mut app = DashboardApp::new(
+            DeckList::new(DeckSource::Manual),
+            Arc::new(ComboDatabase::default()),
+        );
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        app.focus = Focus::Detail;
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Mana Curve"));
+    }
+}